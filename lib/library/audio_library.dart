@@ -2,11 +2,14 @@ import 'dart:io';
 import 'dart:convert';
 import 'dart:ui';
 import 'package:coriander_player/app_settings.dart';
+import 'package:coriander_player/src/rust/api/cue_sheet.dart';
+import 'package:coriander_player/src/rust/api/index_db.dart';
 import 'package:coriander_player/src/rust/api/tag_reader.dart';
+import 'package:coriander_player/src/rust/api/tag_reader.dart' as rust;
 import 'package:coriander_player/utils.dart';
 import 'package:flutter/painting.dart';
 
-/// from index.json
+/// from index.json, or index.db if it exists
 class AudioLibrary {
   List<AudioFolder> folders;
 
@@ -40,26 +43,21 @@ class AudioLibrary {
   ///         },
   ///         ...
   ///     ],
-  ///     "version": 110
+  ///     "version": 111
   /// }
   /// ```
+  ///
+  /// 存在 index.db 时 index.json 不再更新，通过 [getIndexedFolders] 从数据库加载。
   static Future<void> initFromIndex() async {
     try {
       final supportPath = (await getAppDataDir()).path;
-      final indexPath = "$supportPath\\index.json";
-
-      final indexStr = File(indexPath).readAsStringSync();
-      final Map indexJson = json.decode(indexStr);
-      final List foldersJson = indexJson["folders"];
-      final List<AudioFolder> folders = [];
-
-      for (Map folderMap in foldersJson) {
-        final List audiosJson = folderMap["audios"];
-        final List<Audio> audios = [];
-        for (Map audioMap in audiosJson) {
-          audios.add(Audio.fromMap(audioMap));
-        }
-        folders.add(AudioFolder.fromMap(folderMap, audios));
+      final List<AudioFolder> folders;
+      if (File("$supportPath\\index.db").existsSync()) {
+        folders = (await getIndexedFolders(indexPath: supportPath))
+            .map(AudioFolder.fromRust)
+            .toList();
+      } else {
+        folders = _readIndexJson("$supportPath\\index.json");
       }
 
       _instance = AudioLibrary._(folders);
@@ -72,6 +70,24 @@ class AudioLibrary {
     }
   }
 
+  static List<AudioFolder> _readIndexJson(String indexPath) {
+    final indexStr = File(indexPath).readAsStringSync();
+    final Map indexJson = json.decode(indexStr);
+    final List foldersJson = indexJson["folders"];
+    final List<AudioFolder> folders = [];
+
+    for (Map folderMap in foldersJson) {
+      final List audiosJson = folderMap["audios"];
+      final List<Audio> audios = [];
+      for (Map audioMap in audiosJson) {
+        audios.add(Audio.fromMap(audioMap));
+      }
+      folders.add(AudioFolder.fromMap(folderMap, audios));
+    }
+
+    return folders;
+  }
+
   void _buildCollections() {
     for (var f in folders) {
       audioCollection.addAll(f.audios);
@@ -147,6 +163,13 @@ class AudioFolder {
   factory AudioFolder.fromMap(Map map, List<Audio> audios) =>
       AudioFolder(audios, map["path"], map["modified"], map["latest"]);
 
+  factory AudioFolder.fromRust(rust.AudioFolder folder) => AudioFolder(
+        folder.audios.map(Audio.fromRust).toList(),
+        folder.path,
+        folder.modified.toInt(),
+        folder.latest.toInt(),
+      );
+
   @override
   String toString() {
     return {
@@ -192,6 +215,9 @@ class Audio {
   /// 标签来源（Lofty、Windows、null）
   String? by;
 
+  /// CUE 分割出的虚拟音轨在整轨文件中的位置，普通的音乐为 null
+  AudioCue? cue;
+
   ImageProvider? _cover;
 
   /// 以“、”和“/”分割艺术家，会把名称中带有这些符号的艺术家分割。
@@ -207,8 +233,9 @@ class Audio {
     this.path,
     this.modified,
     this.created,
-    this.by,
-  ) : splitedArtists = artist.split(
+    this.by, {
+    this.cue,
+  }) : splitedArtists = artist.split(
           RegExp(AppSettings.instance.artistSplitPattern),
         );
 
@@ -224,6 +251,22 @@ class Audio {
         map["modified"],
         map["created"],
         map["by"],
+        cue: map["cue"] == null ? null : AudioCue.fromMap(map["cue"]),
+      );
+
+  factory Audio.fromRust(rust.Audio audio) => Audio(
+        audio.title,
+        audio.artist,
+        audio.album,
+        audio.track ?? 0,
+        audio.duration.toInt(),
+        audio.bitrate,
+        audio.sampleRate,
+        audio.path,
+        audio.modified.toInt(),
+        audio.created.toInt(),
+        audio.by,
+        cue: audio.cue == null ? null : AudioCue.fromRust(audio.cue!),
       );
 
   Map toMap() => {
@@ -237,9 +280,13 @@ class Audio {
         "path": path,
         "modified": modified,
         "created": created,
-        "by": by
+        "by": by,
+        "cue": cue?.toMap(),
       };
 
+  /// 播放时打开的文件。虚拟音轨为整轨文件
+  String get playbackPath => cue?.file ?? path;
+
   /// 读取音乐文件的图片，自动适应缩放
   Future<ImageProvider?> _getResizedPic({
     required int width,
@@ -297,6 +344,50 @@ class Audio {
   }
 }
 
+/// 虚拟音轨在整轨文件中的位置，见 rust 的 CueTrack
+class AudioCue {
+  /// .cue 文件的绝对路径。内嵌的 CUE 为整轨文件的路径
+  String cuePath;
+
+  /// 整轨文件的绝对路径
+  String file;
+
+  /// 在整轨文件中的开始位置，in ms
+  int start;
+
+  /// 在整轨文件中的结束位置，in ms。null 表示播放到文件结尾
+  int? end;
+
+  /// CUE 是否内嵌在整轨文件中
+  bool embedded;
+
+  AudioCue(this.cuePath, this.file, this.start, this.end, this.embedded);
+
+  factory AudioCue.fromMap(Map map) => AudioCue(
+        map["cue_path"],
+        map["file"],
+        map["start"],
+        map["end"],
+        map["embedded"] ?? false,
+      );
+
+  factory AudioCue.fromRust(CueTrack cue) => AudioCue(
+        cue.cuePath,
+        cue.file,
+        cue.start.toInt(),
+        cue.end?.toInt(),
+        cue.embedded,
+      );
+
+  Map toMap() => {
+        "cue_path": cuePath,
+        "file": file,
+        "start": start,
+        "end": end,
+        "embedded": embedded,
+      };
+}
+
 class Artist {
   String name;
 
@@ -9,7 +9,11 @@ import 'package:coriander_player/utils.dart';
 
 List<Playlist> PLAYLISTS = [];
 
-Future<void> readPlaylists() async {
+/// [relocations]：被移动或重命名的音乐，旧路径 -> 新路径，见 [takeIndexRelocations]。
+/// 歌单中的这些音乐会换成新的路径，并重新保存歌单。
+Future<void> readPlaylists({
+  Map<String, String> relocations = const {},
+}) async {
   try {
     final supportPath = (await getAppDataDir()).path;
     final playlistsPath = "$supportPath\\playlists.json";
@@ -17,9 +21,13 @@ Future<void> readPlaylists() async {
     final playlistsStr = File(playlistsPath).readAsStringSync();
     final List playlistsJson = json.decode(playlistsStr);
 
+    var relocated = false;
     for (Map item in playlistsJson) {
-      PLAYLISTS.add(Playlist.fromMap(item));
+      final playlist = Playlist.fromMap(item);
+      relocated = playlist.relocate(relocations) || relocated;
+      PLAYLISTS.add(playlist);
     }
+    if (relocated) await savePlaylists();
   } catch (err, trace) {
     LOGGER.e(err, stackTrace: trace);
   }
@@ -51,6 +59,20 @@ class Playlist {
 
   Playlist(this.name, this.audios);
 
+  /// 把路径在 relocations 中的音乐换成新的路径。有音乐被替换时返回 true
+  bool relocate(Map<String, String> relocations) {
+    var relocated = false;
+    audios = audios.map((path, audio) {
+      final to = relocations[path];
+      if (to == null) return MapEntry(path, audio);
+
+      relocated = true;
+      audio.path = to;
+      return MapEntry(to, audio);
+    });
+    return relocated;
+  }
+
   Map toMap() {
     final List<Map> audioMaps = [];
     for (var item in audios.values) {
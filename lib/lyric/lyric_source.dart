@@ -52,7 +52,11 @@ class LyricSource {
 
 Map<String, LyricSource> LYRIC_SOURCES = {};
 
-Future<void> readLyricSources() async {
+/// [relocations]：被移动或重命名的音乐，旧路径 -> 新路径，见 [takeIndexRelocations]。
+/// 这些音乐的歌词来源会换成新的路径，并重新保存。
+Future<void> readLyricSources({
+  Map<String, String> relocations = const {},
+}) async {
   try {
     final supportPath = (await getAppDataDir()).path;
     final lyricSourcePath = "$supportPath\\lyric_source.json";
@@ -60,10 +64,14 @@ Future<void> readLyricSources() async {
     final lyricSourceStr = File(lyricSourcePath).readAsStringSync();
     final Map lyricSourceJson = json.decode(lyricSourceStr);
 
+    var relocated = false;
     for (final item in lyricSourceJson.entries) {
-      if (File(item.key).existsSync() == false) continue;
-      LYRIC_SOURCES[item.key] = LyricSource.fromMap(item.value);
+      final String path = relocations[item.key] ?? item.key;
+      relocated = relocated || path != item.key;
+      if (File(path).existsSync() == false) continue;
+      LYRIC_SOURCES[path] = LyricSource.fromMap(item.value);
     }
+    if (relocated) await saveLyricSources();
   } catch (err, trace) {
     LOGGER.e(err, stackTrace: trace);
   }
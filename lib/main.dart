@@ -70,7 +70,8 @@ Future<void> main() async {
   if (File("$supportPath\\app_preference.json").existsSync()) {
     await AppPreference.read();
   }
-  final welcome = !File("$supportPath\\index.json").existsSync();
+  final welcome = !File("$supportPath\\index.json").existsSync() &&
+      !File("$supportPath\\index.db").existsSync();
 
   await initWindow();
 
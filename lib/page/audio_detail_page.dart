@@ -121,7 +121,7 @@ class AudioDetailPage extends StatelessWidget {
                 Text("路径", style: styleTitle),
                 TextButton(
                   onPressed: () async {
-                    final result = await showInExplorer(path: audio.path);
+                    final result = await showInExplorer(path: audio.playbackPath);
 
                     if (!result && context.mounted) {
                       showTextOnSnackBar("打开失败");
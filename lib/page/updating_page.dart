@@ -53,10 +53,14 @@ class _UpdatingStateViewState extends State<UpdatingStateView> {
   StreamSubscription? _subscription;
 
   void whenIndexUpdated() async {
+    // 被移动或重命名的音乐在歌单和歌词来源中的路径需要随之更新
+    final relocations = {
+      for (final item in takeIndexRelocations()) item.from: item.to,
+    };
     await Future.wait([
       AudioLibrary.initFromIndex(),
-      readPlaylists(),
-      readLyricSources(),
+      readPlaylists(relocations: relocations),
+      readLyricSources(relocations: relocations),
     ]);
     _subscription?.cancel();
     final ctx = context;
@@ -126,7 +126,12 @@ class PlaybackService extends ChangeNotifier {
     try {
       _playlistIndex = audioIndex;
       nowPlaying = playlist[audioIndex];
-      _player.setSource(nowPlaying!.path);
+      final cue = nowPlaying!.cue;
+      _player.setSource(
+        nowPlaying!.playbackPath,
+        start: (cue?.start ?? 0) / 1000,
+        end: cue?.end == null ? null : cue!.end! / 1000,
+      );
       setVolumeDsp(AppPreference.instance.playbackPref.volumeDsp);
 
       playService.lyricService.updateLyric();
@@ -141,7 +146,7 @@ class PlaybackService extends ChangeNotifier {
         artist: nowPlaying!.artist,
         album: nowPlaying!.album,
         duration: (length * 1000).floor(),
-        path: nowPlaying!.path,
+        path: nowPlaying!.playbackPath,
       );
 
       playService.desktopLyricService.canSendMessage.then((canSend) {
@@ -52,6 +52,12 @@ class BassPlayer {
   String? _fPath;
   int? _fstream;
 
+  /// 播放的片段在文件中的开始位置，in seconds。CUE 的虚拟音轨不从文件开头播放
+  double _segmentStart = 0;
+
+  /// 播放的片段在文件中的结束位置，in seconds。null 表示播放到文件结尾
+  double? _segmentEnd;
+
   /// 是否启用 wasapi 独占模式
   bool wasapiExclusive = false;
 
@@ -60,17 +66,21 @@ class BassPlayer {
   final _playerStateStreamController =
       StreamController<PlayerState>.broadcast();
 
-  /// audio's length in seconds
-  double get length => _fstream == null
-      ? 1.0
-      : _bass.BASS_ChannelBytes2Seconds(_fstream!,
-          _bass.BASS_ChannelGetLength(_fstream!, BASS.BASS_POS_BYTE));
+  /// audio's length in seconds, relative to the segment set by [setSource]
+  double get length {
+    if (_fstream == null) return 1.0;
+
+    final fileLength = _bass.BASS_ChannelBytes2Seconds(_fstream!,
+        _bass.BASS_ChannelGetLength(_fstream!, BASS.BASS_POS_BYTE));
+    return (_segmentEnd ?? fileLength) - _segmentStart;
+  }
+
+  /// current position in seconds, relative to the segment set by [setSource]
+  double get position => _fstream == null ? 0.0 : _filePosition - _segmentStart;
 
-  /// current position in seconds
-  double get position => _fstream == null
-      ? 0.0
-      : _bass.BASS_ChannelBytes2Seconds(_fstream!,
-          _bass.BASS_ChannelGetPosition(_fstream!, BASS.BASS_POS_BYTE));
+  /// current position in the file in seconds
+  double get _filePosition => _bass.BASS_ChannelBytes2Seconds(_fstream!,
+      _bass.BASS_ChannelGetPosition(_fstream!, BASS.BASS_POS_BYTE));
 
   PlayerState get playerState {
     if (_fstream == null) {
@@ -122,6 +132,13 @@ class BassPlayer {
       (timer) {
         _positionStreamController.add(position);
 
+        /// check if the segment has completed
+        if (_segmentEnd != null && _filePosition >= _segmentEnd!) {
+          pause();
+          _playerStateStreamController.add(PlayerState.completed);
+          return;
+        }
+
         /// check if the channel has completed
         if (playerState == PlayerState.stopped) {
           _playerStateStreamController.add(PlayerState.completed);
@@ -240,7 +257,7 @@ class BassPlayer {
       }
       wasapiExclusive = exclusive;
       if (_fstream != null && _fPath != null) {
-        setSource(_fPath!);
+        setSource(_fPath!, start: _segmentStart, end: _segmentEnd);
         setVolumeDsp(AppPreference.instance.playbackPref.volumeDsp);
         seek(lastPos);
         start();
@@ -256,7 +273,11 @@ class BassPlayer {
 
   /// if setSource has been called once,
   /// it will pause current channel and free current stream.
-  void setSource(String path) {
+  ///
+  /// [start] and [end] (in seconds) limit playback to a segment of the file,
+  /// e.g. a track of a CUE sheet.
+  /// [length], [position] and [seek] are relative to [start].
+  void setSource(String path, {double start = 0, double? end}) {
     if (_fstream != null) {
       _positionUpdater?.cancel();
       freeFStream();
@@ -278,13 +299,18 @@ class BassPlayer {
     if (handle != 0) {
       _fstream = handle;
       _fPath = path;
+      _segmentStart = start;
+      _segmentEnd = end;
+      if (start > 0) seek(0);
     } else {
       _fstream = null;
       _fPath = null;
+      _segmentStart = 0;
+      _segmentEnd = null;
       switch (_bass.BASS_ErrorGetCode()) {
         case BASS.BASS_ERROR_INIT:
           _bassInit();
-          setSource(path);
+          setSource(path, start: start, end: end);
           break;
         case BASS.BASS_ERROR_NOTAVAIL:
           throw const FormatException(
@@ -470,7 +496,8 @@ class BassPlayer {
     _positionUpdater?.cancel();
   }
 
-  /// set channel's position to given [position]
+  /// set channel's position to given [position],
+  /// relative to the segment set by [setSource]
   /// don't check if the position is valid.
   ///
   /// do nothing if [setSource] hasn't been called
@@ -479,7 +506,7 @@ class BassPlayer {
 
     if (_bass.BASS_ChannelSetPosition(
           _fstream!,
-          _bass.BASS_ChannelSeconds2Bytes(_fstream!, position),
+          _bass.BASS_ChannelSeconds2Bytes(_fstream!, _segmentStart + position),
           BASS.BASS_POS_BYTE,
         ) ==
         0) {
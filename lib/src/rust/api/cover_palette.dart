@@ -0,0 +1,83 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_argb`, `_best_swatch`, `_contrast_ratio`, `_extract_palette`, `_lock_cover_palette_cache`, `_modified_stamp`, `_on_color`, `_relative_luminance`, `_saturation_and_lightness`, `invalidate_cover_palettes`, `to_palette_color`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CachedPalette`, `CoverPaletteCache`, `Swatch`, `Target`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// for Flutter  
+/// 从 path 的封面（和 [super::tag_reader::get_picture_from_path] 使用的封面相同）中提取调色板。
+/// album_key 相同的音乐（例如传入 专辑艺术家 + 专辑名）共用第一次提取的结果；
+/// 为 None 时每首音乐单独缓存。最多缓存 256 个调色板。
+/// 没有封面或者封面无法解码时返回 None
+Future<CoverPalette?>  getCoverPalette({required String path , String? albumKey }) => RustLib.instance.api.crateApiCoverPaletteGetCoverPalette(path: path, albumKey: albumKey);
+
+/// for Flutter  
+/// 清空 [get_cover_palette] 的缓存
+void  clearCoverPaletteCache() => RustLib.instance.api.crateApiCoverPaletteClearCoverPaletteCache();
+
+            class CoverPalette  {
+                /// 封面中占比最大的颜色
+final PaletteColor dominant;
+/// 饱和度高、亮度适中的颜色。封面中没有这样的颜色时为 None
+final PaletteColor? vibrant;
+/// 饱和度低、亮度适中的颜色。封面中没有这样的颜色时为 None
+final PaletteColor? muted;
+
+                const CoverPalette({required this.dominant ,this.vibrant ,this.muted ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => dominant.hashCode^vibrant.hashCode^muted.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CoverPalette &&
+                runtimeType == other.runtimeType
+                && dominant == other.dominant&& vibrant == other.vibrant&& muted == other.muted;
+        
+            }
+
+class PaletteColor  {
+                /// a, r, g, b
+final (int,int,int,int) color;
+/// 显示在 color 上的文字、图标的颜色。a, r, g, b
+/// 和 color 的对比度不低于 4.5:1，尽量保留 color 的色相
+final (int,int,int,int) onColor;
+/// 这种颜色在封面中的占比，0.0 ~ 1.0
+final double population;
+
+                const PaletteColor({required this.color ,required this.onColor ,required this.population ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => color.hashCode^onColor.hashCode^population.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PaletteColor &&
+                runtimeType == other.runtimeType
+                && color == other.color&& onColor == other.onColor&& population == other.population;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,50 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_cue_sheets_in`, `_modified_secs`, `_non_empty`, `_parse_cue_sheet`, `_parse_flac_cue_sheet`, `_parse_time`, `_read_flac_cue_sheet`, `_resolve_file`, `_split_image`, `_u64_be`, `_unknown_tag_text`, `_unquote`, `cue_track_of`, `decode_text`, `embedded_cue_sheet_of`, `is_cue_image`, `is_usable`, `read_folder_cue_sheets`, `source_file_of`, `split_by_embedded_cue_sheet`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CueFile`, `CueSheetTrack`, `CueSheet`, `FolderCueSheets`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`, `default`
+
+
+            
+
+            /// 虚拟音轨在整轨文件中的位置
+class CueTrack  {
+                /// .cue 文件的绝对路径。内嵌的 CUE 为整轨文件的路径
+final String cuePath;
+/// 整轨文件的绝对路径，播放时打开这个文件
+final String file;
+/// 在整轨文件中的开始位置（INDEX 01），in ms
+final BigInt start;
+/// 在整轨文件中的结束位置（下一个音轨的 INDEX 01），in ms。None 表示播放到文件结尾
+final BigInt? end;
+/// CUE 是否内嵌在整轨文件中
+final bool embedded;
+
+                const CueTrack({required this.cuePath ,required this.file ,required this.start ,this.end ,required this.embedded ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => cuePath.hashCode^file.hashCode^start.hashCode^end.hashCode^embedded.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CueTrack &&
+                runtimeType == other.runtimeType
+                && cuePath == other.cuePath&& file == other.file&& start == other.start&& end == other.end&& embedded == other.embedded;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,40 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'cue_sheet.dart';
+import 'midi_reader.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'replay_gain.dart';
+import 'tag_reader.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_insert_folder`, `_meta_json`, `_migrate`, `_open`, `_set_meta_json`, `_upsert_audio`, `apply_audio_changes`, `audio`, `audios_under`, `cover_placeholders`, `exists_in`, `folder_records`, `folder`, `folders`, `import_in`, `insert_folder`, `lock`, `meta_json`, `open_in`, `path_in`, `remove_folder`, `replace_all`, `roots`, `rules`, `scan_filter`, `set_meta_json`, `set_roots`, `update_folder`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ConnectionGuard`, `IndexDatabase`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref_mut`, `deref`
+
+
+            /// for Flutter  
+/// 把 index_path/index.json 导入到 index_path/index.db。
+/// 导入后建立/更新索引都会使用 index.db，index.json 保持导入时的内容，不再更新。
+///
+/// 和 [super::tag_reader::update_index] 一样可以通过 [super::tag_reader::cancel_index_action] 取消，
+/// 取消时不会创建 index.db。
+Stream<IndexActionState>  migrateIndexToDatabase({required String indexPath }) => RustLib.instance.api.crateApiIndexDbMigrateIndexToDatabase(indexPath: indexPath);
+
+/// for Flutter  
+/// 读取索引中的所有文件夹。存在 index.db 时从数据库读取，否则读取 index.json
+Future<List<AudioFolder>>  getIndexedFolders({required String indexPath }) => RustLib.instance.api.crateApiIndexDbGetIndexedFolders(indexPath: indexPath);
+
+/// for Flutter  
+/// 读取索引中路径为 folder_path 的文件夹
+Future<AudioFolder?>  getIndexedFolder({required String indexPath , required String folderPath }) => RustLib.instance.api.crateApiIndexDbGetIndexedFolder(indexPath: indexPath, folderPath: folderPath);
+
+/// for Flutter  
+/// 读取索引中路径为 audio_path 的音乐
+Future<Audio?>  getIndexedAudio({required String indexPath , required String audioPath }) => RustLib.instance.api.crateApiIndexDbGetIndexedAudio(indexPath: indexPath, audioPath: audioPath);
+
+            
+            
\ No newline at end of file
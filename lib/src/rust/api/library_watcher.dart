@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'cue_sheet.dart';
+import 'midi_reader.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'replay_gain.dart';
+import 'tag_reader.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_affected_audios`, `_apply_changes`, `_collect_files`, `_diff`, `_folder_modified`, `_is_in_excluded_folder`, `_lock_watcher`, `_watch_targets`, `refresh_library_watch`, `refresh`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LibraryWatcher`
+
+
+            /// for Flutter  
+/// 监听 index_path 下索引的音乐库。文件被添加、删除或修改时更新索引，
+/// 并把变化发送到 sink。
+///
+/// 递归地监听建立索引时传入的根目录，根目录下新建的文件夹中的音乐也会被添加；
+/// 旧版本建立的索引没有保存根目录，只监听索引中的文件夹本身。
+/// 文件夹被删除、移动或重命名时，其中所有的音乐都会被更新。
+/// [super::tag_reader::update_index] 等操作修改了根目录后会自动更新监听的路径。
+///
+/// 变化逐条写入 index.db，每批变化一个事务。index_path 下只有 index.json 时，
+/// 会先把它导入到 index.db（见 [super::index_db::migrate_index_to_database]），之后 index.json 不再更新。
+///
+/// 再次调用会替换之前的监听。
+Stream<LibraryEvent>  watchLibrary({required String indexPath }) => RustLib.instance.api.crateApiLibraryWatcherWatchLibrary(indexPath: indexPath);
+
+/// for Flutter  
+/// 停止 [watch_library] 开始的监听
+Future<void>  stopWatchingLibrary() => RustLib.instance.api.crateApiLibraryWatcherStopWatchingLibrary();
+
+            /// 发送给 Dart 的文件变化
+class LibraryEvent  {
+                final LibraryEventKind kind;
+/// 音乐文件的绝对路径
+final String path;
+/// Added, Modified: 重新读取到的音乐；Removed: None
+final Audio? audio;
+
+                const LibraryEvent({required this.kind ,required this.path ,this.audio ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^path.hashCode^audio.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibraryEvent &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& path == other.path&& audio == other.audio;
+        
+            }
+
+enum LibraryEventKind {
+                    added,
+removed,
+modified,
+                    ;
+                    
+                }
+            
\ No newline at end of file
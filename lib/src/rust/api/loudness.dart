@@ -0,0 +1,102 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'tag_reader.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_lock_loudness_jobs`, `_measure_path`, `_measure`, `_new_ebur128`, `_report`, `_track_loudness`, `_write_replay_gain`, `begin`, `is_cancelled`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LoudnessJob`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `drop`
+
+
+            /// for Flutter  
+/// 取得一个新的响度分析编号，传给 [analyze_track_loudness] 或 [analyze_album_loudness]，
+/// 之后可以通过 [cancel_loudness_analysis] 只取消这一次分析
+BigInt  newLoudnessAnalysisJob() => RustLib.instance.api.crateApiLoudnessNewLoudnessAnalysisJob();
+
+/// for Flutter  
+/// 取消编号为 job 的分析，它会返回 Err("已取消")。其他分析不受影响，已经结束的分析会被忽略
+void  cancelLoudnessAnalysis({required BigInt job }) => RustLib.instance.api.crateApiLoudnessCancelLoudnessAnalysis(job: job);
+
+/// for Flutter  
+/// 解码 path 并测量它的响度。进度通过 sink 发送。
+/// write_tags 为 true 时把结果写入 ReplayGain 标签（Opus 写入 R128_TRACK_GAIN），虚拟音轨不会写入。
+/// 不支持的格式（见 [UNSUPPORTED_FORMATS]）、无法解码或者完全静音的音乐返回 Err。
+/// job 来自 [new_loudness_analysis_job]
+Stream<IndexActionState>  analyzeTrackLoudness({required String path , required bool writeTags , required BigInt job }) => RustLib.instance.api.crateApiLoudnessAnalyzeTrackLoudness(path: path, writeTags: writeTags, job: job);
+
+/// for Flutter  
+/// 测量 paths 中每首音乐的响度以及整张专辑的响度。进度通过 sink 发送。
+/// 无法分析的音乐会被跳过并记录到 [AlbumLoudness::skipped]，所有音乐都无法分析时返回 Err。
+/// write_tags 为 true 时把结果写入 ReplayGain 标签，写入失败的音乐和虚拟音轨会被跳过并记录日志。
+/// job 来自 [new_loudness_analysis_job]
+Stream<IndexActionState>  analyzeAlbumLoudness({required List<String> paths , required bool writeTags , required BigInt job }) => RustLib.instance.api.crateApiLoudnessAnalyzeAlbumLoudness(paths: paths, writeTags: writeTags, job: job);
+
+            class AlbumLoudness  {
+                final List<TrackLoudness> tracks;
+/// 无法解码或者完全静音的音乐，它们不参与专辑响度的计算，也不会被写入标签
+final List<String> skipped;
+/// 整张专辑的 integrated loudness，in LUFS
+final double integrated;
+/// 整张专辑的 loudness range，in LU
+final double loudnessRange;
+/// 所有音乐中最大的 true peak
+final double truePeak;
+/// 整张专辑相对于 ReplayGain 2.0 参考响度的增益，in dB
+final double gain;
+
+                const AlbumLoudness({required this.tracks ,required this.skipped ,required this.integrated ,required this.loudnessRange ,required this.truePeak ,required this.gain ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => tracks.hashCode^skipped.hashCode^integrated.hashCode^loudnessRange.hashCode^truePeak.hashCode^gain.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AlbumLoudness &&
+                runtimeType == other.runtimeType
+                && tracks == other.tracks&& skipped == other.skipped&& integrated == other.integrated&& loudnessRange == other.loudnessRange&& truePeak == other.truePeak&& gain == other.gain;
+        
+            }
+
+class TrackLoudness  {
+                final String path;
+/// integrated loudness, in LUFS
+final double integrated;
+/// loudness range, in LU
+final double loudnessRange;
+/// 所有声道中最大的 true peak，线性振幅
+final double truePeak;
+/// 相对于 ReplayGain 2.0 参考响度的增益，in dB
+final double gain;
+
+                const TrackLoudness({required this.path ,required this.integrated ,required this.loudnessRange ,required this.truePeak ,required this.gain ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^integrated.hashCode^loudnessRange.hashCode^truePeak.hashCode^gain.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TrackLoudness &&
+                runtimeType == other.runtimeType
+                && path == other.path&& integrated == other.integrated&& loudnessRange == other.loudnessRange&& truePeak == other.truePeak&& gain == other.gain;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_decode_text`, `_read_track`, `_read_vlq`, `_ticks_to_seconds`, `_u16_be`, `_u32_be`, `read_midi`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Division`, `MidiTrack`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            
+
+            class MidiInfo  {
+                /// 0: 单音轨，1: 多音轨同时播放，2: 多个独立的序列
+final int format;
+final int tracks;
+/// pulses per quarter note。使用 SMPTE 时间码时为 None
+final int? ppq;
+
+                const MidiInfo({required this.format ,required this.tracks ,this.ppq ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => format.hashCode^tracks.hashCode^ppq.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MidiInfo &&
+                runtimeType == other.runtimeType
+                && format == other.format&& tracks == other.tracks&& ppq == other.ppq;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,41 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'cue_sheet.dart';
+import 'midi_reader.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'replay_gain.dart';
+import 'tag_reader.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_audio_of_entry`, `_display_name_of`, `_encode_location`, `_escape_xml`, `_file_uri`, `_find_unquoted_comma`, `_has_drive_letter`, `_non_empty`, `_normalize`, `_parse_duration_secs`, `_parse_m3u`, `_parse_pls`, `_parse_xspf`, `_playlist_bytes`, `_read_audio`, `_read_cue_audios`, `_relative_path`, `_resolve_location`, `_split_display_name`, `_write_m3u`, `_write_pls`, `_write_xspf`, `of`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PlaylistEntry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// for Flutter  
+/// 读取 M3U/M3U8、PLS 或 XSPF 播放列表（按扩展名判断格式）中的音乐，相对路径相对于播放列表所在的文件夹。
+/// 文件能读取时使用文件的标签，否则（例如文件不存在）只有播放列表中记录的标题、艺术家和时长。
+/// 网络流（http:// 等）会被忽略
+Future<List<Audio>>  readPlaylist({required String path }) => RustLib.instance.api.crateApiPlaylistReadPlaylist(path: path);
+
+/// for Flutter  
+/// 把 paths 按顺序写入路径为 path 的播放列表，覆盖已有的文件。所有格式都使用 UTF-8 编码。
+/// relative 为 true 时写入相对于播放列表所在文件夹的路径，和播放列表不在同一个盘上的文件仍然写入绝对路径。
+/// 能读取到标签时同时写入标题、艺术家和时长
+Future<void>  writePlaylist({required String path , required List<String> paths , required PlaylistFormat format , required bool relative }) => RustLib.instance.api.crateApiPlaylistWritePlaylist(path: path, paths: paths, format: format, relative: relative);
+
+            enum PlaylistFormat {
+                    /// 扩展 M3U。使用 UTF-8 编码写入，读取时会猜测编码
+m3U,
+m3U8,
+pls,
+xspf,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_itun_norm`, `_parse_gain`, `_parse_itun_norm`, `_parse_peak`, `_parse_r128_gain`, `_unknown_item`, `read_from_tag`, `replay_gain_to_r128`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            
+
+            /// gain: 需要调整的音量，in dB，相对于 ReplayGain 2.0 的参考响度（-18 LUFS）
+/// peak: 峰值的线性振幅，1.0 为满刻度
+class ReplayGain  {
+                final double? trackGain;
+final double? trackPeak;
+final double? albumGain;
+final double? albumPeak;
+
+                const ReplayGain({this.trackGain ,this.trackPeak ,this.albumGain ,this.albumPeak ,});
+
+                static Future<ReplayGain>  default_()=>RustLib.instance.api.crateApiReplayGainReplayGainDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => trackGain.hashCode^trackPeak.hashCode^albumGain.hashCode^albumPeak.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReplayGain &&
+                runtimeType == other.runtimeType
+                && trackGain == other.trackGain&& trackPeak == other.trackPeak&& albumGain == other.albumGain&& albumPeak == other.albumPeak;
+        
+            }
+            
\ No newline at end of file
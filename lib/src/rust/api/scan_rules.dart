@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_is_hidden`, `_read_stored_scan_options`, `cover_placeholders`, `fill_cover_placeholder`, `fill_folder_cover`, `folder_cover_of`, `folder_cover_placeholder_of`, `is_audio_excluded`, `is_file_excluded`, `is_folder_excluded`, `is_path_excluded`, `new`, `read_audio`, `read_audios`, `read_stored_scan_filter`, `read_stored_scan_rules`, `rules`, `with_cover_placeholders`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ScanFilter`, `StoredScanOptions`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`
+
+
+            /// for Flutter  
+/// 读取和 index_path 下的索引保存在一起的扫描规则
+Future<ScanRules>  getScanRules({required String indexPath }) => RustLib.instance.api.crateApiScanRulesGetScanRules(indexPath: indexPath);
+
+/// for Flutter  
+/// 建立 index_path 下的索引时是否计算了封面占位图，见 [super::tag_reader::build_index_with_rules]
+Future<bool>  getCoverPlaceholdersEnabled({required String indexPath }) => RustLib.instance.api.crateApiScanRulesGetCoverPlaceholdersEnabled(indexPath: indexPath);
+
+            class ScanRules  {
+                /// 路径匹配其中任意一个 glob 的文件和文件夹不会被索引，例如 `**/Samples/**`
+final List<String> excludeGlobs;
+/// 路径匹配其中任意一个正则表达式的文件和文件夹不会被索引
+final List<String> excludeRegexes;
+/// 跳过隐藏的文件夹：名字以 . 开头，或者在 Windows 上带有隐藏属性。
+/// 建立索引时传入的根目录本身即使是隐藏的也会被扫描
+final bool skipHidden;
+/// 跳过包含 .nomedia 或 .noindex 的文件夹及其所有子文件夹
+final bool respectMarkerFiles;
+/// 进入符号链接（以及 Windows 上的目录联接）指向的文件夹。
+/// 为 false 时跳过指向文件夹的符号链接；指向音乐文件的符号链接总是会被读取
+final bool followSymlinks;
+/// 时长小于它的音乐不会被索引。in secs
+final BigInt minDuration;
+/// 小于它的文件不会被索引。in bytes
+final BigInt minFileSize;
+/// 寻找文件夹封面时使用的文件名（不含扩展名，不区分大小写），越靠前越优先。
+/// 为空时使用默认值：cover, folder, front, albumart。
+/// 修改后已经建立的索引中的 [super::tag_reader::AudioFolder::cover] 在文件夹下次被更新时才会改变
+final List<String> folderCoverNames;
+
+                const ScanRules({required this.excludeGlobs ,required this.excludeRegexes ,required this.skipHidden ,required this.respectMarkerFiles ,required this.followSymlinks ,required this.minDuration ,required this.minFileSize ,required this.folderCoverNames ,});
+
+                static Future<ScanRules>  default_()=>RustLib.instance.api.crateApiScanRulesScanRulesDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => excludeGlobs.hashCode^excludeRegexes.hashCode^skipHidden.hashCode^respectMarkerFiles.hashCode^followSymlinks.hashCode^minDuration.hashCode^minFileSize.hashCode^folderCoverNames.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ScanRules &&
+                runtimeType == other.runtimeType
+                && excludeGlobs == other.excludeGlobs&& excludeRegexes == other.excludeRegexes&& skipHidden == other.skipHidden&& respectMarkerFiles == other.respectMarkerFiles&& followSymlinks == other.followSymlinks&& minDuration == other.minDuration&& minFileSize == other.minFileSize&& folderCoverNames == other.folderCoverNames;
+        
+            }
+            
\ No newline at end of file
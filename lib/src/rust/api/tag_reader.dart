@@ -4,68 +4,287 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'cue_sheet.dart';
+import 'midi_reader.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'replay_gain.dart';
+import 'scan_rules.dart';
 
-// These functions are ignored because they are not marked as `pub`: `_get_lyric_from_lofty`, `_get_lyric_from_lrc_file`, `_get_picture_by_lofty`, `_get_picture_by_windows`, `_update_index_below_1_1_0`, `new_with_path`, `read_by_lofty`, `read_by_win_music_properties`, `read_from_folder_recursively`, `read_from_folder`, `read_from_path`, `to_json_value`, `to_json_value`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AudioFolder`, `Audio`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`
 
-/// for Flutter
-/// 如果无法通过 Lofty 获取则通过 Windows 获取
-Future<Uint8List?> getPictureFromPath(
-        {required String path, required int width, required int height}) =>
-    RustLib.instance.api.crateApiTagReaderGetPictureFromPath(
-        path: path, width: width, height: height);
+            // These functions are ignored because they are not marked as `pub`: `_build_index`, `_comment_of`, `_diff_by_path`, `_embedded_pictures`, `_file_type_of`, `_get_lyric_from_lofty`, `_get_lyric_from_lrc_file`, `_get_picture_by_lofty`, `_get_picture_by_windows`, `_get_picture_from_folder`, `_is_folder_removed`, `_migrate_index_from_0`, `_migrate_index_from_110`, `_scan_folders`, `_update_index_database`, `apply_lofty_tag`, `begin`, `build_scan_thread_pool`, `compute_id`, `effective_roots`, `file_path`, `fill_identity`, `finish_folder`, `get_original_picture`, `into_sorted_result`, `is_in_roots`, `is_root`, `lock_index`, `lock_result`, `lock_scaned_folders`, `new_with_path`, `new`, `progress_of`, `progress`, `read_by_lofty`, `read_by_win_music_properties`, `read_current`, `read_from_folder_recursively`, `read_from_folder`, `read_from_path`, `read_with_embedded_cue_sheet`, `read`, `report_index_action_cancelled`, `report`, `resize_picture`, `root_index_of`, `save_relocations`, `update`, `version_of`, `write`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AudioIndex`, `IndexAction`, `IndexChanges`, `ScanContext`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `drop`, `eq`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
 
-/// for Flutter
+
+            /// for Flutter  
+/// 取消正在进行或正在等待开始的 [build_index_from_folders_recursively] 或 [update_index]。
+/// 被取消的操作会发送一个 message 为 "已取消" 的 [IndexActionState] 后正常结束。
+/// 没有操作时什么也不做，不会影响之后开始的操作
+void  cancelIndexAction() => RustLib.instance.api.crateApiTagReaderCancelIndexAction();
+
+/// for Flutter  
+/// 正在进行或上一次进行的建立/更新索引的操作是否是被取消的
+bool  isIndexActionCancelled() => RustLib.instance.api.crateApiTagReaderIsIndexActionCancelled();
+
+/// for Flutter  
+/// 取出上一次 [update_index] 检测到的被移动或重命名的音乐。
+/// 这些音乐在索引中已经换成了新的路径，以路径为键的数据（歌单、歌词来源等）需要随之更新。
+/// Flutter 端在 [update_index] 结束后调用。
+List<AudioRelocation>  takeIndexRelocations() => RustLib.instance.api.crateApiTagReaderTakeIndexRelocations();
+
+/// for Flutter  
+/// 列出 path 所有标签中的内嵌图片，不包含图片数据。虚拟音轨读取整轨文件
+Future<List<EmbeddedPicture>>  getEmbeddedPictures({required String path }) => RustLib.instance.api.crateApiTagReaderGetEmbeddedPictures(path: path);
+
+/// for Flutter  
+/// 读取 [get_embedded_pictures] 中序号为 index 的图片的原始数据
+Future<Uint8List?>  getEmbeddedPicture({required String path , required int index }) => RustLib.instance.api.crateApiTagReaderGetEmbeddedPicture(path: path, index: index);
+
+/// for Flutter  
+/// 如果无法通过 Lofty 获取则使用文件夹中的封面图片（cover.jpg 等），再没有的话通过 Windows 获取。
+/// 启用了缩略图缓存时从缓存中读取，缓存无法使用时直接读取，见 [super::thumbnail_cache::init_thumbnail_cache]
+Future<Uint8List?>  getPictureFromPath({required String path , required int width , required int height }) => RustLib.instance.api.crateApiTagReaderGetPictureFromPath(path: path, width: width, height: height);
+
+/// for Flutter   
 /// 只支持读取 ID3V2, VorbisComment, Mp4Ilst 存储的内嵌歌词
-/// 以及相同目录相同文件名的 .lrc 外挂歌词（utf-8 or utf-16）
-Future<String?> getLyricFromPath({required String path}) =>
-    RustLib.instance.api.crateApiTagReaderGetLyricFromPath(path: path);
+/// 以及相同目录相同文件名的 .lrc 外挂歌词（utf-8 or utf-16）。
+/// 虚拟音轨使用整轨文件的歌词
+Future<String?>  getLyricFromPath({required String path }) => RustLib.instance.api.crateApiTagReaderGetLyricFromPath(path: path);
 
-/// for Flutter
+/// for Flutter  
 /// 扫描给定路径下所有子文件夹（包括自己）的音乐文件并把索引保存在 index_path/index.json。
-Stream<IndexActionState> buildIndexFromFoldersRecursively(
-        {required List<String> folders, required String indexPath}) =>
-    RustLib.instance.api.crateApiTagReaderBuildIndexFromFoldersRecursively(
-        folders: folders, indexPath: indexPath);
+/// 文件夹的遍历和标签的读取在有界线程池中并行进行。
+///
+/// 使用和原来的索引保存在一起的扫描规则和 cover_placeholders，见 [build_index_with_rules]。
+///
+/// 可以通过 [cancel_index_action] 取消，取消后不会写入 index.json，原来的索引保持不变。
+///
+/// index_path 下存在 index.db 时，索引改为写入 index.db，index.json 不会被修改，见 [migrate_index_to_database]。
+Stream<IndexActionState>  buildIndexFromFoldersRecursively({required List<String> folders , required String indexPath }) => RustLib.instance.api.crateApiTagReaderBuildIndexFromFoldersRecursively(folders: folders, indexPath: indexPath);
 
-/// for Flutter
-/// 读取 index_path/index.json，检查更新。不可能重新读取被修改的文件夹下所有的音乐标签，这样太耗时。
+/// for Flutter  
+/// 和 [build_index_from_folders_recursively] 相同，但是使用给定的扫描规则。
+///
+/// cover_placeholders 为 true 时计算每首音乐和每个文件夹的封面的 BlurHash，
+/// 见 [Audio::cover_placeholder] 和 [AudioFolder::cover_placeholder]。
+/// 需要解码每首音乐的封面，会让建立索引变慢。
 ///
-/// [LOWEST_VERSION] 指定可以继承的 index 的最低版本。
-/// 如果 index version < [LOWEST_VERSION] 或者是 index 根本没有 version 再或者格式不符合要求，就转到
-/// [_update_index_below_1_1_0] 更新 index；
-/// 如果 index version >= [LOWEST_VERSION] 则进行更新。
+/// 规则和 cover_placeholders 会和索引保存在一起，之后的 [update_index] 也会使用它们。
 ///
-/// 如果文件夹不存在，删除记录。
+/// 规则中的 glob 或正则表达式不合法时返回 Err，原来的索引保持不变。
+Stream<IndexActionState>  buildIndexWithRules({required List<String> folders , required String indexPath , required ScanRules rules , required bool coverPlaceholders }) => RustLib.instance.api.crateApiTagReaderBuildIndexWithRules(folders: folders, indexPath: indexPath, rules: rules, coverPlaceholders: coverPlaceholders);
+
+/// for Flutter   
+/// 读取 index_path/index.json，检查更新。不可能重新读取被修改的文件夹下所有的音乐标签，这样太耗时。  
+///
+/// index.json 会先按照 [INDEX_MIGRATIONS] 迁移到 [INDEX_VERSION]。
+/// index.json 不合法（无法解析、缺少字段或者版本不受支持）时返回 Err，不会修改原来的索引。
+///
+/// 如果文件夹不存在，删除记录。  
 /// 如果文件夹被修改（再次读取到的 modified > 记录的 modified），就更新它。没有则跳过它
 /// 1. 遍历该文件夹索引，判断文件是否存在，不存在则删除记录
 /// 2. 遍历该文件夹索引，如果文件被修改（再次读取到的 modified > 记录的 modified），重新读取标签；没有则跳过它
-/// 3. 遍历该文件夹，添加新增（读取到的 created > 记录的 latest）的音乐文件
-Stream<IndexActionState> updateIndex({required String indexPath}) =>
-    RustLib.instance.api.crateApiTagReaderUpdateIndex(indexPath: indexPath);
-
-class IndexActionState {
-  /// completed / total
-  final double progress;
-
-  /// describe action state
-  final String message;
-
-  const IndexActionState({
-    required this.progress,
-    required this.message,
-  });
-
-  @override
-  int get hashCode => progress.hashCode ^ message.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is IndexActionState &&
-          runtimeType == other.runtimeType &&
-          progress == other.progress &&
-          message == other.message;
-}
+/// 3. 遍历该文件夹，添加新增（路径不在索引中）并且满足扫描规则的音乐文件
+///
+/// 之后扫描建立索引时传入的根目录，添加其中新增的文件夹。已经不存在的根目录及其下所有文件夹的记录会被删除。
+/// 旧版本建立的索引没有保存根目录，不会寻找新增的文件夹。
+///
+/// 被删除的音乐和新增的音乐 [Audio::id] 相同时，视为被移动或重命名，可以通过 [take_index_relocations] 取得。
+///
+/// 可以通过 [cancel_index_action] 取消。取消时正在更新的文件夹保持原样，
+/// 已经更新完的文件夹会被写入 index.json。
+///
+/// index_path 下存在 index.db 时改为更新 index.db，每个文件夹更新完后立即写入，index.json 不会被修改。
+Stream<IndexActionState>  updateIndex({required String indexPath }) => RustLib.instance.api.crateApiTagReaderUpdateIndex(indexPath: indexPath);
+
+            class Audio  {
+                final String title;
+final String artist;
+final String album;
+final int? track;
+/// in secs
+final BigInt duration;
+/// kbps
+final int? bitrate;
+final int? sampleRate;
+/// absolute path
+final String path;
+/// secs since UNIX_EPOCH
+final BigInt modified;
+/// secs since UNIX_EPOCH
+final BigInt created;
+/// 标签获取方式
+final String? by;
+/// 文件大小，in bytes
+final BigInt size;
+/// 由文件大小和标签计算出的标识，文件被移动或重命名后保持不变。见 [Audio::compute_id]
+final String id;
+final String? albumArtist;
+final int? trackTotal;
+final int? disc;
+final int? discTotal;
+final int? year;
+/// 完整的录制日期，例如 "2014-10-27"
+final String? date;
+/// 多个流派以 "/" 分隔
+final String? genre;
+/// 多个作曲家以 "/" 分隔
+final String? composer;
+final String? comment;
+/// bits per sample
+final int? bitDepth;
+final int? channels;
+/// 文件格式，例如 "Flac"、"Mpeg"、"Mp4"。Lofty 不支持的格式为大写的扩展名，例如 "WMA"
+final String? fileType;
+/// 从 ReplayGain、R128 或 iTunNORM 标签中读取的音量标准化信息
+final ReplayGain? replayGain;
+/// 封面的 BlurHash，可以在封面加载完成前显示。
+/// 只有建立索引时 cover_placeholders 为 true（见 [build_index_with_rules]）才会计算
+final String? coverPlaceholder;
+/// MIDI 文件的格式、音轨数和 PPQ，其他格式为 None
+final MidiInfo? midi;
+/// CUE 分割出的虚拟音轨在整轨文件中的位置，普通的音乐文件为 None。
+/// 虚拟音轨的 path 为 整轨文件的路径#音轨号，见 [read_folder_cue_sheets]
+final CueTrack? cue;
+
+                const Audio({required this.title ,required this.artist ,required this.album ,this.track ,required this.duration ,this.bitrate ,this.sampleRate ,required this.path ,required this.modified ,required this.created ,this.by ,required this.size ,required this.id ,this.albumArtist ,this.trackTotal ,this.disc ,this.discTotal ,this.year ,this.date ,this.genre ,this.composer ,this.comment ,this.bitDepth ,this.channels ,this.fileType ,this.replayGain ,this.coverPlaceholder ,this.midi ,this.cue ,});
+
+                static Future<Audio>  default_()=>RustLib.instance.api.crateApiTagReaderAudioDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => title.hashCode^artist.hashCode^album.hashCode^track.hashCode^duration.hashCode^bitrate.hashCode^sampleRate.hashCode^path.hashCode^modified.hashCode^created.hashCode^by.hashCode^size.hashCode^id.hashCode^albumArtist.hashCode^trackTotal.hashCode^disc.hashCode^discTotal.hashCode^year.hashCode^date.hashCode^genre.hashCode^composer.hashCode^comment.hashCode^bitDepth.hashCode^channels.hashCode^fileType.hashCode^replayGain.hashCode^coverPlaceholder.hashCode^midi.hashCode^cue.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Audio &&
+                runtimeType == other.runtimeType
+                && title == other.title&& artist == other.artist&& album == other.album&& track == other.track&& duration == other.duration&& bitrate == other.bitrate&& sampleRate == other.sampleRate&& path == other.path&& modified == other.modified&& created == other.created&& by == other.by&& size == other.size&& id == other.id&& albumArtist == other.albumArtist&& trackTotal == other.trackTotal&& disc == other.disc&& discTotal == other.discTotal&& year == other.year&& date == other.date&& genre == other.genre&& composer == other.composer&& comment == other.comment&& bitDepth == other.bitDepth&& channels == other.channels&& fileType == other.fileType&& replayGain == other.replayGain&& coverPlaceholder == other.coverPlaceholder&& midi == other.midi&& cue == other.cue;
+        
+            }
+
+class AudioFolder  {
+                final String path;
+/// secs since UNIX_EPOCH
+final BigInt modified;
+/// biggest created in audios. secs since UNIX_EPOCH
+final BigInt latest;
+final List<Audio> audios;
+/// 文件夹中的封面图片（cover.jpg 等）的绝对路径，见 [folder_cover_of]
+final String? cover;
+/// cover 的 BlurHash，和 [Audio::cover_placeholder] 相同，只有需要时才会计算
+final String? coverPlaceholder;
+
+                const AudioFolder({required this.path ,required this.modified ,required this.latest ,required this.audios ,this.cover ,this.coverPlaceholder ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^modified.hashCode^latest.hashCode^audios.hashCode^cover.hashCode^coverPlaceholder.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AudioFolder &&
+                runtimeType == other.runtimeType
+                && path == other.path&& modified == other.modified&& latest == other.latest&& audios == other.audios&& cover == other.cover&& coverPlaceholder == other.coverPlaceholder;
+        
+            }
+
+/// 一首音乐从 from 被移动或重命名到了 to
+class AudioRelocation  {
+                /// 音乐的 [Audio::id]
+final String id;
+final String from;
+final String to;
+
+                const AudioRelocation({required this.id ,required this.from ,required this.to ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^from.hashCode^to.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AudioRelocation &&
+                runtimeType == other.runtimeType
+                && id == other.id&& from == other.from&& to == other.to;
+        
+            }
+
+class EmbeddedPicture  {
+                /// 在 [get_embedded_pictures] 结果中的序号，用于 [get_embedded_picture]
+final int index;
+/// 例如 "CoverFront", "CoverBack", "Artist"
+final String pictureType;
+final String? mimeType;
+final String? description;
+/// 无法解析图片时为 0
+final int width;
+final int height;
+/// in bytes
+final BigInt size;
+
+                const EmbeddedPicture({required this.index ,required this.pictureType ,this.mimeType ,this.description ,required this.width ,required this.height ,required this.size ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^pictureType.hashCode^mimeType.hashCode^description.hashCode^width.hashCode^height.hashCode^size.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbeddedPicture &&
+                runtimeType == other.runtimeType
+                && index == other.index&& pictureType == other.pictureType&& mimeType == other.mimeType&& description == other.description&& width == other.width&& height == other.height&& size == other.size;
+        
+            }
+
+class IndexActionState  {
+                /// completed / total
+final double progress;
+/// describe action state
+final String message;
+
+                const IndexActionState({required this.progress ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => progress.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexActionState &&
+                runtimeType == other.runtimeType
+                && progress == other.progress&& message == other.message;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,110 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'cue_sheet.dart';
+import 'midi_reader.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'replay_gain.dart';
+import 'tag_reader.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_encode_jpeg`, `_prepare_cover`, `_set_cover`, `_set_or_remove_number`, `_set_or_remove`, `primary_tag_mut`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// for Flutter  
+/// 把 changes 写入 path 的主标签（格式由文件类型决定，例如 MP3 为 ID3v2，FLAC 为 Vorbis Comments），
+/// 没有主标签时创建一个。没有修改的字段和其他标签保持不变。
+///
+/// 返回重新读取的音乐，可以直接替换索引中的记录。
+///
+/// CUE 分割出的虚拟音轨（path#NN）的标签来自 CUE，不能通过这里修改，返回 Err
+Future<Audio>  writeAudioTags({required String path , required AudioTagChanges changes }) => RustLib.instance.api.crateApiTagWriterWriteAudioTags(path: path, changes: changes);
+
+/// for Flutter  
+/// 把 image_path 的图片作为封面（CoverFront）写入 path，替换原来的封面，其他图片保持不变。
+/// path 为 CUE 虚拟音轨时写入它所在的整轨文件
+Future<void>  setCoverFromFile({required String path , required String imagePath , required CoverOptions options }) => RustLib.instance.api.crateApiTagWriterSetCoverFromFile(path: path, imagePath: imagePath, options: options);
+
+/// for Flutter  
+/// 和 [set_cover_from_file] 相同，图片来自内存中的数据
+Future<void>  setCoverFromBytes({required String path , required List<int> image , required CoverOptions options }) => RustLib.instance.api.crateApiTagWriterSetCoverFromBytes(path: path, image: image, options: options);
+
+/// for Flutter  
+/// 把图片作为封面写入 folder_path 下（不包括子文件夹）所有 Lofty 支持的音乐文件。
+/// 图片只处理一次。写入失败的文件会被跳过并记录日志，返回写入成功的文件
+Future<List<String>>  setFolderCover({required String folderPath , required List<int> image , required CoverOptions options }) => RustLib.instance.api.crateApiTagWriterSetFolderCover(folderPath: folderPath, image: image, options: options);
+
+/// for Flutter  
+/// 删除 path 所有标签中的所有内嵌图片。only_front_cover 为 true 时只删除封面（CoverFront）。
+/// path 为 CUE 虚拟音轨时删除它所在的整轨文件中的图片
+Future<void>  removePictures({required String path , required bool onlyFrontCover }) => RustLib.instance.api.crateApiTagWriterRemovePictures(path: path, onlyFrontCover: onlyFrontCover);
+
+            /// 要修改的字段。None 表示不修改；空字符串或 0 表示删除这个字段
+class AudioTagChanges  {
+                final String? title;
+/// 多个艺术家以 "/" 分隔，和读取时相同
+final String? artist;
+final String? album;
+final String? albumArtist;
+final int? track;
+final int? disc;
+final int? year;
+final String? genre;
+
+                const AudioTagChanges({this.title ,this.artist ,this.album ,this.albumArtist ,this.track ,this.disc ,this.year ,this.genre ,});
+
+                static Future<AudioTagChanges>  default_()=>RustLib.instance.api.crateApiTagWriterAudioTagChangesDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => title.hashCode^artist.hashCode^album.hashCode^albumArtist.hashCode^track.hashCode^disc.hashCode^year.hashCode^genre.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AudioTagChanges &&
+                runtimeType == other.runtimeType
+                && title == other.title&& artist == other.artist&& album == other.album&& albumArtist == other.albumArtist&& track == other.track&& disc == other.disc&& year == other.year&& genre == other.genre;
+        
+            }
+
+/// 写入封面前对图片的处理
+class CoverOptions  {
+                /// 宽或高超过它时按比例缩小。0 表示不缩小
+final int maxDimension;
+/// 缩小后或者 recompress 为 true 时重新编码为 JPEG 使用的质量，1 ~ 100
+final int jpegQuality;
+/// 即使不需要缩小也重新编码为 JPEG
+final bool recompress;
+
+                const CoverOptions({required this.maxDimension ,required this.jpegQuality ,required this.recompress ,});
+
+                static Future<CoverOptions>  default_()=>RustLib.instance.api.crateApiTagWriterCoverOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxDimension.hashCode^jpegQuality.hashCode^recompress.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CoverOptions &&
+                runtimeType == other.runtimeType
+                && maxDimension == other.maxDimension&& jpegQuality == other.jpegQuality&& recompress == other.recompress;
+        
+            }
+            
\ No newline at end of file
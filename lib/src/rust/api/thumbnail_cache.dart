@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'tag_reader.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_add_to_cache`, `_cache_key`, `_cached_files`, `_encode`, `_is_cache_file_name`, `_lock_thumbnail_cache`, `_touch`, `_write_to_cache`, `add`, `extension`, `get_cached_thumbnail`, `is_thumbnail_cache_enabled`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ThumbnailCache`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// for Flutter  
+/// 启用缩略图缓存。之后 [super::tag_reader::get_picture_from_path] 会从缓存中读取，
+/// 返回的图片格式为 options.format。再次调用会替换之前的设置
+Future<void>  initThumbnailCache({required ThumbnailCacheOptions options }) => RustLib.instance.api.crateApiThumbnailCacheInitThumbnailCache(options: options);
+
+/// for Flutter  
+/// 删除所有缓存的缩略图，缓存保持启用
+Future<void>  clearThumbnailCache() => RustLib.instance.api.crateApiThumbnailCacheClearThumbnailCache();
+
+/// for Flutter  
+/// 为 paths 中的音乐生成 width x height 的缩略图并写入缓存，已经缓存的会被跳过。
+/// 在线程池中并行生成，进度通过 sink 发送。没有启用缓存时返回 Err
+Stream<IndexActionState>  warmThumbnailCache({required List<String> paths , required int width , required int height }) => RustLib.instance.api.crateApiThumbnailCacheWarmThumbnailCache(paths: paths, width: width, height: height);
+
+            class ThumbnailCacheOptions  {
+                /// 缓存文件夹，不存在时创建
+final String cacheDir;
+/// 缓存的最大容量，in bytes。超过时删除最久没有使用的缩略图
+final BigInt maxBytes;
+final ThumbnailFormat format;
+/// JPEG 的质量，1 ~ 100。
+/// 只用于 JPEG：image 的 WebP 编码器只支持无损编码，PNG 也是无损的，两者都忽略它
+final int quality;
+
+                const ThumbnailCacheOptions({required this.cacheDir ,required this.maxBytes ,required this.format ,required this.quality ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => cacheDir.hashCode^maxBytes.hashCode^format.hashCode^quality.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ThumbnailCacheOptions &&
+                runtimeType == other.runtimeType
+                && cacheDir == other.cacheDir&& maxBytes == other.maxBytes&& format == other.format&& quality == other.quality;
+        
+            }
+
+enum ThumbnailFormat {
+                    jpeg,
+/// 无损 WebP，不使用 quality
+webP,
+/// 不使用 quality
+png,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -3,1290 +3,2943 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/cover_palette.dart';
+import 'api/cue_sheet.dart';
+import 'api/index_db.dart';
 import 'api/installed_font.dart';
+import 'api/library_watcher.dart';
 import 'api/logger.dart';
+import 'api/loudness.dart';
+import 'api/midi_reader.dart';
+import 'api/playlist.dart';
+import 'api/replay_gain.dart';
+import 'api/scan_rules.dart';
 import 'api/smtc_flutter.dart';
 import 'api/system_theme.dart';
 import 'api/tag_reader.dart';
+import 'api/tag_writer.dart';
+import 'api/thumbnail_cache.dart';
 import 'api/utils.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
 
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {}
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => -1148029228;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'rust_lib_coriander_player',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<void> crateApiSmtcFlutterSmtcFlutterClose({required SmtcFlutter that});
-
-  SmtcFlutter crateApiSmtcFlutterSmtcFlutterNew();
-
-  Stream<SMTCControlEvent>
-      crateApiSmtcFlutterSmtcFlutterSubscribeToControlEvents(
-          {required SmtcFlutter that});
-
-  Future<void> crateApiSmtcFlutterSmtcFlutterUpdateDisplay(
-      {required SmtcFlutter that,
-      required String title,
-      required String artist,
-      required String album,
-      required int duration,
-      required String path});
-
-  Future<void> crateApiSmtcFlutterSmtcFlutterUpdateState(
-      {required SmtcFlutter that, required SMTCState state});
-
-  Future<void> crateApiSmtcFlutterSmtcFlutterUpdateTimeProperties(
-      {required SmtcFlutter that, required int progress});
-
-  Stream<IndexActionState> crateApiTagReaderBuildIndexFromFoldersRecursively(
-      {required List<String> folders, required String indexPath});
-
-  Future<List<InstalledFont>?> crateApiInstalledFontGetInstalledFonts();
-
-  Future<String?> crateApiTagReaderGetLyricFromPath({required String path});
-
-  Future<Uint8List?> crateApiTagReaderGetPictureFromPath(
-      {required String path, required int width, required int height});
-
-  Stream<String> crateApiLoggerInitRustLogger();
-
-  Future<bool> crateApiUtilsLaunchInBrowser({required String uri});
-
-  Future<String?> crateApiUtilsPickSingleFolder();
-
-  Future<bool> crateApiUtilsShowInExplorer({required String path});
-
-  SystemTheme crateApiSystemThemeSystemThemeGetSystemTheme();
-
-  Stream<IndexActionState> crateApiTagReaderUpdateIndex(
-      {required String indexPath});
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_SmtcFlutter;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_SmtcFlutter;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SmtcFlutterPtr;
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<void> crateApiSmtcFlutterSmtcFlutterClose(
-      {required SmtcFlutter that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSmtcFlutterSmtcFlutterCloseConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterCloseConstMeta =>
-      const TaskConstMeta(
-        debugName: "SmtcFlutter_close",
-        argNames: ["that"],
-      );
-
-  @override
-  SmtcFlutter crateApiSmtcFlutterSmtcFlutterNew() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSmtcFlutterSmtcFlutterNewConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "SmtcFlutter_new",
-        argNames: [],
-      );
-
-  @override
-  Stream<SMTCControlEvent>
-      crateApiSmtcFlutterSmtcFlutterSubscribeToControlEvents(
-          {required SmtcFlutter that}) {
-    final sink = RustStreamSink<SMTCControlEvent>();
-    unawaited(handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-            that, serializer);
-        sse_encode_StreamSink_smtc_control_event_Sse(sink, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta:
-          kCrateApiSmtcFlutterSmtcFlutterSubscribeToControlEventsConstMeta,
-      argValues: [that, sink],
-      apiImpl: this,
-    )));
-    return sink.stream;
-  }
-
-  TaskConstMeta
-      get kCrateApiSmtcFlutterSmtcFlutterSubscribeToControlEventsConstMeta =>
-          const TaskConstMeta(
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => 975458995;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_coriander_player',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<void> crateApiSmtcFlutterSmtcFlutterClose({required SmtcFlutter that });
+
+SmtcFlutter crateApiSmtcFlutterSmtcFlutterNew();
+
+Stream<SMTCControlEvent> crateApiSmtcFlutterSmtcFlutterSubscribeToControlEvents({required SmtcFlutter that });
+
+Future<void> crateApiSmtcFlutterSmtcFlutterUpdateDisplay({required SmtcFlutter that , required String title , required String artist , required String album , required int duration , required String path });
+
+Future<void> crateApiSmtcFlutterSmtcFlutterUpdateState({required SmtcFlutter that , required SMTCState state });
+
+Future<void> crateApiSmtcFlutterSmtcFlutterUpdateTimeProperties({required SmtcFlutter that , required int progress });
+
+Stream<IndexActionState> crateApiLoudnessAnalyzeAlbumLoudness({required List<String> paths , required bool writeTags , required BigInt job });
+
+Stream<IndexActionState> crateApiLoudnessAnalyzeTrackLoudness({required String path , required bool writeTags , required BigInt job });
+
+Future<Audio> crateApiTagReaderAudioDefault();
+
+Future<AudioTagChanges> crateApiTagWriterAudioTagChangesDefault();
+
+Stream<IndexActionState> crateApiTagReaderBuildIndexFromFoldersRecursively({required List<String> folders , required String indexPath });
+
+Stream<IndexActionState> crateApiTagReaderBuildIndexWithRules({required List<String> folders , required String indexPath , required ScanRules rules , required bool coverPlaceholders });
+
+void crateApiTagReaderCancelIndexAction();
+
+void crateApiLoudnessCancelLoudnessAnalysis({required BigInt job });
+
+void crateApiCoverPaletteClearCoverPaletteCache();
+
+Future<void> crateApiThumbnailCacheClearThumbnailCache();
+
+Future<CoverOptions> crateApiTagWriterCoverOptionsDefault();
+
+Future<CoverPalette?> crateApiCoverPaletteGetCoverPalette({required String path , String? albumKey });
+
+Future<bool> crateApiScanRulesGetCoverPlaceholdersEnabled({required String indexPath });
+
+Future<Uint8List?> crateApiTagReaderGetEmbeddedPicture({required String path , required int index });
+
+Future<List<EmbeddedPicture>> crateApiTagReaderGetEmbeddedPictures({required String path });
+
+Future<Audio?> crateApiIndexDbGetIndexedAudio({required String indexPath , required String audioPath });
+
+Future<AudioFolder?> crateApiIndexDbGetIndexedFolder({required String indexPath , required String folderPath });
+
+Future<List<AudioFolder>> crateApiIndexDbGetIndexedFolders({required String indexPath });
+
+Future<List<InstalledFont>?> crateApiInstalledFontGetInstalledFonts();
+
+Future<String?> crateApiTagReaderGetLyricFromPath({required String path });
+
+Future<Uint8List?> crateApiTagReaderGetPictureFromPath({required String path , required int width , required int height });
+
+Future<ScanRules> crateApiScanRulesGetScanRules({required String indexPath });
+
+Stream<String> crateApiLoggerInitRustLogger();
+
+Future<void> crateApiThumbnailCacheInitThumbnailCache({required ThumbnailCacheOptions options });
+
+bool crateApiTagReaderIsIndexActionCancelled();
+
+Future<bool> crateApiUtilsLaunchInBrowser({required String uri });
+
+Stream<IndexActionState> crateApiIndexDbMigrateIndexToDatabase({required String indexPath });
+
+BigInt crateApiLoudnessNewLoudnessAnalysisJob();
+
+Future<String?> crateApiUtilsPickSingleFolder();
+
+Future<List<Audio>> crateApiPlaylistReadPlaylist({required String path });
+
+Future<void> crateApiTagWriterRemovePictures({required String path , required bool onlyFrontCover });
+
+Future<ReplayGain> crateApiReplayGainReplayGainDefault();
+
+Future<ScanRules> crateApiScanRulesScanRulesDefault();
+
+Future<void> crateApiTagWriterSetCoverFromBytes({required String path , required List<int> image , required CoverOptions options });
+
+Future<void> crateApiTagWriterSetCoverFromFile({required String path , required String imagePath , required CoverOptions options });
+
+Future<List<String>> crateApiTagWriterSetFolderCover({required String folderPath , required List<int> image , required CoverOptions options });
+
+Future<bool> crateApiUtilsShowInExplorer({required String path });
+
+Future<void> crateApiLibraryWatcherStopWatchingLibrary();
+
+SystemTheme crateApiSystemThemeSystemThemeGetSystemTheme();
+
+List<AudioRelocation> crateApiTagReaderTakeIndexRelocations();
+
+Stream<IndexActionState> crateApiTagReaderUpdateIndex({required String indexPath });
+
+Stream<IndexActionState> crateApiThumbnailCacheWarmThumbnailCache({required List<String> paths , required int width , required int height });
+
+Stream<LibraryEvent> crateApiLibraryWatcherWatchLibrary({required String indexPath });
+
+Future<Audio> crateApiTagWriterWriteAudioTags({required String path , required AudioTagChanges changes });
+
+Future<void> crateApiPlaylistWritePlaylist({required String path , required List<String> paths , required PlaylistFormat format , required bool relative });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SmtcFlutter;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SmtcFlutter;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SmtcFlutterPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<void> crateApiSmtcFlutterSmtcFlutterClose({required SmtcFlutter that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterCloseConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterCloseConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_close",
+            argNames: ["that"],
+        );
+        
+
+@override SmtcFlutter crateApiSmtcFlutterSmtcFlutterNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterNewConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_new",
+            argNames: [],
+        );
+        
+
+@override Stream<SMTCControlEvent> crateApiSmtcFlutterSmtcFlutterSubscribeToControlEvents({required SmtcFlutter that })  { 
+            final sink = RustStreamSink<SMTCControlEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+sse_encode_StreamSink_smtc_control_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterSubscribeToControlEventsConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterSubscribeToControlEventsConstMeta => const TaskConstMeta(
             debugName: "SmtcFlutter_subscribe_to_control_events",
             argNames: ["that", "sink"],
-          );
-
-  @override
-  Future<void> crateApiSmtcFlutterSmtcFlutterUpdateDisplay(
-      {required SmtcFlutter that,
-      required String title,
-      required String artist,
-      required String album,
-      required int duration,
-      required String path}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-            that, serializer);
-        sse_encode_String(title, serializer);
-        sse_encode_String(artist, serializer);
-        sse_encode_String(album, serializer);
-        sse_encode_u_32(duration, serializer);
-        sse_encode_String(path, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateDisplayConstMeta,
-      argValues: [that, title, artist, album, duration, path],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdateDisplayConstMeta =>
-      const TaskConstMeta(
-        debugName: "SmtcFlutter_update_display",
-        argNames: ["that", "title", "artist", "album", "duration", "path"],
-      );
-
-  @override
-  Future<void> crateApiSmtcFlutterSmtcFlutterUpdateState(
-      {required SmtcFlutter that, required SMTCState state}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-            that, serializer);
-        sse_encode_smtc_state(state, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateStateConstMeta,
-      argValues: [that, state],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdateStateConstMeta =>
-      const TaskConstMeta(
-        debugName: "SmtcFlutter_update_state",
-        argNames: ["that", "state"],
-      );
-
-  @override
-  Future<void> crateApiSmtcFlutterSmtcFlutterUpdateTimeProperties(
-      {required SmtcFlutter that, required int progress}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-            that, serializer);
-        sse_encode_u_32(progress, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateTimePropertiesConstMeta,
-      argValues: [that, progress],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiSmtcFlutterSmtcFlutterUpdateTimePropertiesConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<void> crateApiSmtcFlutterSmtcFlutterUpdateDisplay({required SmtcFlutter that , required String title , required String artist , required String album , required int duration , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+sse_encode_String(title, serializer);
+sse_encode_String(artist, serializer);
+sse_encode_String(album, serializer);
+sse_encode_u_32(duration, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateDisplayConstMeta,
+            argValues: [that, title, artist, album, duration, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdateDisplayConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_update_display",
+            argNames: ["that", "title", "artist", "album", "duration", "path"],
+        );
+        
+
+@override Future<void> crateApiSmtcFlutterSmtcFlutterUpdateState({required SmtcFlutter that , required SMTCState state })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+sse_encode_smtc_state(state, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateStateConstMeta,
+            argValues: [that, state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdateStateConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_update_state",
+            argNames: ["that", "state"],
+        );
+        
+
+@override Future<void> crateApiSmtcFlutterSmtcFlutterUpdateTimeProperties({required SmtcFlutter that , required int progress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+sse_encode_u_32(progress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateTimePropertiesConstMeta,
+            argValues: [that, progress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdateTimePropertiesConstMeta => const TaskConstMeta(
             debugName: "SmtcFlutter_update_time_properties",
             argNames: ["that", "progress"],
-          );
-
-  @override
-  Stream<IndexActionState> crateApiTagReaderBuildIndexFromFoldersRecursively(
-      {required List<String> folders, required String indexPath}) {
-    final sink = RustStreamSink<IndexActionState>();
-    unawaited(handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(folders, serializer);
-        sse_encode_String(indexPath, serializer);
-        sse_encode_StreamSink_index_action_state_Sse(sink, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 7, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTagReaderBuildIndexFromFoldersRecursivelyConstMeta,
-      argValues: [folders, indexPath, sink],
-      apiImpl: this,
-    )));
-    return sink.stream;
-  }
-
-  TaskConstMeta
-      get kCrateApiTagReaderBuildIndexFromFoldersRecursivelyConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Stream<IndexActionState> crateApiLoudnessAnalyzeAlbumLoudness({required List<String> paths , required bool writeTags , required BigInt job })  { 
+            final sink = RustStreamSink<IndexActionState>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(paths, serializer);
+sse_encode_bool(writeTags, serializer);
+sse_encode_u_64(job, serializer);
+sse_encode_StreamSink_index_action_state_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_album_loudness,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLoudnessAnalyzeAlbumLoudnessConstMeta,
+            argValues: [paths, writeTags, job, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiLoudnessAnalyzeAlbumLoudnessConstMeta => const TaskConstMeta(
+            debugName: "analyze_album_loudness",
+            argNames: ["paths", "writeTags", "job", "sink"],
+        );
+        
+
+@override Stream<IndexActionState> crateApiLoudnessAnalyzeTrackLoudness({required String path , required bool writeTags , required BigInt job })  { 
+            final sink = RustStreamSink<IndexActionState>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_bool(writeTags, serializer);
+sse_encode_u_64(job, serializer);
+sse_encode_StreamSink_index_action_state_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_track_loudness,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLoudnessAnalyzeTrackLoudnessConstMeta,
+            argValues: [path, writeTags, job, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiLoudnessAnalyzeTrackLoudnessConstMeta => const TaskConstMeta(
+            debugName: "analyze_track_loudness",
+            argNames: ["path", "writeTags", "job", "sink"],
+        );
+        
+
+@override Future<Audio> crateApiTagReaderAudioDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_audio,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderAudioDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderAudioDefaultConstMeta => const TaskConstMeta(
+            debugName: "audio_default",
+            argNames: [],
+        );
+        
+
+@override Future<AudioTagChanges> crateApiTagWriterAudioTagChangesDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_audio_tag_changes,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagWriterAudioTagChangesDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagWriterAudioTagChangesDefaultConstMeta => const TaskConstMeta(
+            debugName: "audio_tag_changes_default",
+            argNames: [],
+        );
+        
+
+@override Stream<IndexActionState> crateApiTagReaderBuildIndexFromFoldersRecursively({required List<String> folders , required String indexPath })  { 
+            final sink = RustStreamSink<IndexActionState>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(folders, serializer);
+sse_encode_String(indexPath, serializer);
+sse_encode_StreamSink_index_action_state_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTagReaderBuildIndexFromFoldersRecursivelyConstMeta,
+            argValues: [folders, indexPath, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiTagReaderBuildIndexFromFoldersRecursivelyConstMeta => const TaskConstMeta(
             debugName: "build_index_from_folders_recursively",
             argNames: ["folders", "indexPath", "sink"],
-          );
-
-  @override
-  Future<List<InstalledFont>?> crateApiInstalledFontGetInstalledFonts() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 8, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_list_installed_font,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiInstalledFontGetInstalledFontsConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiInstalledFontGetInstalledFontsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_installed_fonts",
-        argNames: [],
-      );
-
-  @override
-  Future<String?> crateApiTagReaderGetLyricFromPath({required String path}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 9, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiTagReaderGetLyricFromPathConstMeta,
-      argValues: [path],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTagReaderGetLyricFromPathConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_lyric_from_path",
-        argNames: ["path"],
-      );
-
-  @override
-  Future<Uint8List?> crateApiTagReaderGetPictureFromPath(
-      {required String path, required int width, required int height}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        sse_encode_u_32(width, serializer);
-        sse_encode_u_32(height, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 10, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiTagReaderGetPictureFromPathConstMeta,
-      argValues: [path, width, height],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTagReaderGetPictureFromPathConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_picture_from_path",
-        argNames: ["path", "width", "height"],
-      );
-
-  @override
-  Stream<String> crateApiLoggerInitRustLogger() {
-    final sink = RustStreamSink<String>();
-    unawaited(handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_StreamSink_String_Sse(sink, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 11, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiLoggerInitRustLoggerConstMeta,
-      argValues: [sink],
-      apiImpl: this,
-    )));
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiLoggerInitRustLoggerConstMeta =>
-      const TaskConstMeta(
-        debugName: "init_rust_logger",
-        argNames: ["sink"],
-      );
-
-  @override
-  Future<bool> crateApiUtilsLaunchInBrowser({required String uri}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(uri, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 12, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiUtilsLaunchInBrowserConstMeta,
-      argValues: [uri],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUtilsLaunchInBrowserConstMeta =>
-      const TaskConstMeta(
-        debugName: "launch_in_browser",
-        argNames: ["uri"],
-      );
-
-  @override
-  Future<String?> crateApiUtilsPickSingleFolder() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 13, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiUtilsPickSingleFolderConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUtilsPickSingleFolderConstMeta =>
-      const TaskConstMeta(
-        debugName: "pick_single_folder",
-        argNames: [],
-      );
-
-  @override
-  Future<bool> crateApiUtilsShowInExplorer({required String path}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 14, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiUtilsShowInExplorerConstMeta,
-      argValues: [path],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUtilsShowInExplorerConstMeta =>
-      const TaskConstMeta(
-        debugName: "show_in_explorer",
-        argNames: ["path"],
-      );
-
-  @override
-  SystemTheme crateApiSystemThemeSystemThemeGetSystemTheme() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_system_theme,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSystemThemeSystemThemeGetSystemThemeConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSystemThemeSystemThemeGetSystemThemeConstMeta =>
-      const TaskConstMeta(
-        debugName: "system_theme_get_system_theme",
-        argNames: [],
-      );
-
-  @override
-  Stream<IndexActionState> crateApiTagReaderUpdateIndex(
-      {required String indexPath}) {
-    final sink = RustStreamSink<IndexActionState>();
-    unawaited(handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(indexPath, serializer);
-        sse_encode_StreamSink_index_action_state_Sse(sink, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 16, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTagReaderUpdateIndexConstMeta,
-      argValues: [indexPath, sink],
-      apiImpl: this,
-    )));
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiTagReaderUpdateIndexConstMeta =>
-      const TaskConstMeta(
-        debugName: "update_index",
-        argNames: ["indexPath", "sink"],
-      );
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_SmtcFlutter => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_SmtcFlutter => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  SmtcFlutter
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SmtcFlutterImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SmtcFlutter
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SmtcFlutterImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SmtcFlutter
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SmtcFlutterImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  RustStreamSink<IndexActionState> dco_decode_StreamSink_index_action_state_Sse(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  RustStreamSink<SMTCControlEvent> dco_decode_StreamSink_smtc_control_event_Sse(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  IndexActionState dco_decode_index_action_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return IndexActionState(
-      progress: dco_decode_f_64(arr[0]),
-      message: dco_decode_String(arr[1]),
-    );
-  }
-
-  @protected
-  InstalledFont dco_decode_installed_font(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return InstalledFont(
-      path: dco_decode_String(arr[0]),
-      fullName: dco_decode_String(arr[1]),
-    );
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<InstalledFont> dco_decode_list_installed_font(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_installed_font).toList();
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  List<InstalledFont>? dco_decode_opt_list_installed_font(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_installed_font(raw);
-  }
-
-  @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
-  }
-
-  @protected
-  (int, int, int, int) dco_decode_record_u_8_u_8_u_8_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4) {
-      throw Exception('Expected 4 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_u_8(arr[0]),
-      dco_decode_u_8(arr[1]),
-      dco_decode_u_8(arr[2]),
-      dco_decode_u_8(arr[3]),
-    );
-  }
-
-  @protected
-  SMTCControlEvent dco_decode_smtc_control_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SMTCControlEvent.values[raw as int];
-  }
-
-  @protected
-  SMTCState dco_decode_smtc_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SMTCState.values[raw as int];
-  }
-
-  @protected
-  SystemTheme dco_decode_system_theme(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SystemTheme(
-      fore: dco_decode_record_u_8_u_8_u_8_u_8(arr[0]),
-      accent: dco_decode_record_u_8_u_8_u_8_u_8(arr[1]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  SmtcFlutter
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SmtcFlutterImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  SmtcFlutter
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SmtcFlutterImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  SmtcFlutter
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SmtcFlutterImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  RustStreamSink<IndexActionState> sse_decode_StreamSink_index_action_state_Sse(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  RustStreamSink<SMTCControlEvent> sse_decode_StreamSink_smtc_control_event_Sse(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  IndexActionState sse_decode_index_action_state(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_progress = sse_decode_f_64(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    return IndexActionState(progress: var_progress, message: var_message);
-  }
-
-  @protected
-  InstalledFont sse_decode_installed_font(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_path = sse_decode_String(deserializer);
-    var var_fullName = sse_decode_String(deserializer);
-    return InstalledFont(path: var_path, fullName: var_fullName);
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<InstalledFont> sse_decode_list_installed_font(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <InstalledFont>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_installed_font(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  List<InstalledFont>? sse_decode_opt_list_installed_font(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_installed_font(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_u_8_strict(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  (int, int, int, int) sse_decode_record_u_8_u_8_u_8_u_8(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_8(deserializer);
-    var var_field1 = sse_decode_u_8(deserializer);
-    var var_field2 = sse_decode_u_8(deserializer);
-    var var_field3 = sse_decode_u_8(deserializer);
-    return (var_field0, var_field1, var_field2, var_field3);
-  }
-
-  @protected
-  SMTCControlEvent sse_decode_smtc_control_event(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return SMTCControlEvent.values[inner];
-  }
-
-  @protected
-  SMTCState sse_decode_smtc_state(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return SMTCState.values[inner];
-  }
-
-  @protected
-  SystemTheme sse_decode_system_theme(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_fore = sse_decode_record_u_8_u_8_u_8_u_8(deserializer);
-    var var_accent = sse_decode_record_u_8_u_8_u_8_u_8(deserializer);
-    return SystemTheme(fore: var_fore, accent: var_accent);
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SmtcFlutter self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as SmtcFlutterImpl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SmtcFlutter self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as SmtcFlutterImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SmtcFlutter self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as SmtcFlutterImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_StreamSink_String_Sse(
-      RustStreamSink<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-        self.setupAndSerialize(
-            codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+        );
+        
+
+@override Stream<IndexActionState> crateApiTagReaderBuildIndexWithRules({required List<String> folders , required String indexPath , required ScanRules rules , required bool coverPlaceholders })  { 
+            final sink = RustStreamSink<IndexActionState>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(folders, serializer);
+sse_encode_String(indexPath, serializer);
+sse_encode_box_autoadd_scan_rules(rules, serializer);
+sse_encode_bool(coverPlaceholders, serializer);
+sse_encode_StreamSink_index_action_state_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTagReaderBuildIndexWithRulesConstMeta,
+            argValues: [folders, indexPath, rules, coverPlaceholders, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiTagReaderBuildIndexWithRulesConstMeta => const TaskConstMeta(
+            debugName: "build_index_with_rules",
+            argNames: ["folders", "indexPath", "rules", "coverPlaceholders", "sink"],
+        );
+        
+
+@override void crateApiTagReaderCancelIndexAction()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderCancelIndexActionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderCancelIndexActionConstMeta => const TaskConstMeta(
+            debugName: "cancel_index_action",
+            argNames: [],
+        );
+        
+
+@override void crateApiLoudnessCancelLoudnessAnalysis({required BigInt job })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(job, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLoudnessCancelLoudnessAnalysisConstMeta,
+            argValues: [job],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoudnessCancelLoudnessAnalysisConstMeta => const TaskConstMeta(
+            debugName: "cancel_loudness_analysis",
+            argNames: ["job"],
+        );
+        
+
+@override void crateApiCoverPaletteClearCoverPaletteCache()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCoverPaletteClearCoverPaletteCacheConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCoverPaletteClearCoverPaletteCacheConstMeta => const TaskConstMeta(
+            debugName: "clear_cover_palette_cache",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiThumbnailCacheClearThumbnailCache()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiThumbnailCacheClearThumbnailCacheConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiThumbnailCacheClearThumbnailCacheConstMeta => const TaskConstMeta(
+            debugName: "clear_thumbnail_cache",
+            argNames: [],
+        );
+        
+
+@override Future<CoverOptions> crateApiTagWriterCoverOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_cover_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagWriterCoverOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagWriterCoverOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "cover_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<CoverPalette?> crateApiCoverPaletteGetCoverPalette({required String path , String? albumKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_opt_String(albumKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_cover_palette,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCoverPaletteGetCoverPaletteConstMeta,
+            argValues: [path, albumKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCoverPaletteGetCoverPaletteConstMeta => const TaskConstMeta(
+            debugName: "get_cover_palette",
+            argNames: ["path", "albumKey"],
+        );
+        
+
+@override Future<bool> crateApiScanRulesGetCoverPlaceholdersEnabled({required String indexPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScanRulesGetCoverPlaceholdersEnabledConstMeta,
+            argValues: [indexPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScanRulesGetCoverPlaceholdersEnabledConstMeta => const TaskConstMeta(
+            debugName: "get_cover_placeholders_enabled",
+            argNames: ["indexPath"],
+        );
+        
+
+@override Future<Uint8List?> crateApiTagReaderGetEmbeddedPicture({required String path , required int index })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_u_32(index, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderGetEmbeddedPictureConstMeta,
+            argValues: [path, index],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderGetEmbeddedPictureConstMeta => const TaskConstMeta(
+            debugName: "get_embedded_picture",
+            argNames: ["path", "index"],
+        );
+        
+
+@override Future<List<EmbeddedPicture>> crateApiTagReaderGetEmbeddedPictures({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_embedded_picture,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTagReaderGetEmbeddedPicturesConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderGetEmbeddedPicturesConstMeta => const TaskConstMeta(
+            debugName: "get_embedded_pictures",
+            argNames: ["path"],
+        );
+        
+
+@override Future<Audio?> crateApiIndexDbGetIndexedAudio({required String indexPath , required String audioPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_String(audioPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_audio,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiIndexDbGetIndexedAudioConstMeta,
+            argValues: [indexPath, audioPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIndexDbGetIndexedAudioConstMeta => const TaskConstMeta(
+            debugName: "get_indexed_audio",
+            argNames: ["indexPath", "audioPath"],
+        );
+        
+
+@override Future<AudioFolder?> crateApiIndexDbGetIndexedFolder({required String indexPath , required String folderPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_String(folderPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_audio_folder,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiIndexDbGetIndexedFolderConstMeta,
+            argValues: [indexPath, folderPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIndexDbGetIndexedFolderConstMeta => const TaskConstMeta(
+            debugName: "get_indexed_folder",
+            argNames: ["indexPath", "folderPath"],
+        );
+        
+
+@override Future<List<AudioFolder>> crateApiIndexDbGetIndexedFolders({required String indexPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_audio_folder,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiIndexDbGetIndexedFoldersConstMeta,
+            argValues: [indexPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIndexDbGetIndexedFoldersConstMeta => const TaskConstMeta(
+            debugName: "get_indexed_folders",
+            argNames: ["indexPath"],
+        );
+        
+
+@override Future<List<InstalledFont>?> crateApiInstalledFontGetInstalledFonts()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_installed_font,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInstalledFontGetInstalledFontsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstalledFontGetInstalledFontsConstMeta => const TaskConstMeta(
+            debugName: "get_installed_fonts",
+            argNames: [],
+        );
+        
+
+@override Future<String?> crateApiTagReaderGetLyricFromPath({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderGetLyricFromPathConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderGetLyricFromPathConstMeta => const TaskConstMeta(
+            debugName: "get_lyric_from_path",
+            argNames: ["path"],
+        );
+        
+
+@override Future<Uint8List?> crateApiTagReaderGetPictureFromPath({required String path , required int width , required int height })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderGetPictureFromPathConstMeta,
+            argValues: [path, width, height],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderGetPictureFromPathConstMeta => const TaskConstMeta(
+            debugName: "get_picture_from_path",
+            argNames: ["path", "width", "height"],
+        );
+        
+
+@override Future<ScanRules> crateApiScanRulesGetScanRules({required String indexPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_scan_rules,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScanRulesGetScanRulesConstMeta,
+            argValues: [indexPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScanRulesGetScanRulesConstMeta => const TaskConstMeta(
+            debugName: "get_scan_rules",
+            argNames: ["indexPath"],
+        );
+        
+
+@override Stream<String> crateApiLoggerInitRustLogger()  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLoggerInitRustLoggerConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiLoggerInitRustLoggerConstMeta => const TaskConstMeta(
+            debugName: "init_rust_logger",
+            argNames: ["sink"],
+        );
+        
+
+@override Future<void> crateApiThumbnailCacheInitThumbnailCache({required ThumbnailCacheOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_thumbnail_cache_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiThumbnailCacheInitThumbnailCacheConstMeta,
+            argValues: [options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiThumbnailCacheInitThumbnailCacheConstMeta => const TaskConstMeta(
+            debugName: "init_thumbnail_cache",
+            argNames: ["options"],
+        );
+        
+
+@override bool crateApiTagReaderIsIndexActionCancelled()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderIsIndexActionCancelledConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderIsIndexActionCancelledConstMeta => const TaskConstMeta(
+            debugName: "is_index_action_cancelled",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiUtilsLaunchInBrowser({required String uri })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(uri, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiUtilsLaunchInBrowserConstMeta,
+            argValues: [uri],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUtilsLaunchInBrowserConstMeta => const TaskConstMeta(
+            debugName: "launch_in_browser",
+            argNames: ["uri"],
+        );
+        
+
+@override Stream<IndexActionState> crateApiIndexDbMigrateIndexToDatabase({required String indexPath })  { 
+            final sink = RustStreamSink<IndexActionState>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_StreamSink_index_action_state_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiIndexDbMigrateIndexToDatabaseConstMeta,
+            argValues: [indexPath, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiIndexDbMigrateIndexToDatabaseConstMeta => const TaskConstMeta(
+            debugName: "migrate_index_to_database",
+            argNames: ["indexPath", "sink"],
+        );
+        
+
+@override BigInt crateApiLoudnessNewLoudnessAnalysisJob()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLoudnessNewLoudnessAnalysisJobConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoudnessNewLoudnessAnalysisJobConstMeta => const TaskConstMeta(
+            debugName: "new_loudness_analysis_job",
+            argNames: [],
+        );
+        
+
+@override Future<String?> crateApiUtilsPickSingleFolder()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiUtilsPickSingleFolderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUtilsPickSingleFolderConstMeta => const TaskConstMeta(
+            debugName: "pick_single_folder",
+            argNames: [],
+        );
+        
+
+@override Future<List<Audio>> crateApiPlaylistReadPlaylist({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_audio,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPlaylistReadPlaylistConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPlaylistReadPlaylistConstMeta => const TaskConstMeta(
+            debugName: "read_playlist",
+            argNames: ["path"],
+        );
+        
+
+@override Future<void> crateApiTagWriterRemovePictures({required String path , required bool onlyFrontCover })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_bool(onlyFrontCover, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTagWriterRemovePicturesConstMeta,
+            argValues: [path, onlyFrontCover],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagWriterRemovePicturesConstMeta => const TaskConstMeta(
+            debugName: "remove_pictures",
+            argNames: ["path", "onlyFrontCover"],
+        );
+        
+
+@override Future<ReplayGain> crateApiReplayGainReplayGainDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_replay_gain,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiReplayGainReplayGainDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReplayGainReplayGainDefaultConstMeta => const TaskConstMeta(
+            debugName: "replay_gain_default",
+            argNames: [],
+        );
+        
+
+@override Future<ScanRules> crateApiScanRulesScanRulesDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_scan_rules,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScanRulesScanRulesDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScanRulesScanRulesDefaultConstMeta => const TaskConstMeta(
+            debugName: "scan_rules_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiTagWriterSetCoverFromBytes({required String path , required List<int> image , required CoverOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_list_prim_u_8_loose(image, serializer);
+sse_encode_box_autoadd_cover_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTagWriterSetCoverFromBytesConstMeta,
+            argValues: [path, image, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagWriterSetCoverFromBytesConstMeta => const TaskConstMeta(
+            debugName: "set_cover_from_bytes",
+            argNames: ["path", "image", "options"],
+        );
+        
+
+@override Future<void> crateApiTagWriterSetCoverFromFile({required String path , required String imagePath , required CoverOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_String(imagePath, serializer);
+sse_encode_box_autoadd_cover_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        )),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_StreamSink_index_action_state_Sse(
-      RustStreamSink<IndexActionState> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-        self.setupAndSerialize(
-            codec: SseCodec(
-          decodeSuccessData: sse_decode_index_action_state,
+        )
+        ,
+            constMeta: kCrateApiTagWriterSetCoverFromFileConstMeta,
+            argValues: [path, imagePath, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagWriterSetCoverFromFileConstMeta => const TaskConstMeta(
+            debugName: "set_cover_from_file",
+            argNames: ["path", "imagePath", "options"],
+        );
+        
+
+@override Future<List<String>> crateApiTagWriterSetFolderCover({required String folderPath , required List<int> image , required CoverOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(folderPath, serializer);
+sse_encode_list_prim_u_8_loose(image, serializer);
+sse_encode_box_autoadd_cover_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_AnyhowException,
-        )),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_StreamSink_smtc_control_event_Sse(
-      RustStreamSink<SMTCControlEvent> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-        self.setupAndSerialize(
-            codec: SseCodec(
-          decodeSuccessData: sse_decode_smtc_control_event,
+        )
+        ,
+            constMeta: kCrateApiTagWriterSetFolderCoverConstMeta,
+            argValues: [folderPath, image, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagWriterSetFolderCoverConstMeta => const TaskConstMeta(
+            debugName: "set_folder_cover",
+            argNames: ["folderPath", "image", "options"],
+        );
+        
+
+@override Future<bool> crateApiUtilsShowInExplorer({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiUtilsShowInExplorerConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUtilsShowInExplorerConstMeta => const TaskConstMeta(
+            debugName: "show_in_explorer",
+            argNames: ["path"],
+        );
+        
+
+@override Future<void> crateApiLibraryWatcherStopWatchingLibrary()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLibraryWatcherStopWatchingLibraryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibraryWatcherStopWatchingLibraryConstMeta => const TaskConstMeta(
+            debugName: "stop_watching_library",
+            argNames: [],
+        );
+        
+
+@override SystemTheme crateApiSystemThemeSystemThemeGetSystemTheme()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_system_theme,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSystemThemeSystemThemeGetSystemThemeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemThemeSystemThemeGetSystemThemeConstMeta => const TaskConstMeta(
+            debugName: "system_theme_get_system_theme",
+            argNames: [],
+        );
+        
+
+@override List<AudioRelocation> crateApiTagReaderTakeIndexRelocations()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_audio_relocation,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderTakeIndexRelocationsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderTakeIndexRelocationsConstMeta => const TaskConstMeta(
+            debugName: "take_index_relocations",
+            argNames: [],
+        );
+        
+
+@override Stream<IndexActionState> crateApiTagReaderUpdateIndex({required String indexPath })  { 
+            final sink = RustStreamSink<IndexActionState>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_StreamSink_index_action_state_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        )),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_index_action_state(
-      IndexActionState self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self.progress, serializer);
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_installed_font(InstalledFont self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.path, serializer);
-    sse_encode_String(self.fullName, serializer);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_installed_font(
-      List<InstalledFont> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_installed_font(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_list_installed_font(
-      List<InstalledFont>? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_installed_font(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_list_prim_u_8_strict(
-      Uint8List? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_u_8_strict(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_record_u_8_u_8_u_8_u_8(
-      (int, int, int, int) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_8(self.$1, serializer);
-    sse_encode_u_8(self.$2, serializer);
-    sse_encode_u_8(self.$3, serializer);
-    sse_encode_u_8(self.$4, serializer);
-  }
-
-  @protected
-  void sse_encode_smtc_control_event(
-      SMTCControlEvent self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_smtc_state(SMTCState self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_system_theme(SystemTheme self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_record_u_8_u_8_u_8_u_8(self.fore, serializer);
-    sse_encode_record_u_8_u_8_u_8_u_8(self.accent, serializer);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
-
-@sealed
-class SmtcFlutterImpl extends RustOpaque implements SmtcFlutter {
-  // Not to be used by end users
-  SmtcFlutterImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  SmtcFlutterImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_SmtcFlutter,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_SmtcFlutter,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_SmtcFlutterPtr,
-  );
-
-  /// Apis for Flutter
-  Future<void> close() =>
-      RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterClose(
-        that: this,
-      );
-
-  /// Apis for Flutter
-  Stream<SMTCControlEvent> subscribeToControlEvents() => RustLib.instance.api
-          .crateApiSmtcFlutterSmtcFlutterSubscribeToControlEvents(
-        that: this,
-      );
-
-  /// Apis for Flutter
-  Future<void> updateDisplay(
-          {required String title,
-          required String artist,
-          required String album,
-          required int duration,
-          required String path}) =>
-      RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdateDisplay(
-          that: this,
-          title: title,
-          artist: artist,
-          album: album,
-          duration: duration,
-          path: path);
-
-  /// Apis for Flutter
-  Future<void> updateState({required SMTCState state}) => RustLib.instance.api
-      .crateApiSmtcFlutterSmtcFlutterUpdateState(that: this, state: state);
-
-  /// progress, duration: ms
-  /// Apis for Flutter
-  Future<void> updateTimeProperties({required int progress}) =>
-      RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdateTimeProperties(
-          that: this, progress: progress);
-}
+        )
+        ,
+            constMeta: kCrateApiTagReaderUpdateIndexConstMeta,
+            argValues: [indexPath, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiTagReaderUpdateIndexConstMeta => const TaskConstMeta(
+            debugName: "update_index",
+            argNames: ["indexPath", "sink"],
+        );
+        
+
+@override Stream<IndexActionState> crateApiThumbnailCacheWarmThumbnailCache({required List<String> paths , required int width , required int height })  { 
+            final sink = RustStreamSink<IndexActionState>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(paths, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+sse_encode_StreamSink_index_action_state_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiThumbnailCacheWarmThumbnailCacheConstMeta,
+            argValues: [paths, width, height, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiThumbnailCacheWarmThumbnailCacheConstMeta => const TaskConstMeta(
+            debugName: "warm_thumbnail_cache",
+            argNames: ["paths", "width", "height", "sink"],
+        );
+        
+
+@override Stream<LibraryEvent> crateApiLibraryWatcherWatchLibrary({required String indexPath })  { 
+            final sink = RustStreamSink<LibraryEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_StreamSink_library_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLibraryWatcherWatchLibraryConstMeta,
+            argValues: [indexPath, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiLibraryWatcherWatchLibraryConstMeta => const TaskConstMeta(
+            debugName: "watch_library",
+            argNames: ["indexPath", "sink"],
+        );
+        
+
+@override Future<Audio> crateApiTagWriterWriteAudioTags({required String path , required AudioTagChanges changes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_box_autoadd_audio_tag_changes(changes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_audio,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTagWriterWriteAudioTagsConstMeta,
+            argValues: [path, changes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagWriterWriteAudioTagsConstMeta => const TaskConstMeta(
+            debugName: "write_audio_tags",
+            argNames: ["path", "changes"],
+        );
+        
+
+@override Future<void> crateApiPlaylistWritePlaylist({required String path , required List<String> paths , required PlaylistFormat format , required bool relative })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_list_String(paths, serializer);
+sse_encode_playlist_format(format, serializer);
+sse_encode_bool(relative, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPlaylistWritePlaylistConstMeta,
+            argValues: [path, paths, format, relative],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPlaylistWritePlaylistConstMeta => const TaskConstMeta(
+            debugName: "write_playlist",
+            argNames: ["path", "paths", "format", "relative"],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SmtcFlutter => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SmtcFlutter => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected SmtcFlutter dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SmtcFlutterImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SmtcFlutter dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SmtcFlutterImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SmtcFlutter dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SmtcFlutterImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<IndexActionState> dco_decode_StreamSink_index_action_state_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<LibraryEvent> dco_decode_StreamSink_library_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<SMTCControlEvent> dco_decode_StreamSink_smtc_control_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AlbumLoudness dco_decode_album_loudness(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return AlbumLoudness(tracks: dco_decode_list_track_loudness(arr[0]),
+skipped: dco_decode_list_String(arr[1]),
+integrated: dco_decode_f_64(arr[2]),
+loudnessRange: dco_decode_f_64(arr[3]),
+truePeak: dco_decode_f_64(arr[4]),
+gain: dco_decode_f_64(arr[5]),); }
+
+@protected Audio dco_decode_audio(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 29) throw Exception('unexpected arr length: expect 29 but see ${arr.length}');
+                return Audio(title: dco_decode_String(arr[0]),
+artist: dco_decode_String(arr[1]),
+album: dco_decode_String(arr[2]),
+track: dco_decode_opt_box_autoadd_u_32(arr[3]),
+duration: dco_decode_u_64(arr[4]),
+bitrate: dco_decode_opt_box_autoadd_u_32(arr[5]),
+sampleRate: dco_decode_opt_box_autoadd_u_32(arr[6]),
+path: dco_decode_String(arr[7]),
+modified: dco_decode_u_64(arr[8]),
+created: dco_decode_u_64(arr[9]),
+by: dco_decode_opt_String(arr[10]),
+size: dco_decode_u_64(arr[11]),
+id: dco_decode_String(arr[12]),
+albumArtist: dco_decode_opt_String(arr[13]),
+trackTotal: dco_decode_opt_box_autoadd_u_32(arr[14]),
+disc: dco_decode_opt_box_autoadd_u_32(arr[15]),
+discTotal: dco_decode_opt_box_autoadd_u_32(arr[16]),
+year: dco_decode_opt_box_autoadd_u_32(arr[17]),
+date: dco_decode_opt_String(arr[18]),
+genre: dco_decode_opt_String(arr[19]),
+composer: dco_decode_opt_String(arr[20]),
+comment: dco_decode_opt_String(arr[21]),
+bitDepth: dco_decode_opt_box_autoadd_u_8(arr[22]),
+channels: dco_decode_opt_box_autoadd_u_8(arr[23]),
+fileType: dco_decode_opt_String(arr[24]),
+replayGain: dco_decode_opt_box_autoadd_replay_gain(arr[25]),
+coverPlaceholder: dco_decode_opt_String(arr[26]),
+midi: dco_decode_opt_box_autoadd_midi_info(arr[27]),
+cue: dco_decode_opt_box_autoadd_cue_track(arr[28]),); }
+
+@protected AudioFolder dco_decode_audio_folder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return AudioFolder(path: dco_decode_String(arr[0]),
+modified: dco_decode_u_64(arr[1]),
+latest: dco_decode_u_64(arr[2]),
+audios: dco_decode_list_audio(arr[3]),
+cover: dco_decode_opt_String(arr[4]),
+coverPlaceholder: dco_decode_opt_String(arr[5]),); }
+
+@protected AudioRelocation dco_decode_audio_relocation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return AudioRelocation(id: dco_decode_String(arr[0]),
+from: dco_decode_String(arr[1]),
+to: dco_decode_String(arr[2]),); }
+
+@protected AudioTagChanges dco_decode_audio_tag_changes(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return AudioTagChanges(title: dco_decode_opt_String(arr[0]),
+artist: dco_decode_opt_String(arr[1]),
+album: dco_decode_opt_String(arr[2]),
+albumArtist: dco_decode_opt_String(arr[3]),
+track: dco_decode_opt_box_autoadd_u_32(arr[4]),
+disc: dco_decode_opt_box_autoadd_u_32(arr[5]),
+year: dco_decode_opt_box_autoadd_u_32(arr[6]),
+genre: dco_decode_opt_String(arr[7]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected Audio dco_decode_box_autoadd_audio(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_audio(raw); }
+
+@protected AudioFolder dco_decode_box_autoadd_audio_folder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_audio_folder(raw); }
+
+@protected AudioTagChanges dco_decode_box_autoadd_audio_tag_changes(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_audio_tag_changes(raw); }
+
+@protected CoverOptions dco_decode_box_autoadd_cover_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_cover_options(raw); }
+
+@protected CoverPalette dco_decode_box_autoadd_cover_palette(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_cover_palette(raw); }
+
+@protected CueTrack dco_decode_box_autoadd_cue_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_cue_track(raw); }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected MidiInfo dco_decode_box_autoadd_midi_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_midi_info(raw); }
+
+@protected PaletteColor dco_decode_box_autoadd_palette_color(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_palette_color(raw); }
+
+@protected ReplayGain dco_decode_box_autoadd_replay_gain(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_replay_gain(raw); }
+
+@protected ScanRules dco_decode_box_autoadd_scan_rules(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_scan_rules(raw); }
+
+@protected ThumbnailCacheOptions dco_decode_box_autoadd_thumbnail_cache_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_thumbnail_cache_options(raw); }
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected int dco_decode_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected CoverOptions dco_decode_cover_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return CoverOptions(maxDimension: dco_decode_u_32(arr[0]),
+jpegQuality: dco_decode_u_8(arr[1]),
+recompress: dco_decode_bool(arr[2]),); }
+
+@protected CoverPalette dco_decode_cover_palette(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return CoverPalette(dominant: dco_decode_palette_color(arr[0]),
+vibrant: dco_decode_opt_box_autoadd_palette_color(arr[1]),
+muted: dco_decode_opt_box_autoadd_palette_color(arr[2]),); }
+
+@protected CueTrack dco_decode_cue_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CueTrack(cuePath: dco_decode_String(arr[0]),
+file: dco_decode_String(arr[1]),
+start: dco_decode_u_64(arr[2]),
+end: dco_decode_opt_box_autoadd_u_64(arr[3]),
+embedded: dco_decode_bool(arr[4]),); }
+
+@protected EmbeddedPicture dco_decode_embedded_picture(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return EmbeddedPicture(index: dco_decode_u_32(arr[0]),
+pictureType: dco_decode_String(arr[1]),
+mimeType: dco_decode_opt_String(arr[2]),
+description: dco_decode_opt_String(arr[3]),
+width: dco_decode_u_32(arr[4]),
+height: dco_decode_u_32(arr[5]),
+size: dco_decode_u_64(arr[6]),); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected IndexActionState dco_decode_index_action_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return IndexActionState(progress: dco_decode_f_64(arr[0]),
+message: dco_decode_String(arr[1]),); }
+
+@protected InstalledFont dco_decode_installed_font(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return InstalledFont(path: dco_decode_String(arr[0]),
+fullName: dco_decode_String(arr[1]),); }
+
+@protected LibraryEvent dco_decode_library_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LibraryEvent(kind: dco_decode_library_event_kind(arr[0]),
+path: dco_decode_String(arr[1]),
+audio: dco_decode_opt_box_autoadd_audio(arr[2]),); }
+
+@protected LibraryEventKind dco_decode_library_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibraryEventKind.values[raw as int]; }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<Audio> dco_decode_list_audio(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_audio).toList(); }
+
+@protected List<AudioFolder> dco_decode_list_audio_folder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_audio_folder).toList(); }
+
+@protected List<AudioRelocation> dco_decode_list_audio_relocation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_audio_relocation).toList(); }
+
+@protected List<EmbeddedPicture> dco_decode_list_embedded_picture(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_embedded_picture).toList(); }
+
+@protected List<InstalledFont> dco_decode_list_installed_font(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_installed_font).toList(); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<TrackLoudness> dco_decode_list_track_loudness(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_track_loudness).toList(); }
+
+@protected MidiInfo dco_decode_midi_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return MidiInfo(format: dco_decode_u_16(arr[0]),
+tracks: dco_decode_u_16(arr[1]),
+ppq: dco_decode_opt_box_autoadd_u_16(arr[2]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected Audio? dco_decode_opt_box_autoadd_audio(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_audio(raw); }
+
+@protected AudioFolder? dco_decode_opt_box_autoadd_audio_folder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_audio_folder(raw); }
+
+@protected CoverPalette? dco_decode_opt_box_autoadd_cover_palette(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_cover_palette(raw); }
+
+@protected CueTrack? dco_decode_opt_box_autoadd_cue_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_cue_track(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected MidiInfo? dco_decode_opt_box_autoadd_midi_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_midi_info(raw); }
+
+@protected PaletteColor? dco_decode_opt_box_autoadd_palette_color(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_palette_color(raw); }
+
+@protected ReplayGain? dco_decode_opt_box_autoadd_replay_gain(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_replay_gain(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_16(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_8(raw); }
+
+@protected List<InstalledFont>? dco_decode_opt_list_installed_font(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_installed_font(raw); }
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
+
+@protected PaletteColor dco_decode_palette_color(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PaletteColor(color: dco_decode_record_u_8_u_8_u_8_u_8(arr[0]),
+onColor: dco_decode_record_u_8_u_8_u_8_u_8(arr[1]),
+population: dco_decode_f_64(arr[2]),); }
+
+@protected PlaylistFormat dco_decode_playlist_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PlaylistFormat.values[raw as int]; }
+
+@protected (int,int,int,int) dco_decode_record_u_8_u_8_u_8_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 4) {
+                throw Exception('Expected 4 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_8(arr[0]),dco_decode_u_8(arr[1]),dco_decode_u_8(arr[2]),dco_decode_u_8(arr[3]),); }
+
+@protected ReplayGain dco_decode_replay_gain(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ReplayGain(trackGain: dco_decode_opt_box_autoadd_f_64(arr[0]),
+trackPeak: dco_decode_opt_box_autoadd_f_64(arr[1]),
+albumGain: dco_decode_opt_box_autoadd_f_64(arr[2]),
+albumPeak: dco_decode_opt_box_autoadd_f_64(arr[3]),); }
+
+@protected ScanRules dco_decode_scan_rules(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return ScanRules(excludeGlobs: dco_decode_list_String(arr[0]),
+excludeRegexes: dco_decode_list_String(arr[1]),
+skipHidden: dco_decode_bool(arr[2]),
+respectMarkerFiles: dco_decode_bool(arr[3]),
+followSymlinks: dco_decode_bool(arr[4]),
+minDuration: dco_decode_u_64(arr[5]),
+minFileSize: dco_decode_u_64(arr[6]),
+folderCoverNames: dco_decode_list_String(arr[7]),); }
+
+@protected SMTCControlEvent dco_decode_smtc_control_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SMTCControlEvent.values[raw as int]; }
+
+@protected SMTCState dco_decode_smtc_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SMTCState.values[raw as int]; }
+
+@protected SystemTheme dco_decode_system_theme(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SystemTheme(fore: dco_decode_record_u_8_u_8_u_8_u_8(arr[0]),
+accent: dco_decode_record_u_8_u_8_u_8_u_8(arr[1]),); }
+
+@protected ThumbnailCacheOptions dco_decode_thumbnail_cache_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ThumbnailCacheOptions(cacheDir: dco_decode_String(arr[0]),
+maxBytes: dco_decode_u_64(arr[1]),
+format: dco_decode_thumbnail_format(arr[2]),
+quality: dco_decode_u_8(arr[3]),); }
+
+@protected ThumbnailFormat dco_decode_thumbnail_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ThumbnailFormat.values[raw as int]; }
+
+@protected TrackLoudness dco_decode_track_loudness(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return TrackLoudness(path: dco_decode_String(arr[0]),
+integrated: dco_decode_f_64(arr[1]),
+loudnessRange: dco_decode_f_64(arr[2]),
+truePeak: dco_decode_f_64(arr[3]),
+gain: dco_decode_f_64(arr[4]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected SmtcFlutter sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SmtcFlutterImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SmtcFlutter sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SmtcFlutterImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SmtcFlutter sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SmtcFlutterImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<IndexActionState> sse_decode_StreamSink_index_action_state_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<LibraryEvent> sse_decode_StreamSink_library_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<SMTCControlEvent> sse_decode_StreamSink_smtc_control_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AlbumLoudness sse_decode_album_loudness(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tracks = sse_decode_list_track_loudness(deserializer);
+var var_skipped = sse_decode_list_String(deserializer);
+var var_integrated = sse_decode_f_64(deserializer);
+var var_loudnessRange = sse_decode_f_64(deserializer);
+var var_truePeak = sse_decode_f_64(deserializer);
+var var_gain = sse_decode_f_64(deserializer);
+return AlbumLoudness(tracks: var_tracks, skipped: var_skipped, integrated: var_integrated, loudnessRange: var_loudnessRange, truePeak: var_truePeak, gain: var_gain); }
+
+@protected Audio sse_decode_audio(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_title = sse_decode_String(deserializer);
+var var_artist = sse_decode_String(deserializer);
+var var_album = sse_decode_String(deserializer);
+var var_track = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_duration = sse_decode_u_64(deserializer);
+var var_bitrate = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_sampleRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_path = sse_decode_String(deserializer);
+var var_modified = sse_decode_u_64(deserializer);
+var var_created = sse_decode_u_64(deserializer);
+var var_by = sse_decode_opt_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_id = sse_decode_String(deserializer);
+var var_albumArtist = sse_decode_opt_String(deserializer);
+var var_trackTotal = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_disc = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_discTotal = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_year = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_date = sse_decode_opt_String(deserializer);
+var var_genre = sse_decode_opt_String(deserializer);
+var var_composer = sse_decode_opt_String(deserializer);
+var var_comment = sse_decode_opt_String(deserializer);
+var var_bitDepth = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_channels = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_fileType = sse_decode_opt_String(deserializer);
+var var_replayGain = sse_decode_opt_box_autoadd_replay_gain(deserializer);
+var var_coverPlaceholder = sse_decode_opt_String(deserializer);
+var var_midi = sse_decode_opt_box_autoadd_midi_info(deserializer);
+var var_cue = sse_decode_opt_box_autoadd_cue_track(deserializer);
+return Audio(title: var_title, artist: var_artist, album: var_album, track: var_track, duration: var_duration, bitrate: var_bitrate, sampleRate: var_sampleRate, path: var_path, modified: var_modified, created: var_created, by: var_by, size: var_size, id: var_id, albumArtist: var_albumArtist, trackTotal: var_trackTotal, disc: var_disc, discTotal: var_discTotal, year: var_year, date: var_date, genre: var_genre, composer: var_composer, comment: var_comment, bitDepth: var_bitDepth, channels: var_channels, fileType: var_fileType, replayGain: var_replayGain, coverPlaceholder: var_coverPlaceholder, midi: var_midi, cue: var_cue); }
+
+@protected AudioFolder sse_decode_audio_folder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_modified = sse_decode_u_64(deserializer);
+var var_latest = sse_decode_u_64(deserializer);
+var var_audios = sse_decode_list_audio(deserializer);
+var var_cover = sse_decode_opt_String(deserializer);
+var var_coverPlaceholder = sse_decode_opt_String(deserializer);
+return AudioFolder(path: var_path, modified: var_modified, latest: var_latest, audios: var_audios, cover: var_cover, coverPlaceholder: var_coverPlaceholder); }
+
+@protected AudioRelocation sse_decode_audio_relocation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_from = sse_decode_String(deserializer);
+var var_to = sse_decode_String(deserializer);
+return AudioRelocation(id: var_id, from: var_from, to: var_to); }
+
+@protected AudioTagChanges sse_decode_audio_tag_changes(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_title = sse_decode_opt_String(deserializer);
+var var_artist = sse_decode_opt_String(deserializer);
+var var_album = sse_decode_opt_String(deserializer);
+var var_albumArtist = sse_decode_opt_String(deserializer);
+var var_track = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_disc = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_year = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_genre = sse_decode_opt_String(deserializer);
+return AudioTagChanges(title: var_title, artist: var_artist, album: var_album, albumArtist: var_albumArtist, track: var_track, disc: var_disc, year: var_year, genre: var_genre); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected Audio sse_decode_box_autoadd_audio(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_audio(deserializer)); }
+
+@protected AudioFolder sse_decode_box_autoadd_audio_folder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_audio_folder(deserializer)); }
+
+@protected AudioTagChanges sse_decode_box_autoadd_audio_tag_changes(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_audio_tag_changes(deserializer)); }
+
+@protected CoverOptions sse_decode_box_autoadd_cover_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_cover_options(deserializer)); }
+
+@protected CoverPalette sse_decode_box_autoadd_cover_palette(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_cover_palette(deserializer)); }
+
+@protected CueTrack sse_decode_box_autoadd_cue_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_cue_track(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected MidiInfo sse_decode_box_autoadd_midi_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_midi_info(deserializer)); }
+
+@protected PaletteColor sse_decode_box_autoadd_palette_color(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_palette_color(deserializer)); }
+
+@protected ReplayGain sse_decode_box_autoadd_replay_gain(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_replay_gain(deserializer)); }
+
+@protected ScanRules sse_decode_box_autoadd_scan_rules(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_scan_rules(deserializer)); }
+
+@protected ThumbnailCacheOptions sse_decode_box_autoadd_thumbnail_cache_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_thumbnail_cache_options(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_16(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_8(deserializer)); }
+
+@protected CoverOptions sse_decode_cover_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxDimension = sse_decode_u_32(deserializer);
+var var_jpegQuality = sse_decode_u_8(deserializer);
+var var_recompress = sse_decode_bool(deserializer);
+return CoverOptions(maxDimension: var_maxDimension, jpegQuality: var_jpegQuality, recompress: var_recompress); }
+
+@protected CoverPalette sse_decode_cover_palette(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dominant = sse_decode_palette_color(deserializer);
+var var_vibrant = sse_decode_opt_box_autoadd_palette_color(deserializer);
+var var_muted = sse_decode_opt_box_autoadd_palette_color(deserializer);
+return CoverPalette(dominant: var_dominant, vibrant: var_vibrant, muted: var_muted); }
+
+@protected CueTrack sse_decode_cue_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_cuePath = sse_decode_String(deserializer);
+var var_file = sse_decode_String(deserializer);
+var var_start = sse_decode_u_64(deserializer);
+var var_end = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_embedded = sse_decode_bool(deserializer);
+return CueTrack(cuePath: var_cuePath, file: var_file, start: var_start, end: var_end, embedded: var_embedded); }
+
+@protected EmbeddedPicture sse_decode_embedded_picture(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_32(deserializer);
+var var_pictureType = sse_decode_String(deserializer);
+var var_mimeType = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+return EmbeddedPicture(index: var_index, pictureType: var_pictureType, mimeType: var_mimeType, description: var_description, width: var_width, height: var_height, size: var_size); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected IndexActionState sse_decode_index_action_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_progress = sse_decode_f_64(deserializer);
+var var_message = sse_decode_String(deserializer);
+return IndexActionState(progress: var_progress, message: var_message); }
+
+@protected InstalledFont sse_decode_installed_font(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_fullName = sse_decode_String(deserializer);
+return InstalledFont(path: var_path, fullName: var_fullName); }
+
+@protected LibraryEvent sse_decode_library_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_library_event_kind(deserializer);
+var var_path = sse_decode_String(deserializer);
+var var_audio = sse_decode_opt_box_autoadd_audio(deserializer);
+return LibraryEvent(kind: var_kind, path: var_path, audio: var_audio); }
+
+@protected LibraryEventKind sse_decode_library_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LibraryEventKind.values[inner]; }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Audio> sse_decode_list_audio(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Audio>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_audio(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AudioFolder> sse_decode_list_audio_folder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AudioFolder>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_audio_folder(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AudioRelocation> sse_decode_list_audio_relocation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AudioRelocation>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_audio_relocation(deserializer)); }
+        return ans_;
+         }
+
+@protected List<EmbeddedPicture> sse_decode_list_embedded_picture(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <EmbeddedPicture>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_embedded_picture(deserializer)); }
+        return ans_;
+         }
+
+@protected List<InstalledFont> sse_decode_list_installed_font(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <InstalledFont>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_installed_font(deserializer)); }
+        return ans_;
+         }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<TrackLoudness> sse_decode_list_track_loudness(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TrackLoudness>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_track_loudness(deserializer)); }
+        return ans_;
+         }
+
+@protected MidiInfo sse_decode_midi_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_format = sse_decode_u_16(deserializer);
+var var_tracks = sse_decode_u_16(deserializer);
+var var_ppq = sse_decode_opt_box_autoadd_u_16(deserializer);
+return MidiInfo(format: var_format, tracks: var_tracks, ppq: var_ppq); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Audio? sse_decode_opt_box_autoadd_audio(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_audio(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected AudioFolder? sse_decode_opt_box_autoadd_audio_folder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_audio_folder(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CoverPalette? sse_decode_opt_box_autoadd_cover_palette(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_cover_palette(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CueTrack? sse_decode_opt_box_autoadd_cue_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_cue_track(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected MidiInfo? sse_decode_opt_box_autoadd_midi_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_midi_info(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PaletteColor? sse_decode_opt_box_autoadd_palette_color(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_palette_color(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ReplayGain? sse_decode_opt_box_autoadd_replay_gain(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_replay_gain(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_16(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_8(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<InstalledFont>? sse_decode_opt_list_installed_font(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_installed_font(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_8_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PaletteColor sse_decode_palette_color(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_color = sse_decode_record_u_8_u_8_u_8_u_8(deserializer);
+var var_onColor = sse_decode_record_u_8_u_8_u_8_u_8(deserializer);
+var var_population = sse_decode_f_64(deserializer);
+return PaletteColor(color: var_color, onColor: var_onColor, population: var_population); }
+
+@protected PlaylistFormat sse_decode_playlist_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PlaylistFormat.values[inner]; }
+
+@protected (int,int,int,int) sse_decode_record_u_8_u_8_u_8_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_8(deserializer);
+var var_field1 = sse_decode_u_8(deserializer);
+var var_field2 = sse_decode_u_8(deserializer);
+var var_field3 = sse_decode_u_8(deserializer);
+return (var_field0, var_field1, var_field2, var_field3); }
+
+@protected ReplayGain sse_decode_replay_gain(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_trackGain = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_trackPeak = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_albumGain = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_albumPeak = sse_decode_opt_box_autoadd_f_64(deserializer);
+return ReplayGain(trackGain: var_trackGain, trackPeak: var_trackPeak, albumGain: var_albumGain, albumPeak: var_albumPeak); }
+
+@protected ScanRules sse_decode_scan_rules(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_excludeGlobs = sse_decode_list_String(deserializer);
+var var_excludeRegexes = sse_decode_list_String(deserializer);
+var var_skipHidden = sse_decode_bool(deserializer);
+var var_respectMarkerFiles = sse_decode_bool(deserializer);
+var var_followSymlinks = sse_decode_bool(deserializer);
+var var_minDuration = sse_decode_u_64(deserializer);
+var var_minFileSize = sse_decode_u_64(deserializer);
+var var_folderCoverNames = sse_decode_list_String(deserializer);
+return ScanRules(excludeGlobs: var_excludeGlobs, excludeRegexes: var_excludeRegexes, skipHidden: var_skipHidden, respectMarkerFiles: var_respectMarkerFiles, followSymlinks: var_followSymlinks, minDuration: var_minDuration, minFileSize: var_minFileSize, folderCoverNames: var_folderCoverNames); }
+
+@protected SMTCControlEvent sse_decode_smtc_control_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SMTCControlEvent.values[inner]; }
+
+@protected SMTCState sse_decode_smtc_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SMTCState.values[inner]; }
+
+@protected SystemTheme sse_decode_system_theme(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fore = sse_decode_record_u_8_u_8_u_8_u_8(deserializer);
+var var_accent = sse_decode_record_u_8_u_8_u_8_u_8(deserializer);
+return SystemTheme(fore: var_fore, accent: var_accent); }
+
+@protected ThumbnailCacheOptions sse_decode_thumbnail_cache_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_cacheDir = sse_decode_String(deserializer);
+var var_maxBytes = sse_decode_u_64(deserializer);
+var var_format = sse_decode_thumbnail_format(deserializer);
+var var_quality = sse_decode_u_8(deserializer);
+return ThumbnailCacheOptions(cacheDir: var_cacheDir, maxBytes: var_maxBytes, format: var_format, quality: var_quality); }
+
+@protected ThumbnailFormat sse_decode_thumbnail_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ThumbnailFormat.values[inner]; }
+
+@protected TrackLoudness sse_decode_track_loudness(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_integrated = sse_decode_f_64(deserializer);
+var var_loudnessRange = sse_decode_f_64(deserializer);
+var var_truePeak = sse_decode_f_64(deserializer);
+var var_gain = sse_decode_f_64(deserializer);
+return TrackLoudness(path: var_path, integrated: var_integrated, loudnessRange: var_loudnessRange, truePeak: var_truePeak, gain: var_gain); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SmtcFlutter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SmtcFlutterImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SmtcFlutter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SmtcFlutterImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SmtcFlutter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SmtcFlutterImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_String,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_index_action_state_Sse(RustStreamSink<IndexActionState> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_index_action_state,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_library_event_Sse(RustStreamSink<LibraryEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_library_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_smtc_control_event_Sse(RustStreamSink<SMTCControlEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_smtc_control_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_album_loudness(AlbumLoudness self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_track_loudness(self.tracks, serializer);
+sse_encode_list_String(self.skipped, serializer);
+sse_encode_f_64(self.integrated, serializer);
+sse_encode_f_64(self.loudnessRange, serializer);
+sse_encode_f_64(self.truePeak, serializer);
+sse_encode_f_64(self.gain, serializer);
+ }
+
+@protected void sse_encode_audio(Audio self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.artist, serializer);
+sse_encode_String(self.album, serializer);
+sse_encode_opt_box_autoadd_u_32(self.track, serializer);
+sse_encode_u_64(self.duration, serializer);
+sse_encode_opt_box_autoadd_u_32(self.bitrate, serializer);
+sse_encode_opt_box_autoadd_u_32(self.sampleRate, serializer);
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.modified, serializer);
+sse_encode_u_64(self.created, serializer);
+sse_encode_opt_String(self.by, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_String(self.id, serializer);
+sse_encode_opt_String(self.albumArtist, serializer);
+sse_encode_opt_box_autoadd_u_32(self.trackTotal, serializer);
+sse_encode_opt_box_autoadd_u_32(self.disc, serializer);
+sse_encode_opt_box_autoadd_u_32(self.discTotal, serializer);
+sse_encode_opt_box_autoadd_u_32(self.year, serializer);
+sse_encode_opt_String(self.date, serializer);
+sse_encode_opt_String(self.genre, serializer);
+sse_encode_opt_String(self.composer, serializer);
+sse_encode_opt_String(self.comment, serializer);
+sse_encode_opt_box_autoadd_u_8(self.bitDepth, serializer);
+sse_encode_opt_box_autoadd_u_8(self.channels, serializer);
+sse_encode_opt_String(self.fileType, serializer);
+sse_encode_opt_box_autoadd_replay_gain(self.replayGain, serializer);
+sse_encode_opt_String(self.coverPlaceholder, serializer);
+sse_encode_opt_box_autoadd_midi_info(self.midi, serializer);
+sse_encode_opt_box_autoadd_cue_track(self.cue, serializer);
+ }
+
+@protected void sse_encode_audio_folder(AudioFolder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.modified, serializer);
+sse_encode_u_64(self.latest, serializer);
+sse_encode_list_audio(self.audios, serializer);
+sse_encode_opt_String(self.cover, serializer);
+sse_encode_opt_String(self.coverPlaceholder, serializer);
+ }
+
+@protected void sse_encode_audio_relocation(AudioRelocation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.from, serializer);
+sse_encode_String(self.to, serializer);
+ }
+
+@protected void sse_encode_audio_tag_changes(AudioTagChanges self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.title, serializer);
+sse_encode_opt_String(self.artist, serializer);
+sse_encode_opt_String(self.album, serializer);
+sse_encode_opt_String(self.albumArtist, serializer);
+sse_encode_opt_box_autoadd_u_32(self.track, serializer);
+sse_encode_opt_box_autoadd_u_32(self.disc, serializer);
+sse_encode_opt_box_autoadd_u_32(self.year, serializer);
+sse_encode_opt_String(self.genre, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_audio(Audio self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_audio(self, serializer); }
+
+@protected void sse_encode_box_autoadd_audio_folder(AudioFolder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_audio_folder(self, serializer); }
+
+@protected void sse_encode_box_autoadd_audio_tag_changes(AudioTagChanges self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_audio_tag_changes(self, serializer); }
+
+@protected void sse_encode_box_autoadd_cover_options(CoverOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_cover_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_cover_palette(CoverPalette self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_cover_palette(self, serializer); }
+
+@protected void sse_encode_box_autoadd_cue_track(CueTrack self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_cue_track(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_midi_info(MidiInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_midi_info(self, serializer); }
+
+@protected void sse_encode_box_autoadd_palette_color(PaletteColor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_palette_color(self, serializer); }
+
+@protected void sse_encode_box_autoadd_replay_gain(ReplayGain self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_replay_gain(self, serializer); }
+
+@protected void sse_encode_box_autoadd_scan_rules(ScanRules self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_scan_rules(self, serializer); }
+
+@protected void sse_encode_box_autoadd_thumbnail_cache_options(ThumbnailCacheOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_thumbnail_cache_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self, serializer); }
+
+@protected void sse_encode_cover_options(CoverOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxDimension, serializer);
+sse_encode_u_8(self.jpegQuality, serializer);
+sse_encode_bool(self.recompress, serializer);
+ }
+
+@protected void sse_encode_cover_palette(CoverPalette self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_palette_color(self.dominant, serializer);
+sse_encode_opt_box_autoadd_palette_color(self.vibrant, serializer);
+sse_encode_opt_box_autoadd_palette_color(self.muted, serializer);
+ }
+
+@protected void sse_encode_cue_track(CueTrack self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.cuePath, serializer);
+sse_encode_String(self.file, serializer);
+sse_encode_u_64(self.start, serializer);
+sse_encode_opt_box_autoadd_u_64(self.end, serializer);
+sse_encode_bool(self.embedded, serializer);
+ }
+
+@protected void sse_encode_embedded_picture(EmbeddedPicture self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.index, serializer);
+sse_encode_String(self.pictureType, serializer);
+sse_encode_opt_String(self.mimeType, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_u_64(self.size, serializer);
+ }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_index_action_state(IndexActionState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.progress, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_installed_font(InstalledFont self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_String(self.fullName, serializer);
+ }
+
+@protected void sse_encode_library_event(LibraryEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_library_event_kind(self.kind, serializer);
+sse_encode_String(self.path, serializer);
+sse_encode_opt_box_autoadd_audio(self.audio, serializer);
+ }
+
+@protected void sse_encode_library_event_kind(LibraryEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_audio(List<Audio> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_audio(item, serializer); } }
+
+@protected void sse_encode_list_audio_folder(List<AudioFolder> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_audio_folder(item, serializer); } }
+
+@protected void sse_encode_list_audio_relocation(List<AudioRelocation> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_audio_relocation(item, serializer); } }
+
+@protected void sse_encode_list_embedded_picture(List<EmbeddedPicture> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_embedded_picture(item, serializer); } }
+
+@protected void sse_encode_list_installed_font(List<InstalledFont> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_installed_font(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_track_loudness(List<TrackLoudness> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_track_loudness(item, serializer); } }
+
+@protected void sse_encode_midi_info(MidiInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self.format, serializer);
+sse_encode_u_16(self.tracks, serializer);
+sse_encode_opt_box_autoadd_u_16(self.ppq, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_audio(Audio? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_audio(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_audio_folder(AudioFolder? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_audio_folder(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_cover_palette(CoverPalette? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_cover_palette(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_cue_track(CueTrack? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_cue_track(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_midi_info(MidiInfo? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_midi_info(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_palette_color(PaletteColor? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_palette_color(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_replay_gain(ReplayGain? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_replay_gain(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_16(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_8(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_installed_font(List<InstalledFont>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_installed_font(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_8_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_palette_color(PaletteColor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_record_u_8_u_8_u_8_u_8(self.color, serializer);
+sse_encode_record_u_8_u_8_u_8_u_8(self.onColor, serializer);
+sse_encode_f_64(self.population, serializer);
+ }
+
+@protected void sse_encode_playlist_format(PlaylistFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_record_u_8_u_8_u_8_u_8((int,int,int,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.$1, serializer);
+sse_encode_u_8(self.$2, serializer);
+sse_encode_u_8(self.$3, serializer);
+sse_encode_u_8(self.$4, serializer);
+ }
+
+@protected void sse_encode_replay_gain(ReplayGain self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_f_64(self.trackGain, serializer);
+sse_encode_opt_box_autoadd_f_64(self.trackPeak, serializer);
+sse_encode_opt_box_autoadd_f_64(self.albumGain, serializer);
+sse_encode_opt_box_autoadd_f_64(self.albumPeak, serializer);
+ }
+
+@protected void sse_encode_scan_rules(ScanRules self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.excludeGlobs, serializer);
+sse_encode_list_String(self.excludeRegexes, serializer);
+sse_encode_bool(self.skipHidden, serializer);
+sse_encode_bool(self.respectMarkerFiles, serializer);
+sse_encode_bool(self.followSymlinks, serializer);
+sse_encode_u_64(self.minDuration, serializer);
+sse_encode_u_64(self.minFileSize, serializer);
+sse_encode_list_String(self.folderCoverNames, serializer);
+ }
+
+@protected void sse_encode_smtc_control_event(SMTCControlEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_smtc_state(SMTCState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_system_theme(SystemTheme self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_record_u_8_u_8_u_8_u_8(self.fore, serializer);
+sse_encode_record_u_8_u_8_u_8_u_8(self.accent, serializer);
+ }
+
+@protected void sse_encode_thumbnail_cache_options(ThumbnailCacheOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.cacheDir, serializer);
+sse_encode_u_64(self.maxBytes, serializer);
+sse_encode_thumbnail_format(self.format, serializer);
+sse_encode_u_8(self.quality, serializer);
+ }
+
+@protected void sse_encode_thumbnail_format(ThumbnailFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_track_loudness(TrackLoudness self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_f_64(self.integrated, serializer);
+sse_encode_f_64(self.loudnessRange, serializer);
+sse_encode_f_64(self.truePeak, serializer);
+sse_encode_f_64(self.gain, serializer);
+ }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+                }
+                
+
+            @sealed class SmtcFlutterImpl extends RustOpaque implements SmtcFlutter {
+                // Not to be used by end users
+                SmtcFlutterImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                SmtcFlutterImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SmtcFlutter,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SmtcFlutter,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SmtcFlutterPtr,
+                );
+
+                /// Apis for Flutter
+ Future<void>  close()=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterClose(that: this, );
+
+
+/// Apis for Flutter
+ Stream<SMTCControlEvent>  subscribeToControlEvents()=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterSubscribeToControlEvents(that: this, );
+
+
+/// Apis for Flutter
+ Future<void>  updateDisplay({required String title , required String artist , required String album , required int duration , required String path })=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdateDisplay(that: this, title: title, artist: artist, album: album, duration: duration, path: path);
+
+
+/// Apis for Flutter
+ Future<void>  updateState({required SMTCState state })=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdateState(that: this, state: state);
+
+
+/// progress, duration: ms
+/// Apis for Flutter
+ Future<void>  updateTimeProperties({required int progress })=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdateTimeProperties(that: this, progress: progress);
+
+
+            }
\ No newline at end of file
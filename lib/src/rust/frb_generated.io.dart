@@ -3,11 +3,22 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/cover_palette.dart';
+import 'api/cue_sheet.dart';
+import 'api/index_db.dart';
 import 'api/installed_font.dart';
+import 'api/library_watcher.dart';
 import 'api/logger.dart';
+import 'api/loudness.dart';
+import 'api/midi_reader.dart';
+import 'api/playlist.dart';
+import 'api/replay_gain.dart';
+import 'api/scan_rules.dart';
 import 'api/smtc_flutter.dart';
 import 'api/system_theme.dart';
 import 'api/tag_reader.dart';
+import 'api/tag_writer.dart';
+import 'api/thumbnail_cache.dart';
 import 'api/utils.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -15,342 +26,560 @@ import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_SmtcFlutterPtr => wire
-          ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr;
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  SmtcFlutter
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  SmtcFlutter
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          dynamic raw);
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SmtcFlutterPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr;
 
-  @protected
-  SmtcFlutter
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          dynamic raw);
 
-  @protected
-  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
 
-  @protected
-  RustStreamSink<IndexActionState> dco_decode_StreamSink_index_action_state_Sse(
-      dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  RustStreamSink<SMTCControlEvent> dco_decode_StreamSink_smtc_control_event_Sse(
-      dynamic raw);
+@protected SmtcFlutter dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(dynamic raw);
 
-  @protected
-  String dco_decode_String(dynamic raw);
+@protected SmtcFlutter dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(dynamic raw);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+@protected SmtcFlutter dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(dynamic raw);
 
-  @protected
-  double dco_decode_f_64(dynamic raw);
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected RustStreamSink<IndexActionState> dco_decode_StreamSink_index_action_state_Sse(dynamic raw);
 
-  @protected
-  IndexActionState dco_decode_index_action_state(dynamic raw);
+@protected RustStreamSink<LibraryEvent> dco_decode_StreamSink_library_event_Sse(dynamic raw);
 
-  @protected
-  InstalledFont dco_decode_installed_font(dynamic raw);
+@protected RustStreamSink<SMTCControlEvent> dco_decode_StreamSink_smtc_control_event_Sse(dynamic raw);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  List<InstalledFont> dco_decode_list_installed_font(dynamic raw);
+@protected AlbumLoudness dco_decode_album_loudness(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected Audio dco_decode_audio(dynamic raw);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected AudioFolder dco_decode_audio_folder(dynamic raw);
 
-  @protected
-  List<InstalledFont>? dco_decode_opt_list_installed_font(dynamic raw);
+@protected AudioRelocation dco_decode_audio_relocation(dynamic raw);
 
-  @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+@protected AudioTagChanges dco_decode_audio_tag_changes(dynamic raw);
 
-  @protected
-  (int, int, int, int) dco_decode_record_u_8_u_8_u_8_u_8(dynamic raw);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  SMTCControlEvent dco_decode_smtc_control_event(dynamic raw);
+@protected Audio dco_decode_box_autoadd_audio(dynamic raw);
 
-  @protected
-  SMTCState dco_decode_smtc_state(dynamic raw);
+@protected AudioFolder dco_decode_box_autoadd_audio_folder(dynamic raw);
 
-  @protected
-  SystemTheme dco_decode_system_theme(dynamic raw);
+@protected AudioTagChanges dco_decode_box_autoadd_audio_tag_changes(dynamic raw);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected CoverOptions dco_decode_box_autoadd_cover_options(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected CoverPalette dco_decode_box_autoadd_cover_palette(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected CueTrack dco_decode_box_autoadd_cue_track(dynamic raw);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected MidiInfo dco_decode_box_autoadd_midi_info(dynamic raw);
 
-  @protected
-  SmtcFlutter
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SseDeserializer deserializer);
+@protected PaletteColor dco_decode_box_autoadd_palette_color(dynamic raw);
 
-  @protected
-  SmtcFlutter
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SseDeserializer deserializer);
+@protected ReplayGain dco_decode_box_autoadd_replay_gain(dynamic raw);
 
-  @protected
-  SmtcFlutter
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SseDeserializer deserializer);
+@protected ScanRules dco_decode_box_autoadd_scan_rules(dynamic raw);
 
-  @protected
-  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
-      SseDeserializer deserializer);
+@protected ThumbnailCacheOptions dco_decode_box_autoadd_thumbnail_cache_options(dynamic raw);
 
-  @protected
-  RustStreamSink<IndexActionState> sse_decode_StreamSink_index_action_state_Sse(
-      SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_16(dynamic raw);
 
-  @protected
-  RustStreamSink<SMTCControlEvent> sse_decode_StreamSink_smtc_control_event_Sse(
-      SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_8(dynamic raw);
 
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+@protected CoverOptions dco_decode_cover_options(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected CoverPalette dco_decode_cover_palette(dynamic raw);
 
-  @protected
-  IndexActionState sse_decode_index_action_state(SseDeserializer deserializer);
+@protected CueTrack dco_decode_cue_track(dynamic raw);
 
-  @protected
-  InstalledFont sse_decode_installed_font(SseDeserializer deserializer);
+@protected EmbeddedPicture dco_decode_embedded_picture(dynamic raw);
 
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+@protected double dco_decode_f_64(dynamic raw);
 
-  @protected
-  List<InstalledFont> sse_decode_list_installed_font(
-      SseDeserializer deserializer);
+@protected int dco_decode_i_32(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected IndexActionState dco_decode_index_action_state(dynamic raw);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected InstalledFont dco_decode_installed_font(dynamic raw);
 
-  @protected
-  List<InstalledFont>? sse_decode_opt_list_installed_font(
-      SseDeserializer deserializer);
+@protected LibraryEvent dco_decode_library_event(dynamic raw);
 
-  @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected LibraryEventKind dco_decode_library_event_kind(dynamic raw);
 
-  @protected
-  (int, int, int, int) sse_decode_record_u_8_u_8_u_8_u_8(
-      SseDeserializer deserializer);
+@protected List<String> dco_decode_list_String(dynamic raw);
 
-  @protected
-  SMTCControlEvent sse_decode_smtc_control_event(SseDeserializer deserializer);
+@protected List<Audio> dco_decode_list_audio(dynamic raw);
 
-  @protected
-  SMTCState sse_decode_smtc_state(SseDeserializer deserializer);
+@protected List<AudioFolder> dco_decode_list_audio_folder(dynamic raw);
 
-  @protected
-  SystemTheme sse_decode_system_theme(SseDeserializer deserializer);
+@protected List<AudioRelocation> dco_decode_list_audio_relocation(dynamic raw);
 
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+@protected List<EmbeddedPicture> dco_decode_list_embedded_picture(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected List<InstalledFont> dco_decode_list_installed_font(dynamic raw);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer);
+@protected List<TrackLoudness> dco_decode_list_track_loudness(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SmtcFlutter self, SseSerializer serializer);
+@protected MidiInfo dco_decode_midi_info(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SmtcFlutter self, SseSerializer serializer);
+@protected String? dco_decode_opt_String(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SmtcFlutter self, SseSerializer serializer);
+@protected Audio? dco_decode_opt_box_autoadd_audio(dynamic raw);
 
-  @protected
-  void sse_encode_StreamSink_String_Sse(
-      RustStreamSink<String> self, SseSerializer serializer);
+@protected AudioFolder? dco_decode_opt_box_autoadd_audio_folder(dynamic raw);
 
-  @protected
-  void sse_encode_StreamSink_index_action_state_Sse(
-      RustStreamSink<IndexActionState> self, SseSerializer serializer);
+@protected CoverPalette? dco_decode_opt_box_autoadd_cover_palette(dynamic raw);
 
-  @protected
-  void sse_encode_StreamSink_smtc_control_event_Sse(
-      RustStreamSink<SMTCControlEvent> self, SseSerializer serializer);
+@protected CueTrack? dco_decode_opt_box_autoadd_cue_track(dynamic raw);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+@protected MidiInfo? dco_decode_opt_box_autoadd_midi_info(dynamic raw);
 
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+@protected PaletteColor? dco_decode_opt_box_autoadd_palette_color(dynamic raw);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected ReplayGain? dco_decode_opt_box_autoadd_replay_gain(dynamic raw);
 
-  @protected
-  void sse_encode_index_action_state(
-      IndexActionState self, SseSerializer serializer);
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
 
-  @protected
-  void sse_encode_installed_font(InstalledFont self, SseSerializer serializer);
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  void sse_encode_list_installed_font(
-      List<InstalledFont> self, SseSerializer serializer);
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer);
+@protected List<InstalledFont>? dco_decode_opt_list_installed_font(dynamic raw);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  void sse_encode_opt_list_installed_font(
-      List<InstalledFont>? self, SseSerializer serializer);
+@protected PaletteColor dco_decode_palette_color(dynamic raw);
 
-  @protected
-  void sse_encode_opt_list_prim_u_8_strict(
-      Uint8List? self, SseSerializer serializer);
+@protected PlaylistFormat dco_decode_playlist_format(dynamic raw);
 
-  @protected
-  void sse_encode_record_u_8_u_8_u_8_u_8(
-      (int, int, int, int) self, SseSerializer serializer);
+@protected (int,int,int,int) dco_decode_record_u_8_u_8_u_8_u_8(dynamic raw);
 
-  @protected
-  void sse_encode_smtc_control_event(
-      SMTCControlEvent self, SseSerializer serializer);
+@protected ReplayGain dco_decode_replay_gain(dynamic raw);
 
-  @protected
-  void sse_encode_smtc_state(SMTCState self, SseSerializer serializer);
+@protected ScanRules dco_decode_scan_rules(dynamic raw);
 
-  @protected
-  void sse_encode_system_theme(SystemTheme self, SseSerializer serializer);
+@protected SMTCControlEvent dco_decode_smtc_control_event(dynamic raw);
 
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+@protected SMTCState dco_decode_smtc_state(dynamic raw);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected SystemTheme dco_decode_system_theme(dynamic raw);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected ThumbnailCacheOptions dco_decode_thumbnail_cache_options(dynamic raw);
+
+@protected ThumbnailFormat dco_decode_thumbnail_format(dynamic raw);
+
+@protected TrackLoudness dco_decode_track_loudness(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected SmtcFlutter sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SseDeserializer deserializer);
+
+@protected SmtcFlutter sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SseDeserializer deserializer);
+
+@protected SmtcFlutter sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SseDeserializer deserializer);
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<IndexActionState> sse_decode_StreamSink_index_action_state_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<LibraryEvent> sse_decode_StreamSink_library_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<SMTCControlEvent> sse_decode_StreamSink_smtc_control_event_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AlbumLoudness sse_decode_album_loudness(SseDeserializer deserializer);
+
+@protected Audio sse_decode_audio(SseDeserializer deserializer);
+
+@protected AudioFolder sse_decode_audio_folder(SseDeserializer deserializer);
+
+@protected AudioRelocation sse_decode_audio_relocation(SseDeserializer deserializer);
+
+@protected AudioTagChanges sse_decode_audio_tag_changes(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected Audio sse_decode_box_autoadd_audio(SseDeserializer deserializer);
+
+@protected AudioFolder sse_decode_box_autoadd_audio_folder(SseDeserializer deserializer);
+
+@protected AudioTagChanges sse_decode_box_autoadd_audio_tag_changes(SseDeserializer deserializer);
+
+@protected CoverOptions sse_decode_box_autoadd_cover_options(SseDeserializer deserializer);
+
+@protected CoverPalette sse_decode_box_autoadd_cover_palette(SseDeserializer deserializer);
+
+@protected CueTrack sse_decode_box_autoadd_cue_track(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected MidiInfo sse_decode_box_autoadd_midi_info(SseDeserializer deserializer);
+
+@protected PaletteColor sse_decode_box_autoadd_palette_color(SseDeserializer deserializer);
+
+@protected ReplayGain sse_decode_box_autoadd_replay_gain(SseDeserializer deserializer);
+
+@protected ScanRules sse_decode_box_autoadd_scan_rules(SseDeserializer deserializer);
+
+@protected ThumbnailCacheOptions sse_decode_box_autoadd_thumbnail_cache_options(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected CoverOptions sse_decode_cover_options(SseDeserializer deserializer);
+
+@protected CoverPalette sse_decode_cover_palette(SseDeserializer deserializer);
+
+@protected CueTrack sse_decode_cue_track(SseDeserializer deserializer);
+
+@protected EmbeddedPicture sse_decode_embedded_picture(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected IndexActionState sse_decode_index_action_state(SseDeserializer deserializer);
+
+@protected InstalledFont sse_decode_installed_font(SseDeserializer deserializer);
+
+@protected LibraryEvent sse_decode_library_event(SseDeserializer deserializer);
+
+@protected LibraryEventKind sse_decode_library_event_kind(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<Audio> sse_decode_list_audio(SseDeserializer deserializer);
+
+@protected List<AudioFolder> sse_decode_list_audio_folder(SseDeserializer deserializer);
+
+@protected List<AudioRelocation> sse_decode_list_audio_relocation(SseDeserializer deserializer);
+
+@protected List<EmbeddedPicture> sse_decode_list_embedded_picture(SseDeserializer deserializer);
+
+@protected List<InstalledFont> sse_decode_list_installed_font(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<TrackLoudness> sse_decode_list_track_loudness(SseDeserializer deserializer);
+
+@protected MidiInfo sse_decode_midi_info(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected Audio? sse_decode_opt_box_autoadd_audio(SseDeserializer deserializer);
+
+@protected AudioFolder? sse_decode_opt_box_autoadd_audio_folder(SseDeserializer deserializer);
+
+@protected CoverPalette? sse_decode_opt_box_autoadd_cover_palette(SseDeserializer deserializer);
+
+@protected CueTrack? sse_decode_opt_box_autoadd_cue_track(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected MidiInfo? sse_decode_opt_box_autoadd_midi_info(SseDeserializer deserializer);
+
+@protected PaletteColor? sse_decode_opt_box_autoadd_palette_color(SseDeserializer deserializer);
+
+@protected ReplayGain? sse_decode_opt_box_autoadd_replay_gain(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected List<InstalledFont>? sse_decode_opt_list_installed_font(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected PaletteColor sse_decode_palette_color(SseDeserializer deserializer);
+
+@protected PlaylistFormat sse_decode_playlist_format(SseDeserializer deserializer);
+
+@protected (int,int,int,int) sse_decode_record_u_8_u_8_u_8_u_8(SseDeserializer deserializer);
+
+@protected ReplayGain sse_decode_replay_gain(SseDeserializer deserializer);
+
+@protected ScanRules sse_decode_scan_rules(SseDeserializer deserializer);
+
+@protected SMTCControlEvent sse_decode_smtc_control_event(SseDeserializer deserializer);
+
+@protected SMTCState sse_decode_smtc_state(SseDeserializer deserializer);
+
+@protected SystemTheme sse_decode_system_theme(SseDeserializer deserializer);
+
+@protected ThumbnailCacheOptions sse_decode_thumbnail_cache_options(SseDeserializer deserializer);
+
+@protected ThumbnailFormat sse_decode_thumbnail_format(SseDeserializer deserializer);
+
+@protected TrackLoudness sse_decode_track_loudness(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SmtcFlutter self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SmtcFlutter self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SmtcFlutter self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_index_action_state_Sse(RustStreamSink<IndexActionState> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_library_event_Sse(RustStreamSink<LibraryEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_smtc_control_event_Sse(RustStreamSink<SMTCControlEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_album_loudness(AlbumLoudness self, SseSerializer serializer);
+
+@protected void sse_encode_audio(Audio self, SseSerializer serializer);
+
+@protected void sse_encode_audio_folder(AudioFolder self, SseSerializer serializer);
+
+@protected void sse_encode_audio_relocation(AudioRelocation self, SseSerializer serializer);
+
+@protected void sse_encode_audio_tag_changes(AudioTagChanges self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_audio(Audio self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_audio_folder(AudioFolder self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_audio_tag_changes(AudioTagChanges self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_cover_options(CoverOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_cover_palette(CoverPalette self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_cue_track(CueTrack self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_midi_info(MidiInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_palette_color(PaletteColor self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_replay_gain(ReplayGain self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_scan_rules(ScanRules self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_thumbnail_cache_options(ThumbnailCacheOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_cover_options(CoverOptions self, SseSerializer serializer);
+
+@protected void sse_encode_cover_palette(CoverPalette self, SseSerializer serializer);
+
+@protected void sse_encode_cue_track(CueTrack self, SseSerializer serializer);
+
+@protected void sse_encode_embedded_picture(EmbeddedPicture self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_index_action_state(IndexActionState self, SseSerializer serializer);
+
+@protected void sse_encode_installed_font(InstalledFont self, SseSerializer serializer);
+
+@protected void sse_encode_library_event(LibraryEvent self, SseSerializer serializer);
+
+@protected void sse_encode_library_event_kind(LibraryEventKind self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_audio(List<Audio> self, SseSerializer serializer);
+
+@protected void sse_encode_list_audio_folder(List<AudioFolder> self, SseSerializer serializer);
+
+@protected void sse_encode_list_audio_relocation(List<AudioRelocation> self, SseSerializer serializer);
+
+@protected void sse_encode_list_embedded_picture(List<EmbeddedPicture> self, SseSerializer serializer);
+
+@protected void sse_encode_list_installed_font(List<InstalledFont> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_track_loudness(List<TrackLoudness> self, SseSerializer serializer);
+
+@protected void sse_encode_midi_info(MidiInfo self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_audio(Audio? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_audio_folder(AudioFolder? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_cover_palette(CoverPalette? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_cue_track(CueTrack? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_midi_info(MidiInfo? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_palette_color(PaletteColor? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_replay_gain(ReplayGain? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_installed_font(List<InstalledFont>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
+
+@protected void sse_encode_palette_color(PaletteColor self, SseSerializer serializer);
+
+@protected void sse_encode_playlist_format(PlaylistFormat self, SseSerializer serializer);
+
+@protected void sse_encode_record_u_8_u_8_u_8_u_8((int,int,int,int) self, SseSerializer serializer);
+
+@protected void sse_encode_replay_gain(ReplayGain self, SseSerializer serializer);
+
+@protected void sse_encode_scan_rules(ScanRules self, SseSerializer serializer);
+
+@protected void sse_encode_smtc_control_event(SMTCControlEvent self, SseSerializer serializer);
+
+@protected void sse_encode_smtc_state(SMTCState self, SseSerializer serializer);
+
+@protected void sse_encode_system_theme(SystemTheme self, SseSerializer serializer);
+
+@protected void sse_encode_thumbnail_cache_options(ThumbnailCacheOptions self, SseSerializer serializer);
+
+@protected void sse_encode_thumbnail_format(ThumbnailFormat self, SseSerializer serializer);
+
+@protected void sse_encode_track_loudness(TrackLoudness self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
-
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-      _lookup;
-
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-      : _lookup = dynamicLibrary.lookup;
-
-  void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_coriander_player_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter');
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_coriander_player_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter');
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-}
+
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
+
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_coriander_player_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_coriander_player_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+        }
+        
\ No newline at end of file
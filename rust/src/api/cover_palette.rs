@@ -0,0 +1,417 @@
+// 从封面中提取调色板，用于根据封面给界面着色。
+// 结果按调用者给出的专辑缓存，同一张专辑的音乐只计算一次。
+// 缓存记录每首音乐和所在文件夹的修改时间，封面在应用之外被修改后会重新提取。
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+    sync::{Mutex, MutexGuard},
+    time::SystemTime,
+};
+
+use flutter_rust_bridge::frb;
+use image::{imageops, DynamicImage};
+
+use super::{cue_sheet::source_file_of, tag_reader::get_original_picture};
+
+/// on_color 和 color 的最小对比度（WCAG AA 正文）
+const MIN_CONTRAST: f64 = 4.5;
+
+/// 提取前把封面缩小到这个尺寸以内
+const SAMPLE_SIZE: u32 = 64;
+
+/// 每个通道保留的位数，相近的颜色被归为同一类
+const QUANTIZE_BITS: u8 = 4;
+
+/// 透明度低于它的像素不参与统计
+const MIN_ALPHA: u8 = 128;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaletteColor {
+    /// a, r, g, b
+    pub color: (u8, u8, u8, u8),
+    /// 显示在 color 上的文字、图标的颜色。a, r, g, b
+    /// 和 color 的对比度不低于 4.5:1，尽量保留 color 的色相
+    pub on_color: (u8, u8, u8, u8),
+    /// 这种颜色在封面中的占比，0.0 ~ 1.0
+    pub population: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverPalette {
+    /// 封面中占比最大的颜色
+    pub dominant: PaletteColor,
+    /// 饱和度高、亮度适中的颜色。封面中没有这样的颜色时为 None
+    pub vibrant: Option<PaletteColor>,
+    /// 饱和度低、亮度适中的颜色。封面中没有这样的颜色时为 None
+    pub muted: Option<PaletteColor>,
+}
+
+/// 缓存的调色板的最大数量，超过时删除最久没有使用的
+const COVER_PALETTE_CACHE_CAPACITY: usize = 256;
+
+struct CachedPalette {
+    palette: CoverPalette,
+    /// 通过这个缓存取得过调色板的音乐和取得时的 [_modified_stamp]，
+    /// 修改它们的封面时删除这个缓存
+    paths: BTreeMap<String, ModifiedStamp>,
+    /// 最近一次使用的序号，越大越新
+    last_used: u64,
+}
+
+#[frb(ignore)]
+#[derive(Default)]
+struct CoverPaletteCache {
+    /// K: album_key 或者 path
+    entries: BTreeMap<String, CachedPalette>,
+    /// 每次使用加一
+    clock: u64,
+}
+
+/// 音乐文件和所在文件夹（文件夹中的封面图片可能被替换）的修改时间
+type ModifiedStamp = (Option<SystemTime>, Option<SystemTime>);
+
+/// 虚拟音轨使用整轨文件的修改时间
+fn _modified_stamp(path: &str) -> ModifiedStamp {
+    let path = Path::new(source_file_of(path));
+    let modified = |path: &Path| fs::metadata(path).and_then(|val| val.modified()).ok();
+    (modified(path), path.parent().and_then(modified))
+}
+
+static COVER_PALETTE_CACHE: Mutex<Option<CoverPaletteCache>> = Mutex::new(None);
+
+fn _lock_cover_palette_cache() -> MutexGuard<'static, Option<CoverPaletteCache>> {
+    match COVER_PALETTE_CACHE.lock() {
+        Ok(val) => val,
+        Err(val) => val.into_inner(),
+    }
+}
+
+/// for Flutter  
+/// 从 path 的封面（和 [super::tag_reader::get_picture_from_path] 使用的封面相同）中提取调色板。
+/// album_key 相同的音乐（例如传入 专辑艺术家 + 专辑名）共用第一次提取的结果；
+/// 为 None 时每首音乐单独缓存。最多缓存 256 个调色板。
+/// 没有封面或者封面无法解码时返回 None
+pub fn get_cover_palette(path: String, album_key: Option<String>) -> Option<CoverPalette> {
+    let key = album_key.unwrap_or_else(|| path.clone());
+    let stamp = _modified_stamp(&path);
+    {
+        let mut cache = _lock_cover_palette_cache();
+        let cache = cache.get_or_insert_with(CoverPaletteCache::default);
+        cache.clock += 1;
+        let clock = cache.clock;
+        if let Some(entry) = cache.entries.get_mut(&key) {
+            // 这首音乐的修改时间变化后，封面可能已经不同，重新提取
+            if entry.paths.get(&path).is_none_or(|val| *val == stamp) {
+                entry.last_used = clock;
+                entry.paths.insert(path, stamp);
+                return Some(entry.palette.clone());
+            }
+            cache.entries.remove(&key);
+        }
+    }
+
+    let pic = get_original_picture(&path)?;
+    let palette = _extract_palette(&image::load_from_memory(&pic).ok()?)?;
+
+    let mut cache = _lock_cover_palette_cache();
+    let cache = cache.get_or_insert_with(CoverPaletteCache::default);
+    if cache.entries.len() >= COVER_PALETTE_CACHE_CAPACITY && !cache.entries.contains_key(&key) {
+        let oldest = cache
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(key, _)| key.clone());
+        if let Some(oldest) = oldest {
+            cache.entries.remove(&oldest);
+        }
+    }
+    cache.entries.insert(
+        key,
+        CachedPalette {
+            palette: palette.clone(),
+            paths: BTreeMap::from([(path, stamp)]),
+            last_used: cache.clock,
+        },
+    );
+
+    Some(palette)
+}
+
+/// for Flutter  
+/// 清空 [get_cover_palette] 的缓存
+#[frb(sync)]
+pub fn clear_cover_palette_cache() {
+    *_lock_cover_palette_cache() = None;
+}
+
+/// 封面被修改后，删除 paths 中的音乐用过的调色板。
+/// CUE 虚拟音轨和它所在的整轨文件共用封面，按整轨文件比较
+pub(crate) fn invalidate_cover_palettes(paths: &[&str]) {
+    let sources: BTreeSet<&str> = paths.iter().map(|item| source_file_of(item)).collect();
+    if let Some(cache) = _lock_cover_palette_cache().as_mut() {
+        cache.entries.retain(|_, entry| {
+            !entry
+                .paths
+                .keys()
+                .any(|item| sources.contains(source_file_of(item)))
+        });
+    }
+}
+
+/// 把封面的颜色量化后按数量统计，再按照饱和度、亮度和占比为每个目标挑选颜色。
+/// 图片完全透明时返回 None
+fn _extract_palette(pic: &DynamicImage) -> Option<CoverPalette> {
+    let sample = pic
+        .resize(SAMPLE_SIZE, SAMPLE_SIZE, imageops::FilterType::Triangle)
+        .to_rgba8();
+
+    // K: 量化后的颜色，V: (像素数量, r, g, b 之和)
+    let mut buckets: BTreeMap<u32, (u64, [u64; 3])> = BTreeMap::new();
+    let shift = 8 - QUANTIZE_BITS;
+    for pixel in sample.pixels() {
+        let [r, g, b, a] = pixel.0;
+        if a < MIN_ALPHA {
+            continue;
+        }
+
+        let key = ((r >> shift) as u32) << (QUANTIZE_BITS * 2)
+            | ((g >> shift) as u32) << QUANTIZE_BITS
+            | (b >> shift) as u32;
+        let bucket = buckets.entry(key).or_insert((0, [0; 3]));
+        bucket.0 += 1;
+        bucket.1[0] += r as u64;
+        bucket.1[1] += g as u64;
+        bucket.1[2] += b as u64;
+    }
+
+    let total: u64 = buckets.values().map(|(count, _)| count).sum();
+    if total == 0 {
+        return None;
+    }
+
+    // 每一类取平均颜色
+    let swatches: Vec<Swatch> = buckets
+        .values()
+        .map(|(count, sum)| {
+            let rgb = [
+                (sum[0] / count) as u8,
+                (sum[1] / count) as u8,
+                (sum[2] / count) as u8,
+            ];
+            let (saturation, lightness) = _saturation_and_lightness(rgb);
+            Swatch {
+                rgb,
+                population: *count as f64 / total as f64,
+                saturation,
+                lightness,
+            }
+        })
+        .collect();
+
+    let dominant = swatches
+        .iter()
+        .max_by(|a, b| a.population.total_cmp(&b.population))?;
+    let max_population = dominant.population;
+
+    Some(CoverPalette {
+        dominant: dominant.to_palette_color(),
+        vibrant: _best_swatch(&swatches, &VIBRANT_TARGET, max_population)
+            .map(Swatch::to_palette_color),
+        muted: _best_swatch(&swatches, &MUTED_TARGET, max_population).map(Swatch::to_palette_color),
+    })
+}
+
+struct Swatch {
+    rgb: [u8; 3],
+    population: f64,
+    saturation: f64,
+    lightness: f64,
+}
+
+impl Swatch {
+    fn to_palette_color(&self) -> PaletteColor {
+        PaletteColor {
+            color: _argb(self.rgb),
+            on_color: _argb(_on_color(self.rgb)),
+            population: self.population,
+        }
+    }
+}
+
+/// 挑选颜色的条件，和 Android Palette 的 Target 类似
+struct Target {
+    min_saturation: f64,
+    target_saturation: f64,
+    max_saturation: f64,
+    min_lightness: f64,
+    target_lightness: f64,
+    max_lightness: f64,
+}
+
+const VIBRANT_TARGET: Target = Target {
+    min_saturation: 0.35,
+    target_saturation: 1.0,
+    max_saturation: 1.0,
+    min_lightness: 0.3,
+    target_lightness: 0.5,
+    max_lightness: 0.7,
+};
+
+const MUTED_TARGET: Target = Target {
+    min_saturation: 0.0,
+    target_saturation: 0.3,
+    max_saturation: 0.4,
+    min_lightness: 0.3,
+    target_lightness: 0.5,
+    max_lightness: 0.7,
+};
+
+/// 在满足 target 的颜色中，按照 饱和度、亮度与目标的接近程度 和 占比 打分，返回分数最高的颜色
+fn _best_swatch<'a>(
+    swatches: &'a [Swatch],
+    target: &Target,
+    max_population: f64,
+) -> Option<&'a Swatch> {
+    const SATURATION_WEIGHT: f64 = 0.24;
+    const LIGHTNESS_WEIGHT: f64 = 0.52;
+    const POPULATION_WEIGHT: f64 = 0.24;
+
+    let score = |swatch: &Swatch| {
+        SATURATION_WEIGHT * (1.0 - (swatch.saturation - target.target_saturation).abs())
+            + LIGHTNESS_WEIGHT * (1.0 - (swatch.lightness - target.target_lightness).abs())
+            + POPULATION_WEIGHT * (swatch.population / max_population)
+    };
+
+    swatches
+        .iter()
+        .filter(|swatch| {
+            (target.min_saturation..=target.max_saturation).contains(&swatch.saturation)
+                && (target.min_lightness..=target.max_lightness).contains(&swatch.lightness)
+        })
+        .max_by(|a, b| score(a).total_cmp(&score(b)))
+}
+
+/// 把 rgb 向白色或黑色（对比度更高的一边）混合，直到对比度不低于 [MIN_CONTRAST]。
+/// 白色和黑色中总有一个与任何颜色的对比度不低于 4.58:1，所以一定能满足
+fn _on_color(rgb: [u8; 3]) -> [u8; 3] {
+    let luminance = _relative_luminance(rgb);
+    let toward: [u8; 3] = if _contrast_ratio(luminance, 1.0) >= _contrast_ratio(luminance, 0.0) {
+        [255, 255, 255]
+    } else {
+        [0, 0, 0]
+    };
+
+    for step in 1..=20 {
+        let amount = step as f64 / 20.0;
+        let mixed = [0, 1, 2].map(|index| {
+            (rgb[index] as f64 + (toward[index] as f64 - rgb[index] as f64) * amount).round() as u8
+        });
+        if _contrast_ratio(luminance, _relative_luminance(mixed)) >= MIN_CONTRAST {
+            return mixed;
+        }
+    }
+    toward
+}
+
+/// WCAG 2.x 的相对亮度
+fn _relative_luminance(rgb: [u8; 3]) -> f64 {
+    let linear = rgb.map(|value| {
+        let value = value as f64 / 255.0;
+        if value <= 0.03928 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    });
+    0.2126 * linear[0] + 0.7152 * linear[1] + 0.0722 * linear[2]
+}
+
+fn _contrast_ratio(a: f64, b: f64) -> f64 {
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// HSL 中的 (saturation, lightness)，都在 0.0 ~ 1.0 之间
+fn _saturation_and_lightness(rgb: [u8; 3]) -> (f64, f64) {
+    let [r, g, b] = rgb.map(|value| value as f64 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let lightness = (max + min) / 2.0;
+    if max == min {
+        return (0.0, lightness);
+    }
+
+    let saturation = (max - min) / (1.0 - (2.0 * lightness - 1.0).abs());
+    (saturation.min(1.0), lightness)
+}
+
+fn _argb(rgb: [u8; 3]) -> (u8, u8, u8, u8) {
+    (255, rgb[0], rgb[1], rgb[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn on_color_meets_min_contrast() {
+        let colors: [[u8; 3]; 12] = [
+            [0, 0, 0],
+            [255, 255, 255],
+            [119, 119, 119],
+            [128, 128, 128],
+            [255, 0, 0],
+            [0, 255, 0],
+            [0, 0, 255],
+            [255, 255, 0],
+            [0, 255, 255],
+            [255, 0, 255],
+            [255, 128, 0],
+            [128, 0, 255],
+        ];
+        for rgb in colors {
+            let on_color = _on_color(rgb);
+            let ratio = _contrast_ratio(_relative_luminance(rgb), _relative_luminance(on_color));
+            assert!(
+                ratio >= MIN_CONTRAST,
+                "{:?} on {:?}: {}",
+                on_color,
+                rgb,
+                ratio
+            );
+        }
+    }
+
+    #[test]
+    fn modified_cover_is_extracted_again() {
+        let dir = std::env::temp_dir().join(format!(
+            "coriander_cover_palette_{}_modified",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let audio = dir.join("audio.mp3");
+        fs::write(&audio, b"not really audio").unwrap();
+        let path = audio.to_string_lossy().to_string();
+        let album_key = Some(format!("{}-album", path));
+
+        let red = image::RgbImage::from_pixel(4, 4, image::Rgb([255, 0, 0]));
+        red.save(dir.join("cover.png")).unwrap();
+        let palette = get_cover_palette(path.clone(), album_key.clone()).unwrap();
+        assert_eq!(palette.dominant.color, (255, 255, 0, 0));
+
+        // 替换文件夹中的封面，文件夹的修改时间随之变化
+        fs::remove_file(dir.join("cover.png")).unwrap();
+        let blue = image::RgbImage::from_pixel(4, 4, image::Rgb([0, 0, 255]));
+        blue.save(dir.join("cover.png")).unwrap();
+        fs::File::open(&dir)
+            .unwrap()
+            .set_modified(SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000))
+            .unwrap();
+        let palette = get_cover_palette(path, album_key).unwrap();
+        assert_eq!(palette.dominant.color, (255, 0, 0, 255));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,55 @@
+// 封面的 BlurHash 占位图。建立索引时计算并保存在索引中，
+// Flutter 可以在 get_picture_from_path 返回之前立即画出模糊的封面。
+
+use std::fs;
+
+use super::{logger::log_to_dart, tag_reader::get_original_picture};
+
+/// 计算前把封面缩小到这个尺寸以内。BlurHash 只保留低频信息，更大的图片不会让结果更好
+const SAMPLE_SIZE: u32 = 32;
+
+/// 较长的一边使用的分量数。分量越多越清晰，字符串也越长（4x4 为 28 个字符）
+const MAX_COMPONENTS: u32 = 4;
+
+/// 计算 path 的封面（和 [super::tag_reader::get_picture_from_path] 使用的封面相同）的 BlurHash。
+/// 没有封面或者封面无法解码时返回 None
+pub(crate) fn cover_placeholder_of(path: &str) -> Option<String> {
+    _placeholder_of_picture(&get_original_picture(path)?, path)
+}
+
+/// 计算图片文件（例如文件夹中的 cover.jpg）的 BlurHash。无法读取或者解码时返回 None
+pub(crate) fn image_placeholder_of(image_path: &str) -> Option<String> {
+    let pic = match fs::read(image_path) {
+        Ok(val) => val,
+        Err(err) => {
+            log_to_dart(format!("fail to read {}: {}", image_path, err));
+            return None;
+        }
+    };
+    _placeholder_of_picture(&pic, image_path)
+}
+
+fn _placeholder_of_picture(pic: &[u8], path: &str) -> Option<String> {
+    let sample = image::load_from_memory(pic)
+        .ok()?
+        .thumbnail(SAMPLE_SIZE, SAMPLE_SIZE)
+        .to_rgba8();
+    let (width, height) = sample.dimensions();
+
+    // 按照宽高比分配分量，保证较短的一边至少有 1 个
+    let (components_x, components_y) = if width >= height {
+        let y = (MAX_COMPONENTS * height + width / 2) / width;
+        (MAX_COMPONENTS, y.max(1))
+    } else {
+        let x = (MAX_COMPONENTS * width + height / 2) / height;
+        (x.max(1), MAX_COMPONENTS)
+    };
+
+    match blurhash::encode(components_x, components_y, width, height, sample.as_raw()) {
+        Ok(val) => Some(val),
+        Err(err) => {
+            log_to_dart(format!("fail to compute placeholder of {}: {}", path, err));
+            None
+        }
+    }
+}
@@ -0,0 +1,957 @@
+// CUE 表单：把一个整轨文件（FLAC、APE、WAV 等）分割为多个虚拟音轨。
+// 虚拟音轨的路径为 整轨文件的路径#音轨号，例如 D:\Music\Album\CDImage.flac#03，
+// 播放时打开 [CueTrack::file] 并从 [CueTrack::start] 播放到 [CueTrack::end]。
+// CUE 可以是同一文件夹中的 .cue 文件，也可以内嵌在整轨文件中（CUESHEET 标签或 FLAC 的 CUESHEET 元数据块）。
+
+use std::{
+    collections::HashSet,
+    fs::{self, File},
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    time::{Duration, UNIX_EPOCH},
+};
+
+use chardetng::EncodingDetector;
+use encoding_rs::Encoding;
+use flutter_rust_bridge::frb;
+use lofty::{
+    file::{AudioFile, FileType, TaggedFile, TaggedFileExt},
+    prelude::ItemKey,
+    tag::Tag,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    logger::log_to_dart,
+    scan_rules::ScanFilter,
+    tag_reader::{Audio, SUPPORT_FORMAT},
+};
+
+/// CUE 的时间 mm:ss:ff 中，每秒 75 帧
+const FRAMES_PER_SECOND: u64 = 75;
+
+/// FLAC 的 CUESHEET 元数据块的类型
+const FLAC_CUESHEET_BLOCK: u8 = 5;
+
+/// 虚拟音轨在整轨文件中的位置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CueTrack {
+    /// .cue 文件的绝对路径。内嵌的 CUE 为整轨文件的路径
+    pub cue_path: String,
+    /// 整轨文件的绝对路径，播放时打开这个文件
+    pub file: String,
+    /// 在整轨文件中的开始位置（INDEX 01），in ms
+    pub start: u64,
+    /// 在整轨文件中的结束位置（下一个音轨的 INDEX 01），in ms。None 表示播放到文件结尾
+    pub end: Option<u64>,
+    /// CUE 是否内嵌在整轨文件中
+    #[serde(default)]
+    pub embedded: bool,
+}
+
+/// 一个文件夹中所有 CUE 表单的虚拟音轨
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct FolderCueSheets {
+    /// 被 CUE 分割的整轨文件，它们本身不会作为音乐被索引
+    pub(crate) images: HashSet<PathBuf>,
+    pub(crate) audios: Vec<Audio>,
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct CueSheet {
+    title: Option<String>,
+    performer: Option<String>,
+    songwriter: Option<String>,
+    genre: Option<String>,
+    date: Option<String>,
+    disc: Option<u32>,
+    disc_total: Option<u32>,
+    files: Vec<CueFile>,
+}
+
+struct CueFile {
+    /// FILE 中的文件名
+    name: String,
+    tracks: Vec<CueSheetTrack>,
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+struct CueSheetTrack {
+    number: u32,
+    /// 数据音轨（MODE1/2352 等）不会被索引
+    is_audio: bool,
+    title: Option<String>,
+    performer: Option<String>,
+    songwriter: Option<String>,
+    /// INDEX 01，in ms
+    start: Option<u64>,
+}
+
+/// 读取 folder 中所有的 .cue 文件，生成虚拟音轨。
+/// 多个 CUE 引用同一个整轨文件时（例如不同编码的副本）只使用按文件名排序的第一个。
+/// 每个 FILE 只有一个从头开始的音轨时，它不是整轨文件，不会被分割
+pub(crate) fn read_folder_cue_sheets(folder: &Path, filter: &ScanFilter) -> FolderCueSheets {
+    let mut result = FolderCueSheets::default();
+
+    for (cue_path, cue_sheet, files) in _cue_sheets_in(folder) {
+        let cue_path_str = cue_path.to_string_lossy().to_string();
+        let cue_modified = _modified_secs(&cue_path);
+        let track_total = cue_sheet
+            .files
+            .iter()
+            .flat_map(|item| &item.tracks)
+            .filter(|item| item.is_audio)
+            .count() as u32;
+
+        for (cue_file, image) in cue_sheet.files.iter().zip(files) {
+            let image = match image {
+                Some(value) if !result.images.contains(&value) => value,
+                _ => continue,
+            };
+            let image_audio = match filter.read_audio(&image) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            let tracks: Vec<&CueSheetTrack> = cue_file
+                .tracks
+                .iter()
+                .filter(|item| item.is_usable())
+                .collect();
+            // 只有一个从头开始的音轨时按普通的音乐文件索引
+            if tracks.len() == 1 && tracks[0].start == Some(0) {
+                continue;
+            }
+
+            let audios = _split_image(
+                &image_audio,
+                &cue_sheet,
+                &tracks,
+                track_total,
+                &cue_path_str,
+                cue_modified,
+                false,
+            );
+            result.images.insert(image);
+            result.audios.extend(
+                audios
+                    .into_iter()
+                    .filter(|item| !filter.is_audio_excluded(item)),
+            );
+        }
+    }
+
+    result
+}
+
+/// 整轨文件内嵌的 CUE：Vorbis comment 或 APE 标签中的 CUESHEET，没有的话使用 FLAC 的 CUESHEET 元数据块。
+/// 少于两个音轨时返回 None，此时按普通的音乐文件索引
+pub(crate) fn embedded_cue_sheet_of(path: &Path, tagged_file: &TaggedFile) -> Option<CueSheet> {
+    let has_tracks = |cue_sheet: &CueSheet| {
+        cue_sheet
+            .files
+            .iter()
+            .flat_map(|item| &item.tracks)
+            .filter(|item| item.is_usable())
+            .count()
+            >= 2
+    };
+
+    let text_cue_sheet = tagged_file
+        .tags()
+        .iter()
+        .find_map(|tag| _unknown_tag_text(tag, "CUESHEET"))
+        .map(_parse_cue_sheet)
+        .filter(has_tracks);
+    if text_cue_sheet.is_some() || tagged_file.file_type() != FileType::Flac {
+        return text_cue_sheet;
+    }
+
+    let sample_rate = tagged_file.properties().sample_rate()?;
+    _read_flac_cue_sheet(path, sample_rate, tagged_file.primary_tag()).filter(has_tracks)
+}
+
+/// 按照内嵌的 CUE 把 audio 分割为虚拟音轨，音轨的标题来自 CUE。
+/// 内嵌的 CUE 中的 FILE 都指向整轨文件本身
+pub(crate) fn split_by_embedded_cue_sheet(audio: &Audio, cue_sheet: &CueSheet) -> Vec<Audio> {
+    let tracks: Vec<&CueSheetTrack> = cue_sheet
+        .files
+        .iter()
+        .flat_map(|item| &item.tracks)
+        .filter(|item| item.is_usable())
+        .collect();
+    _split_image(
+        audio,
+        cue_sheet,
+        &tracks,
+        tracks.len() as u32,
+        &audio.path,
+        audio.modified,
+        true,
+    )
+}
+
+impl CueSheetTrack {
+    /// 有开始位置的音频音轨
+    fn is_usable(&self) -> bool {
+        self.is_audio && self.start.is_some()
+    }
+}
+
+/// 按照 tracks 的开始位置把整轨文件分割为虚拟音轨。
+/// 每个音轨结束于下一个音轨的开始，最后一个音轨播放到文件结尾
+fn _split_image(
+    image_audio: &Audio,
+    cue_sheet: &CueSheet,
+    tracks: &[&CueSheetTrack],
+    track_total: u32,
+    cue_path: &str,
+    cue_modified: u64,
+    embedded: bool,
+) -> Vec<Audio> {
+    let mut audios = vec![];
+    for (index, track) in tracks.iter().enumerate() {
+        let start = track.start.unwrap_or(0);
+        let end = tracks.get(index + 1).and_then(|item| item.start);
+
+        let mut audio = image_audio.clone();
+        audio.path = format!("{}#{:02}", image_audio.path, track.number);
+        audio.title = track
+            .title
+            .clone()
+            .unwrap_or_else(|| format!("Track {:02}", track.number));
+        if let Some(performer) = track.performer.clone().or(cue_sheet.performer.clone()) {
+            audio.artist = performer;
+        }
+        if let Some(title) = cue_sheet.title.clone() {
+            audio.album = title;
+        }
+        audio.album_artist = cue_sheet.performer.clone().or(audio.album_artist);
+        audio.track = Some(track.number);
+        audio.track_total = Some(track_total);
+        audio.disc = cue_sheet.disc.or(audio.disc);
+        audio.disc_total = cue_sheet.disc_total.or(audio.disc_total);
+        if let Some(date) = &cue_sheet.date {
+            audio.year = date.get(..4).and_then(|item| item.parse().ok());
+            audio.date = Some(date.clone()).filter(|item| item.len() > 4);
+        }
+        audio.genre = cue_sheet.genre.clone().or(audio.genre);
+        audio.composer = track
+            .songwriter
+            .clone()
+            .or(cue_sheet.songwriter.clone())
+            .or(audio.composer);
+        audio.duration = end
+            .unwrap_or(image_audio.duration * 1000)
+            .saturating_sub(start)
+            / 1000;
+        audio.modified = audio.modified.max(cue_modified);
+        audio.by = Some("CUE".to_string());
+        // 整轨文件的 ReplayGain 是整张专辑的，不适用于单个音轨
+        audio.replay_gain = None;
+        audio.cue = Some(CueTrack {
+            cue_path: cue_path.to_string(),
+            file: image_audio.path.clone(),
+            start,
+            end,
+            embedded,
+        });
+        audio.id = audio.compute_id();
+        audios.push(audio);
+    }
+    audios
+}
+
+/// tag 中 lofty 不认识的字段，不区分大小写
+fn _unknown_tag_text<'a>(tag: &'a Tag, key: &str) -> Option<&'a str> {
+    tag.items().find_map(|item| match item.key() {
+        ItemKey::Unknown(value) if value.eq_ignore_ascii_case(key) => item.value().text(),
+        _ => None,
+    })
+}
+
+/// 读取 FLAC 的 CUESHEET 元数据块。文件开头可能有 ID3v2 标签
+fn _read_flac_cue_sheet(path: &Path, sample_rate: u32, tag: Option<&Tag>) -> Option<CueSheet> {
+    let mut file = File::open(path).ok()?;
+    let mut header = [0u8; 10];
+    file.read_exact(&mut header[..4]).ok()?;
+    if &header[..3] == b"ID3" {
+        file.read_exact(&mut header[4..]).ok()?;
+        // 大小是 synchsafe 整数，不包括 10 字节的头和可能存在的 10 字节的尾
+        let size = header[6..10]
+            .iter()
+            .fold(0u64, |acc, item| (acc << 7) | (*item & 0x7F) as u64);
+        let footer = if header[5] & 0x10 != 0 { 10 } else { 0 };
+        file.seek(SeekFrom::Start(10 + size + footer)).ok()?;
+        file.read_exact(&mut header[..4]).ok()?;
+    }
+    if &header[..4] != b"fLaC" {
+        return None;
+    }
+
+    // 元数据块：1 字节的最后一块标记和类型、3 字节的大小
+    loop {
+        let mut block_header = [0u8; 4];
+        file.read_exact(&mut block_header).ok()?;
+        let len = u32::from_be_bytes([0, block_header[1], block_header[2], block_header[3]]);
+        if block_header[0] & 0x7F == FLAC_CUESHEET_BLOCK {
+            let mut data = vec![0u8; len as usize];
+            file.read_exact(&mut data).ok()?;
+            return Some(_parse_flac_cue_sheet(&data, sample_rate, tag));
+        }
+        if block_header[0] & 0x80 != 0 {
+            return None;
+        }
+        file.seek(SeekFrom::Current(len as i64)).ok()?;
+    }
+}
+
+/// CUESHEET 元数据块的结构：
+/// - 媒体编号 128 字节、lead-in 采样数 8 字节、CD 标记和保留 259 字节、音轨数 1 字节
+/// - 每个音轨：采样偏移 8 字节、音轨号 1 字节、ISRC 12 字节、标记 1 字节（最高位为 1 表示数据音轨）、
+///   保留 13 字节、索引数 1 字节
+/// - 每个索引：相对于音轨的采样偏移 8 字节、索引号 1 字节、保留 3 字节
+///
+/// 元数据块中没有标题，标题和艺术家来自 CUE_TRACKnn_TITLE、CUE_TRACKnn_PERFORMER 标签（foobar2000 的写法）
+fn _parse_flac_cue_sheet(data: &[u8], sample_rate: u32, tag: Option<&Tag>) -> CueSheet {
+    let tag_text = |key: String| {
+        tag.and_then(|tag| _unknown_tag_text(tag, &key))
+            .and_then(|value| _non_empty(value.to_string()))
+    };
+
+    let mut tracks = vec![];
+    let track_count = data.get(395).copied().unwrap_or(0);
+    let mut offset = 396;
+    for _ in 0..track_count {
+        let (track_offset, number, flags, index_count) = match (
+            _u64_be(data, offset),
+            data.get(offset + 8),
+            data.get(offset + 21),
+            data.get(offset + 35),
+        ) {
+            (Some(track_offset), Some(number), Some(flags), Some(index_count)) => {
+                (track_offset, *number, *flags, *index_count)
+            }
+            _ => break,
+        };
+        offset += 36;
+
+        let mut start = None;
+        for _ in 0..index_count {
+            if data.get(offset + 8) == Some(&1) {
+                start = _u64_be(data, offset).and_then(|index_offset| {
+                    track_offset
+                        .saturating_add(index_offset)
+                        .saturating_mul(1000)
+                        .checked_div(sample_rate as u64)
+                });
+            }
+            offset += 12;
+        }
+
+        // lead-out 音轨：CD 为 170，其他为 255
+        if number == 170 || number == 255 {
+            continue;
+        }
+        tracks.push(CueSheetTrack {
+            number: number as u32,
+            is_audio: flags & 0x80 == 0,
+            title: tag_text(format!("CUE_TRACK{:02}_TITLE", number)),
+            performer: tag_text(format!("CUE_TRACK{:02}_PERFORMER", number)),
+            songwriter: None,
+            start,
+        });
+    }
+
+    CueSheet {
+        files: vec![CueFile {
+            name: String::new(),
+            tracks,
+        }],
+        ..Default::default()
+    }
+}
+
+fn _u64_be(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_be_bytes(
+        data.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+/// path 是否是同一文件夹中某个 CUE 引用的整轨文件。只解析 CUE，不读取整轨文件
+pub(crate) fn is_cue_image(path: &Path) -> bool {
+    let folder = match path.parent() {
+        Some(value) => value,
+        None => return false,
+    };
+    _cue_sheets_in(folder)
+        .iter()
+        .flat_map(|(_, _, files)| files)
+        .any(|item| item.as_deref() == Some(path))
+}
+
+/// 虚拟音轨的路径不存在时，返回它所在的整轨文件。其他路径原样返回
+pub(crate) fn source_file_of(path: &str) -> &str {
+    if Path::new(path).exists() {
+        return path;
+    }
+    match path.rsplit_once('#') {
+        Some((file, number))
+            if number.chars().all(|item| item.is_ascii_digit()) && Path::new(file).is_file() =>
+        {
+            file
+        }
+        _ => path,
+    }
+}
+
+/// 虚拟音轨在整轨文件中的位置，和建立索引时的 [Audio::cue] 相同。
+/// path 不是虚拟音轨，或者 CUE 中已经没有这个音轨时返回 None
+pub(crate) fn cue_track_of(path: &str) -> Option<CueTrack> {
+    let file = source_file_of(path);
+    if file == path {
+        return None;
+    }
+
+    let find = |audios: Vec<Audio>| {
+        audios
+            .into_iter()
+            .find(|item| item.path == path)
+            .and_then(|item| item.cue)
+    };
+    let folder_audios = read_folder_cue_sheets(Path::new(file).parent()?, &ScanFilter::default());
+    if let Some(cue) = find(folder_audios.audios) {
+        return Some(cue);
+    }
+
+    let (audio, cue_sheet) = Audio::read_with_embedded_cue_sheet(file)?;
+    find(split_by_embedded_cue_sheet(&audio, &cue_sheet?))
+}
+
+/// folder 中所有 .cue 文件（按文件名排序）的内容，以及每个 FILE 对应的文件
+fn _cue_sheets_in(folder: &Path) -> Vec<(PathBuf, CueSheet, Vec<Option<PathBuf>>)> {
+    let mut entries: Vec<PathBuf> = match fs::read_dir(folder) {
+        Ok(value) => value
+            .filter_map(|item| item.ok())
+            .map(|item| item.path())
+            .filter(|item| item.is_file())
+            .collect(),
+        Err(_) => return vec![],
+    };
+    entries.sort();
+
+    let mut cue_sheets = vec![];
+    for cue_path in entries.iter().filter(|item| {
+        item.extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("cue"))
+    }) {
+        let bytes = match fs::read(cue_path) {
+            Ok(value) => value,
+            Err(err) => {
+                log_to_dart(format!("{:?}: {}", cue_path, err));
+                continue;
+            }
+        };
+        let cue_sheet = _parse_cue_sheet(&decode_text(&bytes));
+        let files = cue_sheet
+            .files
+            .iter()
+            .map(|item| _resolve_file(&item.name, &entries))
+            .collect();
+        cue_sheets.push((cue_path.clone(), cue_sheet, files));
+    }
+
+    cue_sheets
+}
+
+/// 在同一文件夹中寻找 FILE 指向的文件，不区分大小写。
+/// 找不到时寻找文件名相同、扩展名不同的音乐文件（例如 CUE 中是 .wav，实际转换为了 .flac）
+fn _resolve_file(name: &str, entries: &[PathBuf]) -> Option<PathBuf> {
+    // 只使用文件名，忽略 CUE 中记录的文件夹
+    let name = name
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or(name)
+        .to_lowercase();
+    let stem = match name.rsplit_once('.') {
+        Some((stem, _)) => stem,
+        None => name.as_str(),
+    };
+
+    let file_name_of = |item: &PathBuf| {
+        item.file_name()
+            .map(|value| value.to_string_lossy().to_lowercase())
+    };
+    if let Some(file) = entries
+        .iter()
+        .find(|item| file_name_of(item).as_deref() == Some(name.as_str()))
+    {
+        return Some(file.clone());
+    }
+
+    entries
+        .iter()
+        .find(|item| {
+            item.file_stem()
+                .is_some_and(|value| value.to_string_lossy().to_lowercase() == stem)
+                && item.extension().is_some_and(|extension| {
+                    SUPPORT_FORMAT.contains_key(extension.to_string_lossy().to_lowercase().as_str())
+                })
+        })
+        .cloned()
+}
+
+/// 解码 CUE、M3U 等没有规定编码的文本文件。
+/// 有 BOM 时按照 BOM 解码，是合法的 UTF-8 时按 UTF-8 解码，否则猜测编码（GBK、Shift_JIS、Big5 等）
+pub(crate) fn decode_text(bytes: &[u8]) -> String {
+    if let Some((encoding, bom_len)) = Encoding::for_bom(bytes) {
+        return encoding
+            .decode_without_bom_handling(&bytes[bom_len..])
+            .0
+            .into_owned();
+    }
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return text.to_string();
+    }
+
+    let mut detector = EncodingDetector::new();
+    detector.feed(bytes, true);
+    detector
+        .guess(None, false)
+        .decode_without_bom_handling(bytes)
+        .0
+        .into_owned()
+}
+
+fn _parse_cue_sheet(text: &str) -> CueSheet {
+    let mut cue_sheet = CueSheet::default();
+
+    for line in text.lines() {
+        let line = line.trim();
+        let (command, rest) = match line.split_once(char::is_whitespace) {
+            Some((command, rest)) => (command.to_ascii_uppercase(), rest.trim()),
+            None => continue,
+        };
+        let track = cue_sheet
+            .files
+            .last_mut()
+            .and_then(|item| item.tracks.last_mut());
+
+        match (command.as_str(), track) {
+            ("FILE", _) => {
+                // FILE "name" WAVE，没有引号时最后一个词是类型
+                let name = if rest.starts_with('"') {
+                    _unquote(rest)
+                } else {
+                    rest.rsplit_once(char::is_whitespace)
+                        .map(|(name, _)| name.trim())
+                        .unwrap_or(rest)
+                        .to_string()
+                };
+                cue_sheet.files.push(CueFile {
+                    name,
+                    tracks: vec![],
+                });
+            }
+            ("TRACK", _) => {
+                let mut parts = rest.split_whitespace();
+                let number = parts.next().and_then(|item| item.parse().ok()).unwrap_or(0);
+                let is_audio = parts
+                    .next()
+                    .is_none_or(|item| item.eq_ignore_ascii_case("AUDIO"));
+                // 内嵌的 CUE 可能省略 FILE
+                if cue_sheet.files.is_empty() {
+                    cue_sheet.files.push(CueFile {
+                        name: String::new(),
+                        tracks: vec![],
+                    });
+                }
+                if let Some(file) = cue_sheet.files.last_mut() {
+                    file.tracks.push(CueSheetTrack {
+                        number,
+                        is_audio,
+                        ..Default::default()
+                    });
+                }
+            }
+            ("INDEX", Some(track)) => {
+                let mut parts = rest.split_whitespace();
+                if parts.next().and_then(|item| item.parse::<u32>().ok()) == Some(1) {
+                    track.start = parts.next().and_then(_parse_time);
+                }
+            }
+            ("TITLE", Some(track)) => track.title = _non_empty(_unquote(rest)),
+            ("PERFORMER", Some(track)) => track.performer = _non_empty(_unquote(rest)),
+            ("SONGWRITER", Some(track)) => track.songwriter = _non_empty(_unquote(rest)),
+            ("TITLE", None) => cue_sheet.title = _non_empty(_unquote(rest)),
+            ("PERFORMER", None) => cue_sheet.performer = _non_empty(_unquote(rest)),
+            ("SONGWRITER", None) => cue_sheet.songwriter = _non_empty(_unquote(rest)),
+            ("REM", _) => {
+                let (key, value) = match rest.split_once(char::is_whitespace) {
+                    Some((key, value)) => (key.to_ascii_uppercase(), _unquote(value.trim())),
+                    None => continue,
+                };
+                match key.as_str() {
+                    "GENRE" => cue_sheet.genre = _non_empty(value),
+                    "DATE" => cue_sheet.date = _non_empty(value),
+                    "DISCNUMBER" => cue_sheet.disc = value.parse().ok(),
+                    "TOTALDISCS" => cue_sheet.disc_total = value.parse().ok(),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    cue_sheet
+}
+
+/// "value" 或 value
+fn _unquote(value: &str) -> String {
+    match value.strip_prefix('"') {
+        Some(value) => match value.find('"') {
+            Some(end) => value[..end].to_string(),
+            None => value.to_string(),
+        },
+        None => value.to_string(),
+    }
+}
+
+fn _non_empty(value: String) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+/// mm:ss:ff，返回 ms。秒数不小于 60、帧数不小于 75 或者溢出时返回 None
+fn _parse_time(value: &str) -> Option<u64> {
+    let mut parts = value.split(':').map(|item| item.parse::<u64>().ok());
+    let (minutes, seconds, frames) = match (parts.next()?, parts.next()?, parts.next()?) {
+        (Some(minutes), Some(seconds), Some(frames)) => (minutes, seconds, frames),
+        _ => return None,
+    };
+    if parts.next().is_some() || seconds >= 60 || frames >= FRAMES_PER_SECOND {
+        return None;
+    }
+
+    let total_frames = minutes
+        .checked_mul(60)?
+        .checked_add(seconds)?
+        .checked_mul(FRAMES_PER_SECOND)?
+        .checked_add(frames)?;
+    Some(total_frames.checked_mul(1000)? / FRAMES_PER_SECOND)
+}
+
+fn _modified_secs(path: &Path) -> u64 {
+    fs::metadata(path)
+        .and_then(|item| item.modified())
+        .unwrap_or(UNIX_EPOCH)
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use lofty::tag::{ItemValue, TagItem, TagType};
+
+    use super::*;
+
+    const CUE_SHEET: &str = r#"REM GENRE "Classical"
+REM DATE 1998-05-01
+REM DISCNUMBER 2
+REM TOTALDISCS 3
+PERFORMER "Album Artist"
+TITLE "Album"
+FILE "Disc 1.flac" WAVE
+  TRACK 01 AUDIO
+    TITLE "First"
+    INDEX 00 00:00:00
+    INDEX 01 00:00:33
+  TRACK 02 AUDIO
+    TITLE ""
+    PERFORMER "Guest"
+    SONGWRITER "Composer"
+    INDEX 01 03:20:15
+  track 03 mode1/2352
+    INDEX 01 05:00:00
+FILE Disc 2.wav WAVE
+  TRACK 04 AUDIO
+    INDEX 01 00:00:00
+"#;
+
+    #[test]
+    fn cue_sheet_is_parsed() {
+        let cue_sheet = _parse_cue_sheet(CUE_SHEET);
+        assert_eq!(cue_sheet.title.as_deref(), Some("Album"));
+        assert_eq!(cue_sheet.performer.as_deref(), Some("Album Artist"));
+        assert_eq!(cue_sheet.genre.as_deref(), Some("Classical"));
+        assert_eq!(cue_sheet.date.as_deref(), Some("1998-05-01"));
+        assert_eq!(cue_sheet.disc, Some(2));
+        assert_eq!(cue_sheet.disc_total, Some(3));
+
+        assert_eq!(cue_sheet.files.len(), 2);
+        assert_eq!(cue_sheet.files[0].name, "Disc 1.flac");
+        assert_eq!(cue_sheet.files[1].name, "Disc 2.wav");
+
+        let tracks = &cue_sheet.files[0].tracks;
+        assert_eq!(tracks.len(), 3);
+        assert_eq!(tracks[0].number, 1);
+        assert_eq!(tracks[0].title.as_deref(), Some("First"));
+        // INDEX 00 不是开始位置
+        assert_eq!(tracks[0].start, Some(440));
+        assert_eq!(tracks[1].title, None);
+        assert_eq!(tracks[1].performer.as_deref(), Some("Guest"));
+        assert_eq!(tracks[1].songwriter.as_deref(), Some("Composer"));
+        assert_eq!(tracks[1].start, Some(200_200));
+        assert!(!tracks[2].is_audio);
+        assert!(!tracks[2].is_usable());
+        assert_eq!(cue_sheet.files[1].tracks[0].start, Some(0));
+    }
+
+    #[test]
+    fn embedded_cue_sheet_without_file() {
+        let cue_sheet =
+            _parse_cue_sheet("TRACK 01 AUDIO\nINDEX 01 00:00:00\nTRACK 02\nINDEX 01 01:00:00");
+        assert_eq!(cue_sheet.files.len(), 1);
+        assert_eq!(cue_sheet.files[0].name, "");
+        assert_eq!(cue_sheet.files[0].tracks.len(), 2);
+        assert!(cue_sheet.files[0].tracks[1].is_usable());
+    }
+
+    #[test]
+    fn time_is_converted_to_ms() {
+        assert_eq!(_parse_time("00:00:00"), Some(0));
+        assert_eq!(_parse_time("01:02:74"), Some(62_986));
+        assert_eq!(_parse_time("120:00:00"), Some(7_200_000));
+
+        assert_eq!(_parse_time("00:60:00"), None);
+        assert_eq!(_parse_time("00:00:75"), None);
+        assert_eq!(_parse_time("00:00"), None);
+        assert_eq!(_parse_time("00:00:00:00"), None);
+        assert_eq!(_parse_time("aa:00:00"), None);
+        assert_eq!(_parse_time("-1:00:00"), None);
+        assert_eq!(_parse_time("18446744073709551615:00:00"), None);
+        assert_eq!(_parse_time("4099276460824344:00:00"), None);
+    }
+
+    #[test]
+    fn file_is_resolved_by_name_then_stem() {
+        let entries = vec![
+            PathBuf::from("/music/CDImage.APE"),
+            PathBuf::from("/music/other.flac"),
+            PathBuf::from("/music/other.log"),
+        ];
+        assert_eq!(
+            _resolve_file("C:\\rip\\cdimage.ape", &entries),
+            Some(PathBuf::from("/music/CDImage.APE"))
+        );
+        // 转换过格式的整轨文件
+        assert_eq!(
+            _resolve_file("other.wav", &entries),
+            Some(PathBuf::from("/music/other.flac"))
+        );
+        assert_eq!(_resolve_file("missing.wav", &entries), None);
+    }
+
+    #[test]
+    fn text_is_decoded_by_bom_or_detected() {
+        assert_eq!(decode_text(b"\xEF\xBB\xBFTITLE"), "TITLE");
+        assert_eq!(decode_text(b"\xFF\xFET\x00I\x00"), "TI");
+        assert_eq!(decode_text("标题".as_bytes()), "标题");
+        // GBK
+        assert_eq!(
+            decode_text(b"TITLE \"\xD6\xD0\xCE\xC4\xB5\xC4\xB1\xEA\xCC\xE2\""),
+            "TITLE \"中文的标题\""
+        );
+    }
+
+    #[test]
+    fn image_is_split_by_track_starts() {
+        let cue_sheet = _parse_cue_sheet(CUE_SHEET);
+        let image_audio = Audio {
+            title: "CDImage".to_string(),
+            artist: "Image Artist".to_string(),
+            path: "/music/Disc 1.flac".to_string(),
+            duration: 400,
+            modified: 10,
+            ..Default::default()
+        };
+        let tracks: Vec<&CueSheetTrack> = cue_sheet.files[0]
+            .tracks
+            .iter()
+            .filter(|item| item.is_usable())
+            .collect();
+        let audios = _split_image(
+            &image_audio,
+            &cue_sheet,
+            &tracks,
+            3,
+            "/music/a.cue",
+            20,
+            false,
+        );
+
+        assert_eq!(audios.len(), 2);
+        assert_eq!(audios[0].path, "/music/Disc 1.flac#01");
+        assert_eq!(audios[0].title, "First");
+        assert_eq!(audios[0].artist, "Album Artist");
+        assert_eq!(audios[0].album, "Album");
+        assert_eq!(audios[0].year, Some(1998));
+        assert_eq!(audios[0].duration, 199);
+        assert_eq!(audios[0].modified, 20);
+        assert_eq!(
+            audios[0].cue,
+            Some(CueTrack {
+                cue_path: "/music/a.cue".to_string(),
+                file: "/music/Disc 1.flac".to_string(),
+                start: 440,
+                end: Some(200_200),
+                embedded: false,
+            })
+        );
+
+        assert_eq!(audios[1].title, "Track 02");
+        assert_eq!(audios[1].artist, "Guest");
+        assert_eq!(audios[1].composer.as_deref(), Some("Composer"));
+        assert_eq!(audios[1].duration, 199);
+        assert_eq!(audios[1].cue.as_ref().unwrap().end, None);
+        assert_eq!(audios[1].track_total, Some(3));
+    }
+
+    #[test]
+    fn virtual_path_maps_to_image() {
+        let path =
+            std::env::temp_dir().join(format!("coriander_cue_{}_image.flac", std::process::id()));
+        fs::write(&path, b"").unwrap();
+        let file = path.to_string_lossy().to_string();
+
+        let virtual_path = format!("{}#03", file);
+        let not_a_track = format!("{}#a3", file);
+        let missing = format!("{}.missing#03", file);
+        let results = (
+            source_file_of(&file).to_string(),
+            source_file_of(&virtual_path).to_string(),
+            source_file_of(&not_a_track).to_string(),
+            source_file_of(&missing).to_string(),
+        );
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(results.0, file);
+        assert_eq!(results.1, file);
+        assert_eq!(results.2, not_a_track);
+        assert_eq!(results.3, missing);
+    }
+
+    /// (采样偏移, 音轨号, 标记, [(相对采样偏移, 索引号)])
+    type FlacTrack<'a> = (u64, u8, u8, &'a [(u64, u8)]);
+
+    /// CUESHEET 元数据块
+    fn _flac_cue_sheet(tracks: &[FlacTrack]) -> Vec<u8> {
+        let mut data = vec![0u8; 395];
+        data.push(tracks.len() as u8);
+        for (offset, number, flags, indexes) in tracks {
+            data.extend_from_slice(&offset.to_be_bytes());
+            data.push(*number);
+            data.extend_from_slice(&[0; 12]);
+            data.push(*flags);
+            data.extend_from_slice(&[0; 13]);
+            data.push(indexes.len() as u8);
+            for (index_offset, index_number) in indexes.iter() {
+                data.extend_from_slice(&index_offset.to_be_bytes());
+                data.push(*index_number);
+                data.extend_from_slice(&[0; 3]);
+            }
+        }
+        data
+    }
+
+    fn _tracks() -> Vec<u8> {
+        _flac_cue_sheet(&[
+            (0, 1, 0, &[(0, 0), (88_200, 1)]),
+            (441_000, 2, 0, &[(0, 1)]),
+            (882_000, 3, 0x80, &[(0, 1)]),
+            (1_323_000, 170, 0, &[]),
+        ])
+    }
+
+    #[test]
+    fn flac_cue_sheet_offsets() {
+        let mut tag = Tag::new(TagType::VorbisComments);
+        tag.push_unchecked(TagItem::new(
+            ItemKey::Unknown("cue_track01_title".to_string()),
+            ItemValue::Text("First".to_string()),
+        ));
+        tag.push_unchecked(TagItem::new(
+            ItemKey::Unknown("CUE_TRACK02_PERFORMER".to_string()),
+            ItemValue::Text("Guest".to_string()),
+        ));
+
+        let cue_sheet = _parse_flac_cue_sheet(&_tracks(), 44100, Some(&tag));
+        assert_eq!(cue_sheet.files.len(), 1);
+        let tracks = &cue_sheet.files[0].tracks;
+        // lead-out 音轨不会被读取
+        assert_eq!(tracks.len(), 3);
+
+        // 音轨的偏移加上 INDEX 01 的偏移
+        assert_eq!(tracks[0].number, 1);
+        assert_eq!(tracks[0].start, Some(2000));
+        assert_eq!(tracks[0].title.as_deref(), Some("First"));
+        assert_eq!(tracks[1].start, Some(10_000));
+        assert_eq!(tracks[1].title, None);
+        assert_eq!(tracks[1].performer.as_deref(), Some("Guest"));
+        assert!(tracks[1].is_usable());
+        assert_eq!(tracks[2].start, Some(20_000));
+        assert!(!tracks[2].is_audio);
+    }
+
+    #[test]
+    fn truncated_flac_cue_sheet() {
+        let data = _tracks();
+        // 第二个音轨的索引被截断
+        let cue_sheet = _parse_flac_cue_sheet(&data[..396 + 36 + 24 + 36], 44100, None);
+        let tracks = &cue_sheet.files[0].tracks;
+        assert_eq!(tracks.len(), 2);
+        assert_eq!(tracks[0].start, Some(2000));
+        assert_eq!(tracks[1].start, None);
+
+        assert!(_parse_flac_cue_sheet(&data[..100], 44100, None).files[0]
+            .tracks
+            .is_empty());
+        // 采样率为 0 时没有开始位置
+        assert_eq!(
+            _parse_flac_cue_sheet(&data, 0, None).files[0].tracks[0].start,
+            None
+        );
+    }
+
+    #[test]
+    fn flac_cue_sheet_block_is_found() {
+        let cue_sheet = _tracks();
+        let mut data = b"ID3\x04\x00\x00\x00\x00\x00\x02\x00\x00fLaC".to_vec();
+        // STREAMINFO，不是最后一块
+        data.extend_from_slice(&[0, 0, 0, 34]);
+        data.extend_from_slice(&[0; 34]);
+        data.push(0x80 | FLAC_CUESHEET_BLOCK);
+        data.extend_from_slice(&(cue_sheet.len() as u32).to_be_bytes()[1..]);
+        data.extend_from_slice(&cue_sheet);
+
+        let path =
+            std::env::temp_dir().join(format!("coriander_cue_{}_block.flac", std::process::id()));
+        fs::write(&path, &data).unwrap();
+        let found = _read_flac_cue_sheet(&path, 44100, None);
+        // 没有 CUESHEET 元数据块
+        fs::write(&path, &data[..12 + 4 + 4 + 34]).unwrap();
+        let missing = _read_flac_cue_sheet(&path, 44100, None);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(found.unwrap().files[0].tracks.len(), 3);
+        assert!(missing.is_none());
+    }
+}
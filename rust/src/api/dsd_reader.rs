@@ -0,0 +1,479 @@
+// DSD 音乐的文件头和标签：DSF（小端序，结尾为 ID3v2 标签）和 DSDIFF（大端序的 FRM8 块）。
+
+use std::{
+    fs::File,
+    io::{Cursor, Read, Seek, SeekFrom},
+    path::Path,
+};
+
+use anyhow::{anyhow, bail};
+use lofty::{
+    config::ParseOptions,
+    file::{FileType, TaggedFileExt},
+    probe::Probe,
+};
+
+use super::{logger::log_to_dart, tag_reader::Audio};
+
+/// 读取到内存中的元数据块（ID3v2、PROP、DIIN）的最大大小。超过时视为损坏的文件
+const MAX_METADATA_SIZE: u64 = 64 * 1024 * 1024;
+
+/// DSD 每个采样 1 bit
+const DSD_BIT_DEPTH: u8 = 1;
+
+fn _new_audio(path: &Path) -> anyhow::Result<Audio> {
+    Audio::new_with_path(path, Some("DSD".to_string())).ok_or(anyhow!("文件名不正确: {:?}", path))
+}
+
+fn _read_exact_vec(file: &mut File, size: u64) -> anyhow::Result<Vec<u8>> {
+    if size > MAX_METADATA_SIZE {
+        bail!("元数据块太大: {} bytes", size);
+    }
+    let mut data = vec![0u8; size as usize];
+    file.read_exact(&mut data)?;
+    Ok(data)
+}
+
+/// 通过 lofty 解析单独的 ID3v2 标签：把它当作没有音频帧的 MP3 读取。
+/// 后面补上 0，让 lofty 寻找 ID3v1 和 APE 标签时不会越过开头
+fn _apply_id3v2(audio: &mut Audio, mut id3v2: Vec<u8>) {
+    id3v2.extend_from_slice(&[0u8; 128]);
+    let tagged_file = Probe::new(Cursor::new(id3v2))
+        .set_file_type(FileType::Mpeg)
+        .options(ParseOptions::new().read_properties(false))
+        .read();
+
+    match tagged_file {
+        Ok(tagged_file) => {
+            if let Some(tag) = tagged_file.primary_tag() {
+                audio.apply_lofty_tag(tag);
+            }
+        }
+        Err(err) => log_to_dart(format!("fail to read ID3v2 of {}: {}", audio.path, err)),
+    }
+}
+
+fn _u32_le(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn _u64_le(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(
+        data.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+/// DSF 的结构：
+/// - "DSD " 块：块大小、文件大小、元数据（ID3v2）的偏移，没有元数据时为 0
+/// - "fmt " 块：版本、格式、声道类型、声道数、采样率、每个采样的位数、每个声道的采样数……
+/// - "data" 块
+/// - ID3v2 标签
+pub(crate) fn read_dsf(path: &Path) -> anyhow::Result<Audio> {
+    let mut file = File::open(path)?;
+    let file_size = file.metadata()?.len();
+
+    // "DSD " 块 28 字节，"fmt " 块 52 字节
+    let mut header = [0u8; 80];
+    file.read_exact(&mut header)?;
+    if &header[..4] != b"DSD " || &header[28..32] != b"fmt " {
+        bail!("不是 DSF 文件: {:?}", path);
+    }
+    let metadata_offset = _u64_le(&header, 20).unwrap_or(0);
+    let channels = _u32_le(&header, 28 + 24).unwrap_or(0);
+    let sample_rate = _u32_le(&header, 28 + 28).unwrap_or(0);
+    let sample_count = _u64_le(&header, 28 + 36).unwrap_or(0);
+    if channels == 0 || sample_rate == 0 {
+        bail!("DSF 的 fmt 块不正确: {:?}", path);
+    }
+
+    let mut audio = _new_audio(path)?;
+    audio.sample_rate = Some(sample_rate);
+    audio.channels = u8::try_from(channels).ok();
+    audio.bit_depth = Some(DSD_BIT_DEPTH);
+    audio.duration = sample_count / sample_rate as u64;
+    audio.bitrate = Some((sample_rate as u64 * channels as u64 / 1000) as u32);
+
+    if metadata_offset > 0 && metadata_offset < file_size {
+        file.seek(SeekFrom::Start(metadata_offset))?;
+        let id3v2 = _read_exact_vec(&mut file, file_size - metadata_offset)?;
+        _apply_id3v2(&mut audio, id3v2);
+    }
+
+    Ok(audio)
+}
+
+fn _u16_be(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn _u32_be(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn _u64_be(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_be_bytes(
+        data.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+/// DSDIFF 块中的子块：4 字节 ID、8 字节大小（大端序）、数据，大小为奇数时后面有 1 字节的填充
+fn _dff_sub_chunks(data: &[u8]) -> Vec<(&[u8], &[u8])> {
+    let mut chunks = vec![];
+    let mut offset = 0;
+    while let (Some(id), Some(size)) = (data.get(offset..offset + 4), _u64_be(data, offset + 4)) {
+        let start = offset + 12;
+        let end = match usize::try_from(size) {
+            Ok(size) if size <= data.len().saturating_sub(start) => start + size,
+            _ => break,
+        };
+        chunks.push((id, &data[start..end]));
+        offset = end + (size as usize & 1);
+    }
+    chunks
+}
+
+/// DSDIFF 的结构：
+/// - "FRM8" 块，类型为 "DSD "，包含以下的块
+/// - "PROP" 块，类型为 "SND "：采样率（FS）、声道（CHNL）、压缩方式（CMPR）
+/// - "DSD " 块（未压缩的采样）或 "DST " 块（DST 压缩，开头的 FRTE 块记录帧数和帧率）
+/// - "DIIN" 块（可选）：艺术家（DIAR）、标题（DITI）
+/// - "ID3 " 块（可选，非标准但很常见）
+pub(crate) fn read_dff(path: &Path) -> anyhow::Result<Audio> {
+    let mut file = File::open(path)?;
+    let file_size = file.metadata()?.len();
+
+    let mut header = [0u8; 16];
+    file.read_exact(&mut header)?;
+    if &header[..4] != b"FRM8" || &header[12..16] != b"DSD " {
+        bail!("不是 DSDIFF 文件: {:?}", path);
+    }
+    // FRM8 的大小从类型开始计算
+    let form_end = _u64_be(&header, 4)
+        .unwrap_or(0)
+        .saturating_add(12)
+        .min(file_size);
+
+    let mut audio = _new_audio(path)?;
+    let mut sample_rate: Option<u32> = None;
+    let mut channels: Option<u16> = None;
+    let mut dsd_size: Option<u64> = None;
+    let mut dst_frames: Option<(u32, u16)> = None;
+    let mut id3v2: Option<Vec<u8>> = None;
+
+    let mut position = 16;
+    while position + 12 <= form_end {
+        file.seek(SeekFrom::Start(position))?;
+        let mut chunk_header = [0u8; 12];
+        file.read_exact(&mut chunk_header)?;
+        let size = _u64_be(&chunk_header, 4).unwrap_or(0);
+
+        match &chunk_header[..4] {
+            b"PROP" => {
+                let data = _read_exact_vec(&mut file, size)?;
+                if data.get(..4) != Some(b"SND ") {
+                    bail!("DSDIFF 的 PROP 块不正确: {:?}", path);
+                }
+                for (id, data) in _dff_sub_chunks(&data[4..]) {
+                    match id {
+                        b"FS  " => sample_rate = _u32_be(data, 0),
+                        b"CHNL" => channels = _u16_be(data, 0),
+                        _ => {}
+                    }
+                }
+            }
+            b"DSD " => dsd_size = Some(size),
+            b"DST " => {
+                // FRTE 块：4 字节 ID、8 字节大小、4 字节帧数、2 字节帧率
+                let mut frte = [0u8; 18];
+                file.read_exact(&mut frte)?;
+                if &frte[..4] == b"FRTE" {
+                    dst_frames = _u32_be(&frte, 12).zip(_u16_be(&frte, 16));
+                }
+            }
+            b"DIIN" => {
+                let data = _read_exact_vec(&mut file, size)?;
+                for (id, data) in _dff_sub_chunks(&data) {
+                    // 4 字节长度，之后是文本
+                    let text = match _u32_be(data, 0) {
+                        Some(len) => data
+                            .get(4..4 + len as usize)
+                            .map(|item| String::from_utf8_lossy(item).trim().to_string()),
+                        None => None,
+                    };
+                    match (id, text) {
+                        (b"DIAR", Some(text)) if !text.is_empty() => audio.artist = text,
+                        (b"DITI", Some(text)) if !text.is_empty() => audio.title = text,
+                        _ => {}
+                    }
+                }
+            }
+            b"ID3 " => id3v2 = Some(_read_exact_vec(&mut file, size)?),
+            _ => {}
+        }
+
+        position = position
+            .saturating_add(12)
+            .saturating_add(size)
+            .saturating_add(size & 1);
+    }
+
+    let (sample_rate, channels) = match (sample_rate, channels) {
+        (Some(sample_rate), Some(channels)) if sample_rate > 0 && channels > 0 => {
+            (sample_rate, channels)
+        }
+        _ => bail!("DSDIFF 缺少采样率或声道数: {:?}", path),
+    };
+    audio.sample_rate = Some(sample_rate);
+    audio.channels = u8::try_from(channels).ok();
+    audio.bit_depth = Some(DSD_BIT_DEPTH);
+
+    let seconds = match (dsd_size, dst_frames) {
+        // 每个字节是一个声道的 8 个采样
+        (Some(dsd_size), _) => dsd_size as f64 * 8.0 / channels as f64 / sample_rate as f64,
+        (None, Some((frames, frame_rate))) if frame_rate > 0 => frames as f64 / frame_rate as f64,
+        _ => 0.0,
+    };
+    audio.duration = seconds as u64;
+    audio.bitrate = if dsd_size.is_some() {
+        Some((sample_rate as u64 * channels as u64 / 1000) as u32)
+    } else if seconds > 0.0 {
+        // DST 是压缩的，使用平均码率
+        Some((file_size as f64 * 8.0 / seconds / 1000.0).round() as u32)
+    } else {
+        None
+    };
+
+    // ID3v2 的标签比 DIIN 更完整，优先使用
+    if let Some(id3v2) = id3v2 {
+        _apply_id3v2(&mut audio, id3v2);
+    }
+
+    Ok(audio)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use super::*;
+
+    /// 2.8224 MHz（DSD64）
+    const SAMPLE_RATE: u32 = 2_822_400;
+
+    /// 把 data 写入临时文件夹中的 name，返回它的路径
+    fn _fixture(name: &str, data: &[u8]) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("coriander_dsd_{}_{}", std::process::id(), name));
+        fs::write(&path, data).unwrap();
+        path
+    }
+
+    /// 只有文本帧的 ID3v2.3 标签
+    fn _id3v23(frames: &[(&[u8; 4], &str)]) -> Vec<u8> {
+        let mut body = vec![];
+        for (id, text) in frames {
+            body.extend_from_slice(*id);
+            body.extend_from_slice(&(text.len() as u32 + 1).to_be_bytes());
+            body.extend_from_slice(&[0, 0, 0]);
+            body.extend_from_slice(text.as_bytes());
+        }
+        assert!(body.len() < 128);
+
+        let mut tag = b"ID3\x03\x00\x00\x00\x00\x00".to_vec();
+        tag.push(body.len() as u8);
+        tag.extend_from_slice(&body);
+        tag
+    }
+
+    fn _dsf_fixture(seconds: u64, id3v2: Option<&[u8]>) -> Vec<u8> {
+        let data_chunk = [b"data".as_slice(), &16u64.to_le_bytes(), &[0x69; 4]].concat();
+        let metadata_offset = match id3v2 {
+            Some(_) => 28 + 52 + data_chunk.len() as u64,
+            None => 0,
+        };
+        let file_size = metadata_offset + id3v2.map(|item| item.len() as u64).unwrap_or(0);
+
+        let mut data = b"DSD ".to_vec();
+        data.extend_from_slice(&28u64.to_le_bytes());
+        data.extend_from_slice(&file_size.to_le_bytes());
+        data.extend_from_slice(&metadata_offset.to_le_bytes());
+
+        data.extend_from_slice(b"fmt ");
+        data.extend_from_slice(&52u64.to_le_bytes());
+        // 版本、格式（DSD raw）、声道类型（立体声）、声道数
+        for value in [1u32, 0, 2, 2] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&(SAMPLE_RATE as u64 * seconds).to_le_bytes());
+        data.extend_from_slice(&4096u32.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+
+        data.extend_from_slice(&data_chunk);
+        if let Some(id3v2) = id3v2 {
+            data.extend_from_slice(id3v2);
+        }
+        data
+    }
+
+    #[test]
+    fn dsf_fmt_chunk_and_id3v2() {
+        let id3v2 = _id3v23(&[(b"TIT2", "Title"), (b"TPE1", "Artist")]);
+        let path = _fixture("tagged.dsf", &_dsf_fixture(3, Some(&id3v2)));
+        let audio = read_dsf(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(audio.duration, 3);
+        assert_eq!(audio.sample_rate, Some(SAMPLE_RATE));
+        assert_eq!(audio.channels, Some(2));
+        assert_eq!(audio.bit_depth, Some(1));
+        assert_eq!(audio.bitrate, Some(5644));
+        assert_eq!(audio.title, "Title");
+        assert_eq!(audio.artist, "Artist");
+    }
+
+    #[test]
+    fn dsf_without_metadata() {
+        let path = _fixture("untagged.dsf", &_dsf_fixture(1, None));
+        let audio = read_dsf(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(audio.duration, 1);
+        assert_eq!(audio.artist, "UNKNOWN");
+
+        let mut data = _dsf_fixture(1, None);
+        data[28..32].copy_from_slice(b"fmtx");
+        let path = _fixture("bad.dsf", &data);
+        assert!(read_dsf(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    /// 大小为奇数时补上 1 字节的填充
+    fn _dff_chunk(id: &[u8; 4], data: &[u8]) -> Vec<u8> {
+        let mut chunk = id.to_vec();
+        chunk.extend_from_slice(&(data.len() as u64).to_be_bytes());
+        chunk.extend_from_slice(data);
+        if data.len() % 2 == 1 {
+            chunk.push(0);
+        }
+        chunk
+    }
+
+    fn _dff_text(text: &str) -> Vec<u8> {
+        [&(text.len() as u32).to_be_bytes(), text.as_bytes()].concat()
+    }
+
+    fn _dff_fixture(sound: Vec<u8>, extra: Vec<u8>) -> Vec<u8> {
+        let channels = [&2u16.to_be_bytes()[..], b"SLFT", b"SRGT"].concat();
+        let prop = [
+            b"SND ".to_vec(),
+            _dff_chunk(b"FS  ", &SAMPLE_RATE.to_be_bytes()),
+            _dff_chunk(b"CHNL", &channels),
+            _dff_chunk(b"CMPR", b"DSD \x0Enot compressed\x00"),
+        ]
+        .concat();
+        let diin = [
+            _dff_chunk(b"DIAR", &_dff_text("Artist")),
+            _dff_chunk(b"DITI", &_dff_text("Title")),
+        ]
+        .concat();
+
+        let body = [
+            b"DSD ".to_vec(),
+            _dff_chunk(b"FVER", &0x01050000u32.to_be_bytes()),
+            _dff_chunk(b"PROP", &prop),
+            _dff_chunk(b"DIIN", &diin),
+            extra,
+            sound,
+        ]
+        .concat();
+        [
+            b"FRM8".as_slice(),
+            &(body.len() as u64).to_be_bytes(),
+            &body,
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn dff_prop_and_diin_chunks() {
+        // 1 秒的立体声 DSD64：每个字节是一个声道的 8 个采样
+        let sound = _dff_chunk(b"DSD ", &vec![0x69; SAMPLE_RATE as usize * 2 / 8]);
+        let path = _fixture("raw.dff", &_dff_fixture(sound, vec![]));
+        let audio = read_dff(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(audio.duration, 1);
+        assert_eq!(audio.sample_rate, Some(SAMPLE_RATE));
+        assert_eq!(audio.channels, Some(2));
+        assert_eq!(audio.bitrate, Some(5644));
+        assert_eq!(audio.artist, "Artist");
+        assert_eq!(audio.title, "Title");
+    }
+
+    #[test]
+    fn dff_dst_frames_and_id3_chunk() {
+        // 75 帧/秒，225 帧
+        let frte = [
+            b"FRTE".as_slice(),
+            &6u64.to_be_bytes(),
+            &225u32.to_be_bytes(),
+            &75u16.to_be_bytes(),
+        ]
+        .concat();
+        let sound = _dff_chunk(b"DST ", &frte);
+        let id3 = _dff_chunk(b"ID3 ", &_id3v23(&[(b"TIT2", "ID3 Title")]));
+        let data = _dff_fixture(sound, id3);
+        let file_size = data.len() as f64;
+        let path = _fixture("dst.dff", &data);
+        let audio = read_dff(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(audio.duration, 3);
+        assert_eq!(
+            audio.bitrate,
+            Some((file_size * 8.0 / 3.0 / 1000.0).round() as u32)
+        );
+        // ID3v2 优先，没有的字段保留 DIIN 中的值
+        assert_eq!(audio.title, "ID3 Title");
+        assert_eq!(audio.artist, "Artist");
+    }
+
+    #[test]
+    fn dff_requires_prop() {
+        let body = [b"DSD ".to_vec(), _dff_chunk(b"DSD ", &[0; 16])].concat();
+        let data = [
+            b"FRM8".as_slice(),
+            &(body.len() as u64).to_be_bytes(),
+            &body,
+        ]
+        .concat();
+        let path = _fixture("no_prop.dff", &data);
+        assert!(read_dff(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn dff_sub_chunks_skip_padding() {
+        let data = [_dff_chunk(b"ABCD", b"odd"), _dff_chunk(b"EFGH", b"even")].concat();
+        assert_eq!(
+            _dff_sub_chunks(&data),
+            vec![
+                (b"ABCD".as_slice(), b"odd".as_slice()),
+                (b"EFGH".as_slice(), b"even".as_slice())
+            ]
+        );
+
+        // 大小超过剩余的数据时停止
+        let mut data = _dff_chunk(b"ABCD", b"data");
+        data[4..12].copy_from_slice(&u64::MAX.to_be_bytes());
+        assert!(_dff_sub_chunks(&data).is_empty());
+    }
+}
@@ -0,0 +1,679 @@
+// Lofty 不支持的格式（SUPPORT_FORMAT 中为 false 的格式）的后备读取方式，不依赖 Windows Api。
+// MP1、MP2 等 symphonia 能解析的容器通过 symphonia 读取，
+// ASF（WMA）、AC3、AMR 只读取文件头，使用简单的解析器，DSF 和 DSDIFF 见 dsd_reader，MIDI 见 midi_reader。
+
+use std::{
+    fs::{self, File},
+    io::Read,
+    path::Path,
+};
+
+use anyhow::{anyhow, bail, Context};
+use symphonia::core::{
+    codecs::CODEC_TYPE_NULL,
+    formats::FormatOptions,
+    io::MediaSourceStream,
+    meta::{MetadataOptions, MetadataRevision, StandardTagKey},
+    probe::Hint,
+};
+
+use super::{
+    dsd_reader::{read_dff, read_dsf},
+    midi_reader::read_midi,
+    tag_reader::Audio,
+};
+
+/// 使用后备方式读取 path 的时长、采样率、码率和标签。返回的音乐没有 size 和 id
+pub(crate) fn read_by_fallback(path: &Path, modified: u64, created: u64) -> anyhow::Result<Audio> {
+    let extension = path
+        .extension()
+        .map(|item| item.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    let mut audio = match extension.as_str() {
+        "asf" | "wma" => _read_asf(path)?,
+        "ac3" => _read_ac3(path)?,
+        "amr" => _read_amr(path)?,
+        "dsf" => read_dsf(path)?,
+        "dff" => read_dff(path)?,
+        "mid" => read_midi(path)?,
+        _ => _read_by_symphonia(path)?,
+    };
+
+    audio.modified = modified;
+    audio.created = created;
+    Ok(audio)
+}
+
+fn _new_audio(path: &Path, by: &str) -> anyhow::Result<Audio> {
+    Audio::new_with_path(path, Some(by.to_string())).ok_or(anyhow!("文件名不正确: {:?}", path))
+}
+
+/// 通过文件大小和时长计算平均码率，in kbps
+fn _average_bitrate(size: u64, seconds: f64) -> Option<u32> {
+    if seconds <= 0.0 {
+        return None;
+    }
+    Some((size as f64 * 8.0 / seconds / 1000.0).round() as u32)
+}
+
+fn _read_by_symphonia(path: &Path) -> anyhow::Result<Audio> {
+    let file = File::open(path)?;
+    let file_size = file.metadata()?.len();
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+    let mut hint = Hint::new();
+    if let Some(extension) = path.extension().and_then(|item| item.to_str()) {
+        hint.with_extension(extension);
+    }
+
+    let mut probed = symphonia::default::get_probe()
+        .format(
+            &hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .with_context(|| format!("不支持的格式: {:?}", path))?;
+
+    let mut audio = _new_audio(path, "Symphonia")?;
+
+    // 容器之前的标签（例如 ID3v2）在 probe 时读取，容器内的标签由 format 读取，后者优先
+    if let Some(metadata) = probed.metadata.get() {
+        if let Some(revision) = metadata.current() {
+            _apply_symphonia_tags(&mut audio, revision);
+        }
+    }
+    if let Some(revision) = probed.format.metadata().current() {
+        _apply_symphonia_tags(&mut audio, revision);
+    }
+
+    let track = probed
+        .format
+        .tracks()
+        .iter()
+        .find(|item| item.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or(anyhow!("没有音轨: {:?}", path))?;
+    let track_id = track.id;
+    let params = track.codec_params.clone();
+
+    audio.sample_rate = params.sample_rate;
+    audio.channels = params.channels.map(|item| item.count() as u8);
+    audio.bit_depth = params.bits_per_sample.map(|item| item as u8);
+
+    // 没有总帧数时（例如没有 Xing 头的 MP2）遍历所有数据包累加时长，不需要解码
+    let frames = match params.n_frames {
+        Some(value) => value,
+        None => {
+            let mut frames: u64 = 0;
+            while let Ok(packet) = probed.format.next_packet() {
+                if packet.track_id() == track_id {
+                    frames += packet.dur;
+                }
+            }
+            frames
+        }
+    };
+    let seconds = match (params.time_base, params.sample_rate) {
+        (Some(time_base), _) => {
+            let time = time_base.calc_time(frames);
+            time.seconds as f64 + time.frac
+        }
+        (None, Some(sample_rate)) => frames as f64 / sample_rate as f64,
+        (None, None) => 0.0,
+    };
+    audio.duration = seconds as u64;
+    audio.bitrate = _average_bitrate(file_size, seconds);
+
+    Ok(audio)
+}
+
+fn _apply_symphonia_tags(audio: &mut Audio, revision: &MetadataRevision) {
+    for tag in revision.tags() {
+        if let Some(key) = tag.std_key {
+            _apply_tag(audio, key, tag.value.to_string());
+        }
+    }
+}
+
+/// 把各种格式的标签统一为 symphonia 的 [StandardTagKey] 后写入 audio，空的值会被忽略
+fn _apply_tag(audio: &mut Audio, key: StandardTagKey, value: String) {
+    let value = value.trim_matches(|item: char| item.is_whitespace() || item == '\0');
+    if value.is_empty() {
+        return;
+    }
+    let text = Some(value.to_string());
+
+    match key {
+        StandardTagKey::TrackTitle => audio.title = value.to_string(),
+        StandardTagKey::Artist => audio.artist = value.to_string(),
+        StandardTagKey::Album => audio.album = value.to_string(),
+        StandardTagKey::AlbumArtist => audio.album_artist = text,
+        StandardTagKey::TrackNumber => {
+            let (number, total) = _parse_number_pair(value);
+            audio.track = number.or(audio.track);
+            audio.track_total = total.or(audio.track_total);
+        }
+        StandardTagKey::TrackTotal => audio.track_total = value.parse().ok(),
+        StandardTagKey::DiscNumber => {
+            let (number, total) = _parse_number_pair(value);
+            audio.disc = number.or(audio.disc);
+            audio.disc_total = total.or(audio.disc_total);
+        }
+        StandardTagKey::DiscTotal => audio.disc_total = value.parse().ok(),
+        StandardTagKey::Date | StandardTagKey::ReleaseDate => {
+            audio.year = value.get(..4).and_then(|item| item.parse().ok());
+            // 只有年份时不需要 date
+            if value.len() > 4 {
+                audio.date = text;
+            }
+        }
+        StandardTagKey::Genre => audio.genre = text,
+        StandardTagKey::Composer => audio.composer = text,
+        StandardTagKey::Comment => audio.comment = text,
+        _ => {}
+    }
+}
+
+/// "3/12" 或 "3"
+fn _parse_number_pair(value: &str) -> (Option<u32>, Option<u32>) {
+    let mut parts = value.split('/').map(|item| item.trim().parse::<u32>().ok());
+    (parts.next().flatten(), parts.next().flatten())
+}
+
+fn _u16_at(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn _u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn _u64_at(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(
+        data.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+/// UTF-16LE，去掉结尾的 \0
+fn _utf16_at(data: &[u8], offset: usize, len: usize) -> Option<String> {
+    let units: Vec<u16> = data
+        .get(offset..offset + len)?
+        .chunks_exact(2)
+        .map(|item| u16::from_le_bytes([item[0], item[1]]))
+        .collect();
+    Some(
+        String::from_utf16_lossy(&units)
+            .trim_end_matches('\0')
+            .to_string(),
+    )
+}
+
+// ASF 的 GUID 按照它在文件中的字节顺序（前三段为小端序）
+const ASF_HEADER_OBJECT: [u8; 16] = [
+    0x30, 0x26, 0xB2, 0x75, 0x8E, 0x66, 0xCF, 0x11, 0xA6, 0xD9, 0x00, 0xAA, 0x00, 0x62, 0xCE, 0x6C,
+];
+const ASF_FILE_PROPERTIES_OBJECT: [u8; 16] = [
+    0xA1, 0xDC, 0xAB, 0x8C, 0x47, 0xA9, 0xCF, 0x11, 0x8E, 0xE4, 0x00, 0xC0, 0x0C, 0x20, 0x53, 0x65,
+];
+const ASF_STREAM_PROPERTIES_OBJECT: [u8; 16] = [
+    0x91, 0x07, 0xDC, 0xB7, 0xB7, 0xA9, 0xCF, 0x11, 0x8E, 0xE6, 0x00, 0xC0, 0x0C, 0x20, 0x53, 0x65,
+];
+const ASF_CONTENT_DESCRIPTION_OBJECT: [u8; 16] = [
+    0x33, 0x26, 0xB2, 0x75, 0x8E, 0x66, 0xCF, 0x11, 0xA6, 0xD9, 0x00, 0xAA, 0x00, 0x62, 0xCE, 0x6C,
+];
+const ASF_EXTENDED_CONTENT_DESCRIPTION_OBJECT: [u8; 16] = [
+    0x40, 0xA4, 0xD0, 0xD2, 0x07, 0xE3, 0xD2, 0x11, 0x97, 0xF0, 0x00, 0xA0, 0xC9, 0x5E, 0xA8, 0x50,
+];
+const ASF_AUDIO_MEDIA: [u8; 16] = [
+    0x40, 0x9E, 0x69, 0xF8, 0x4D, 0x5B, 0xCF, 0x11, 0xA8, 0xFD, 0x00, 0x80, 0x5F, 0x5C, 0x44, 0x2B,
+];
+
+/// 头对象包含所有元数据，通常只有几十 KB。超过它时视为损坏的文件
+const MAX_ASF_HEADER_SIZE: u64 = 16 * 1024 * 1024;
+
+/// 读取 ASF 头对象中的 File Properties、Stream Properties、
+/// Content Description 和 Extended Content Description
+fn _read_asf(path: &Path) -> anyhow::Result<Audio> {
+    let mut file = File::open(path)?;
+    let file_size = file.metadata()?.len();
+
+    // GUID、大小、子对象数量、保留的 2 字节
+    let mut header = [0u8; 30];
+    file.read_exact(&mut header)?;
+    if header[..16] != ASF_HEADER_OBJECT {
+        bail!("不是 ASF 文件: {:?}", path);
+    }
+    let header_size = _u64_at(&header, 16).unwrap_or(0);
+    if !(30..=MAX_ASF_HEADER_SIZE).contains(&header_size) {
+        bail!("ASF 头对象的大小不正确: {:?}", path);
+    }
+    let mut objects = vec![0u8; header_size as usize - 30];
+    file.read_exact(&mut objects)?;
+
+    let mut audio = _new_audio(path, "ASF")?;
+    let mut seconds = 0.0;
+    let mut average_bitrate: Option<u32> = None;
+
+    let mut offset = 0;
+    while let (Some(guid), Some(size)) = (
+        objects.get(offset..offset + 16),
+        _u64_at(&objects, offset + 16),
+    ) {
+        let end = match usize::try_from(size) {
+            Ok(size) if size >= 24 && size <= objects.len() - offset => offset + size,
+            _ => break,
+        };
+        let data = &objects[offset + 24..end];
+
+        if guid == ASF_FILE_PROPERTIES_OBJECT {
+            // 播放时长以 100 ns 为单位，包括以 ms 为单位的预卷
+            if let (Some(play_duration), Some(preroll)) = (_u64_at(data, 40), _u64_at(data, 56)) {
+                seconds = (play_duration as f64 / 10_000_000.0 - preroll as f64 / 1000.0).max(0.0);
+            }
+        } else if guid == ASF_STREAM_PROPERTIES_OBJECT
+            && data.get(..16) == Some(&ASF_AUDIO_MEDIA[..])
+        {
+            // 类型相关的数据从 54 开始，音频流为 WAVEFORMATEX
+            audio.channels = _u16_at(data, 56).map(|item| item as u8);
+            audio.sample_rate = _u32_at(data, 58);
+            average_bitrate = _u32_at(data, 62).map(|item| (item as u64 * 8 / 1000) as u32);
+            audio.bit_depth = _u16_at(data, 68)
+                .filter(|item| *item > 0)
+                .map(|item| item as u8);
+        } else if guid == ASF_CONTENT_DESCRIPTION_OBJECT {
+            _read_asf_content_description(&mut audio, data);
+        } else if guid == ASF_EXTENDED_CONTENT_DESCRIPTION_OBJECT {
+            _read_asf_extended_content_description(&mut audio, data);
+        }
+
+        offset = end;
+    }
+
+    audio.duration = seconds as u64;
+    audio.bitrate = average_bitrate
+        .filter(|item| *item > 0)
+        .or_else(|| _average_bitrate(file_size, seconds));
+    Ok(audio)
+}
+
+/// 五个 UTF-16LE 字符串的长度，之后依次是 标题、作者、版权、描述、评级
+fn _read_asf_content_description(audio: &mut Audio, data: &[u8]) {
+    let keys = [
+        Some(StandardTagKey::TrackTitle),
+        Some(StandardTagKey::Artist),
+        None,
+        Some(StandardTagKey::Comment),
+        None,
+    ];
+
+    let mut offset = 10;
+    for (index, key) in keys.into_iter().enumerate() {
+        let len = match _u16_at(data, index * 2) {
+            Some(value) => value as usize,
+            None => return,
+        };
+        if let (Some(key), Some(value)) = (key, _utf16_at(data, offset, len)) {
+            _apply_tag(audio, key, value);
+        }
+        offset += len;
+    }
+}
+
+/// 属性数量，之后每个属性为 名字长度、名字、值的类型、值的长度、值
+fn _read_asf_extended_content_description(audio: &mut Audio, data: &[u8]) {
+    let count = match _u16_at(data, 0) {
+        Some(value) => value,
+        None => return,
+    };
+
+    let mut offset = 2;
+    for _ in 0..count {
+        let name_len = match _u16_at(data, offset) {
+            Some(value) => value as usize,
+            None => return,
+        };
+        let name = _utf16_at(data, offset + 2, name_len).unwrap_or_default();
+        offset += 2 + name_len;
+
+        let (value_type, value_len) = match (_u16_at(data, offset), _u16_at(data, offset + 2)) {
+            (Some(value_type), Some(value_len)) => (value_type, value_len as usize),
+            _ => return,
+        };
+        offset += 4;
+        let value = match value_type {
+            0 => _utf16_at(data, offset, value_len),
+            3 => _u32_at(data, offset).map(|item| item.to_string()),
+            4 => _u64_at(data, offset).map(|item| item.to_string()),
+            5 => _u16_at(data, offset).map(|item| item.to_string()),
+            // 字节数组和 BOOL
+            _ => None,
+        };
+        offset += value_len;
+
+        let value = match value {
+            Some(value) => value,
+            None => continue,
+        };
+        let key = match name.as_str() {
+            "WM/AlbumTitle" => StandardTagKey::Album,
+            "WM/AlbumArtist" => StandardTagKey::AlbumArtist,
+            "WM/TrackNumber" => StandardTagKey::TrackNumber,
+            // 旧的 WM/Track 从 0 开始，只在没有 WM/TrackNumber 时使用
+            "WM/Track" => {
+                if audio.track.is_none() {
+                    audio.track = value.trim().parse::<u32>().ok().map(|item| item + 1);
+                }
+                continue;
+            }
+            "WM/PartOfSet" => StandardTagKey::DiscNumber,
+            "WM/Year" => StandardTagKey::Date,
+            "WM/Genre" => StandardTagKey::Genre,
+            "WM/Composer" => StandardTagKey::Composer,
+            _ => continue,
+        };
+        _apply_tag(audio, key, value);
+    }
+}
+
+const AC3_SAMPLE_RATES: [u32; 3] = [48000, 44100, 32000];
+
+/// in kbps，以 frmsizecod / 2 为下标
+const AC3_BITRATES: [u32; 19] = [
+    32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320, 384, 448, 512, 576, 640,
+];
+
+/// 以 acmod 为下标，不包括 LFE
+const AC3_CHANNELS: [u8; 8] = [2, 1, 2, 3, 3, 4, 4, 5];
+
+/// 读取第一个同步帧的帧头。AC3 是固定码率的，时长由文件大小和码率计算
+fn _read_ac3(path: &Path) -> anyhow::Result<Audio> {
+    let file = File::open(path)?;
+    let file_size = file.metadata()?.len();
+
+    let mut buffer = vec![];
+    file.take(64 * 1024).read_to_end(&mut buffer)?;
+    let start = buffer
+        .windows(2)
+        .position(|item| item == [0x0B, 0x77])
+        .ok_or(anyhow!("不是 AC3 文件: {:?}", path))?;
+    let header = buffer
+        .get(start..start + 8)
+        .ok_or(anyhow!("AC3 帧头不完整: {:?}", path))?;
+
+    let bsid = header[5] >> 3;
+    if bsid > 10 {
+        bail!("不支持 E-AC3: {:?}", path);
+    }
+    let sample_rate = *AC3_SAMPLE_RATES
+        .get((header[4] >> 6) as usize)
+        .ok_or(anyhow!("AC3 采样率不正确: {:?}", path))?;
+    let bitrate = *AC3_BITRATES
+        .get(((header[4] & 0x3F) >> 1) as usize)
+        .ok_or(anyhow!("AC3 码率不正确: {:?}", path))?;
+
+    // acmod 之后的 cmixlev、surmixlev、dsurmod 是否存在由 acmod 决定，之后是 lfeon
+    let acmod = header[6] >> 5;
+    let bits = u16::from_be_bytes([header[6], header[7]]);
+    let mut position = 3;
+    if acmod & 1 != 0 && acmod != 1 {
+        position += 2;
+    }
+    if acmod & 4 != 0 {
+        position += 2;
+    }
+    if acmod == 2 {
+        position += 2;
+    }
+    let lfe = ((bits >> (15 - position)) & 1) as u8;
+
+    let seconds = (file_size - start as u64) as f64 * 8.0 / (bitrate as f64 * 1000.0);
+
+    let mut audio = _new_audio(path, "AC3")?;
+    audio.duration = seconds as u64;
+    audio.bitrate = Some(bitrate);
+    audio.sample_rate = Some(sample_rate);
+    audio.channels = Some(AC3_CHANNELS[acmod as usize] + lfe);
+    Ok(audio)
+}
+
+/// AMR-NB 每种帧类型的数据长度（不包括 1 字节的帧头），以帧类型为下标
+const AMR_NB_FRAME_SIZES: [usize; 16] = [12, 13, 15, 17, 19, 20, 26, 31, 5, 0, 0, 0, 0, 0, 0, 0];
+
+/// AMR-WB 每种帧类型的数据长度
+const AMR_WB_FRAME_SIZES: [usize; 16] = [17, 23, 32, 36, 40, 46, 50, 58, 60, 5, 0, 0, 0, 0, 0, 0];
+
+/// 每一帧 20 ms
+const AMR_FRAME_SECONDS: f64 = 0.02;
+
+/// 单声道的 AMR-NB 和 AMR-WB。遍历所有帧头计算时长，AMR 文件很小（12.2 kbps 一小时约 5.5 MB）
+fn _read_amr(path: &Path) -> anyhow::Result<Audio> {
+    let data = fs::read(path)?;
+    let (mut offset, sample_rate, frame_sizes) = if data.starts_with(b"#!AMR-WB\n") {
+        (9, 16000, &AMR_WB_FRAME_SIZES)
+    } else if data.starts_with(b"#!AMR\n") {
+        (6, 8000, &AMR_NB_FRAME_SIZES)
+    } else {
+        bail!("不是单声道的 AMR 文件: {:?}", path);
+    };
+
+    let mut frames: u64 = 0;
+    while offset < data.len() {
+        let frame_type = ((data[offset] >> 3) & 0x0F) as usize;
+        offset += 1 + frame_sizes[frame_type];
+        frames += 1;
+    }
+    let seconds = frames as f64 * AMR_FRAME_SECONDS;
+
+    let mut audio = _new_audio(path, "AMR")?;
+    audio.duration = seconds as u64;
+    audio.bitrate = _average_bitrate(data.len() as u64, seconds);
+    audio.sample_rate = Some(sample_rate);
+    audio.channels = Some(1);
+    Ok(audio)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    /// 把 data 写入临时文件夹中的 name，返回它的路径
+    fn _fixture(name: &str, data: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "coriander_fallback_{}_{}",
+            std::process::id(),
+            name
+        ));
+        fs::write(&path, data).unwrap();
+        path
+    }
+
+    /// UTF-16LE，带结尾的 \0
+    fn _utf16(value: &str) -> Vec<u8> {
+        value
+            .encode_utf16()
+            .chain(std::iter::once(0))
+            .flat_map(|item| item.to_le_bytes())
+            .collect()
+    }
+
+    fn _asf_object(guid: &[u8; 16], data: &[u8]) -> Vec<u8> {
+        let mut object = guid.to_vec();
+        object.extend_from_slice(&(24 + data.len() as u64).to_le_bytes());
+        object.extend_from_slice(data);
+        object
+    }
+
+    fn _asf_fixture() -> Vec<u8> {
+        // 播放时长 183 s，包括 3000 ms 的预卷
+        let mut file_properties = vec![0u8; 80];
+        file_properties[40..48].copy_from_slice(&1_830_000_000u64.to_le_bytes());
+        file_properties[56..64].copy_from_slice(&3000u64.to_le_bytes());
+
+        // 双声道，44100 Hz，16000 字节/秒，16 bit
+        let mut stream_properties = vec![0u8; 72];
+        stream_properties[..16].copy_from_slice(&ASF_AUDIO_MEDIA);
+        stream_properties[56..58].copy_from_slice(&2u16.to_le_bytes());
+        stream_properties[58..62].copy_from_slice(&44100u32.to_le_bytes());
+        stream_properties[62..66].copy_from_slice(&16000u32.to_le_bytes());
+        stream_properties[68..70].copy_from_slice(&16u16.to_le_bytes());
+
+        let strings = [
+            _utf16("Title"),
+            _utf16("Artist"),
+            vec![],
+            _utf16("Note"),
+            vec![],
+        ];
+        let mut content_description = vec![];
+        for item in &strings {
+            content_description.extend_from_slice(&(item.len() as u16).to_le_bytes());
+        }
+        for item in &strings {
+            content_description.extend_from_slice(item);
+        }
+
+        let mut extended_content_description = 4u16.to_le_bytes().to_vec();
+        let mut push_attribute = |name: &str, value_type: u16, value: &[u8]| {
+            let name = _utf16(name);
+            extended_content_description.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            extended_content_description.extend_from_slice(&name);
+            extended_content_description.extend_from_slice(&value_type.to_le_bytes());
+            extended_content_description.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            extended_content_description.extend_from_slice(value);
+        };
+        push_attribute("WM/AlbumTitle", 0, &_utf16("Album"));
+        push_attribute("WM/Track", 3, &4u32.to_le_bytes());
+        push_attribute("WM/PartOfSet", 0, &_utf16("1/2"));
+        push_attribute("WM/Year", 0, &_utf16("2003"));
+
+        let objects = [
+            _asf_object(&ASF_FILE_PROPERTIES_OBJECT, &file_properties),
+            _asf_object(&ASF_STREAM_PROPERTIES_OBJECT, &stream_properties),
+            _asf_object(&ASF_CONTENT_DESCRIPTION_OBJECT, &content_description),
+            _asf_object(
+                &ASF_EXTENDED_CONTENT_DESCRIPTION_OBJECT,
+                &extended_content_description,
+            ),
+        ]
+        .concat();
+
+        let mut data = ASF_HEADER_OBJECT.to_vec();
+        data.extend_from_slice(&(30 + objects.len() as u64).to_le_bytes());
+        data.extend_from_slice(&4u32.to_le_bytes());
+        data.extend_from_slice(&[0x01, 0x02]);
+        data.extend_from_slice(&objects);
+        data
+    }
+
+    #[test]
+    fn asf_header_objects() {
+        let path = _fixture("header.wma", &_asf_fixture());
+        let audio = _read_asf(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(audio.duration, 180);
+        assert_eq!(audio.channels, Some(2));
+        assert_eq!(audio.sample_rate, Some(44100));
+        assert_eq!(audio.bitrate, Some(128));
+        assert_eq!(audio.bit_depth, Some(16));
+        assert_eq!(audio.title, "Title");
+        assert_eq!(audio.artist, "Artist");
+        assert_eq!(audio.comment.as_deref(), Some("Note"));
+        assert_eq!(audio.album, "Album");
+        // WM/Track 从 0 开始
+        assert_eq!(audio.track, Some(5));
+        assert_eq!((audio.disc, audio.disc_total), (Some(1), Some(2)));
+        assert_eq!(audio.year, Some(2003));
+        assert_eq!(audio.date, None);
+    }
+
+    #[test]
+    fn asf_rejects_bad_header() {
+        let mut data = _asf_fixture();
+        data[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+        let path = _fixture("bad_size.wma", &data);
+        assert!(_read_asf(&path).is_err());
+        fs::remove_file(&path).unwrap();
+
+        let path = _fixture("not_asf.wma", &[0u8; 64]);
+        assert!(_read_asf(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    /// 48 kHz、192 kbps 的 AC3 帧头，之后用 0 填充到 seconds 秒
+    fn _ac3_fixture(bsid: u8, acmod_byte: u8, seconds: usize) -> Vec<u8> {
+        // 同步帧之前的垃圾数据
+        let mut data = vec![0x00, 0x00];
+        data.extend_from_slice(&[0x0B, 0x77, 0x00, 0x00, 0x14, bsid << 3, acmod_byte, 0x00]);
+        data.resize(2 + 192 * 1000 / 8 * seconds, 0);
+        data
+    }
+
+    #[test]
+    fn ac3_frame_header() {
+        // acmod 7（3/2），cmixlev、surmixlev，lfeon 为 1
+        let path = _fixture("surround.ac3", &_ac3_fixture(8, 0b1110_0001, 2));
+        let audio = _read_ac3(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(audio.duration, 2);
+        assert_eq!(audio.bitrate, Some(192));
+        assert_eq!(audio.sample_rate, Some(48000));
+        assert_eq!(audio.channels, Some(6));
+
+        // acmod 2（立体声），dsurmod，lfeon 为 1
+        let path = _fixture("stereo.ac3", &_ac3_fixture(8, 0b0100_0100, 1));
+        let audio = _read_ac3(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(audio.duration, 1);
+        assert_eq!(audio.channels, Some(3));
+    }
+
+    #[test]
+    fn ac3_rejects_eac3_and_missing_sync() {
+        let path = _fixture("eac3.ac3", &_ac3_fixture(16, 0, 1));
+        assert!(_read_ac3(&path).is_err());
+        fs::remove_file(&path).unwrap();
+
+        let path = _fixture("no_sync.ac3", &[0u8; 1024]);
+        assert!(_read_ac3(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn amr_frames() {
+        // 100 个 12.2 kbps 的帧（帧类型 7，31 字节）
+        let mut data = b"#!AMR\n".to_vec();
+        for _ in 0..100 {
+            data.push(7 << 3 | 0x04);
+            data.extend_from_slice(&[0u8; 31]);
+        }
+        let path = _fixture("nb.amr", &data);
+        let audio = _read_amr(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(audio.duration, 2);
+        assert_eq!(audio.sample_rate, Some(8000));
+        assert_eq!(audio.channels, Some(1));
+        assert_eq!(audio.bitrate, Some(13));
+
+        // 50 个 23.85 kbps 的帧（帧类型 8，60 字节）
+        let mut data = b"#!AMR-WB\n".to_vec();
+        for _ in 0..50 {
+            data.push(8 << 3 | 0x04);
+            data.extend_from_slice(&[0u8; 60]);
+        }
+        let path = _fixture("wb.amr", &data);
+        let audio = _read_amr(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(audio.duration, 1);
+        assert_eq!(audio.sample_rate, Some(16000));
+
+        let path = _fixture("mc.amr", b"#!AMR_MC1.0\n\x00\x00\x00\x02");
+        assert!(_read_amr(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,86 @@
+// 文件和文件夹的身份。通过符号链接、挂载点或者不同的写法访问到的同一个文件，身份相同。
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+/// Windows: (卷序列号, 文件 ID)；其他平台: (设备号, inode)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct FileIdentity {
+    device: u64,
+    index: u64,
+}
+
+impl FileIdentity {
+    /// 读取 path 指向的文件或文件夹的身份，会跟随符号链接
+    #[cfg(windows)]
+    pub(crate) fn of(path: &Path) -> io::Result<Self> {
+        use std::{
+            fs::OpenOptions,
+            os::windows::{fs::OpenOptionsExt, io::AsRawHandle},
+        };
+        use windows::Win32::{
+            Foundation::HANDLE,
+            Storage::FileSystem::{
+                GetFileInformationByHandle, BY_HANDLE_FILE_INFORMATION, FILE_FLAG_BACKUP_SEMANTICS,
+            },
+        };
+
+        // 打开文件夹需要 FILE_FLAG_BACKUP_SEMANTICS；只读取属性，不需要任何访问权限
+        let file = OpenOptions::new()
+            .access_mode(0)
+            .custom_flags(FILE_FLAG_BACKUP_SEMANTICS.0)
+            .open(path)?;
+
+        let mut info = BY_HANDLE_FILE_INFORMATION::default();
+        unsafe { GetFileInformationByHandle(HANDLE(file.as_raw_handle() as isize), &mut info) }
+            .map_err(io::Error::other)?;
+
+        Ok(FileIdentity {
+            device: info.dwVolumeSerialNumber as u64,
+            index: (info.nFileIndexHigh as u64) << 32 | info.nFileIndexLow as u64,
+        })
+    }
+
+    /// 读取 path 指向的文件或文件夹的身份，会跟随符号链接
+    #[cfg(unix)]
+    pub(crate) fn of(path: &Path) -> io::Result<Self> {
+        use std::os::unix::fs::MetadataExt;
+
+        let metadata = std::fs::metadata(path)?;
+        Ok(FileIdentity {
+            device: metadata.dev(),
+            index: metadata.ino(),
+        })
+    }
+}
+
+impl FileIdentity {
+    /// 用于哈希等需要稳定字节表示的地方
+    pub(crate) fn to_le_bytes(self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&self.device.to_le_bytes());
+        bytes[8..].copy_from_slice(&self.index.to_le_bytes());
+        bytes
+    }
+}
+
+/// 解析所有符号链接，得到 path 的绝对路径。
+/// Windows 上会去掉 `\\?\` 前缀，保证和其他地方得到的路径写法一致
+pub(crate) fn canonical_path(path: &Path) -> io::Result<PathBuf> {
+    let canonical = path.canonicalize()?;
+
+    #[cfg(windows)]
+    {
+        let canonical_str = canonical.to_string_lossy();
+        if let Some(unc) = canonical_str.strip_prefix(r"\\?\UNC\") {
+            return Ok(PathBuf::from(format!(r"\\{}", unc)));
+        }
+        if let Some(local) = canonical_str.strip_prefix(r"\\?\") {
+            return Ok(PathBuf::from(local));
+        }
+    }
+
+    Ok(canonical)
+}
@@ -0,0 +1,85 @@
+// 文件夹中的封面图片（cover.jpg、folder.png 等），用于没有内嵌封面的音乐。
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// 默认的封面文件名（不含扩展名），越靠前越优先
+const DEFAULT_FOLDER_COVER_NAMES: [&str; 4] = ["cover", "folder", "front", "albumart"];
+
+const FOLDER_COVER_EXTENSIONS: [&str; 4] = ["jpg", "jpeg", "png", "webp"];
+
+/// 文件夹中没有封面时，再到这些子文件夹中寻找
+const FOLDER_COVER_SUBFOLDERS: [&str; 2] = ["scans", "artwork"];
+
+/// 在 folder 中寻找封面图片，找不到时在 Scans、Artwork 子文件夹中寻找。
+/// names 是封面的文件名（不含扩展名），越靠前越优先，
+/// 为空时使用 [DEFAULT_FOLDER_COVER_NAMES]，见 [super::scan_rules::ScanRules::folder_cover_names]。
+/// 文件名和扩展名都不区分大小写
+pub(crate) fn find_folder_cover(folder: &Path, names: &[String]) -> Option<PathBuf> {
+    let names: Vec<String> = if names.is_empty() {
+        DEFAULT_FOLDER_COVER_NAMES
+            .iter()
+            .map(|item| item.to_string())
+            .collect()
+    } else {
+        names.iter().map(|item| item.to_lowercase()).collect()
+    };
+
+    if let Some(cover) = _find_cover_in(folder, &names) {
+        return Some(cover);
+    }
+
+    let sub_folders: Vec<PathBuf> = fs::read_dir(folder)
+        .ok()?
+        .filter_map(|item| item.ok())
+        .filter(|item| item.file_type().is_ok_and(|file_type| file_type.is_dir()))
+        .map(|item| item.path())
+        .collect();
+    FOLDER_COVER_SUBFOLDERS.iter().find_map(|sub_folder_name| {
+        sub_folders
+            .iter()
+            .filter(|item| {
+                item.file_name()
+                    .is_some_and(|name| name.to_string_lossy().to_lowercase() == *sub_folder_name)
+            })
+            .find_map(|item| _find_cover_in(item, &names))
+    })
+}
+
+fn _find_cover_in(folder: &Path, names: &[String]) -> Option<PathBuf> {
+    let mut candidates: Vec<(usize, usize, PathBuf)> = vec![];
+    for entry in fs::read_dir(folder).ok()?.filter_map(|item| item.ok()) {
+        let path = entry.path();
+        let (stem, extension) = match (path.file_stem(), path.extension()) {
+            (Some(stem), Some(extension)) => (
+                stem.to_string_lossy().to_lowercase(),
+                extension.to_string_lossy().to_lowercase(),
+            ),
+            _ => continue,
+        };
+
+        let name_rank = match names.iter().position(|item| *item == stem) {
+            Some(value) => value,
+            None => continue,
+        };
+        let extension_rank = match FOLDER_COVER_EXTENSIONS
+            .iter()
+            .position(|item| *item == extension)
+        {
+            Some(value) => value,
+            None => continue,
+        };
+        if path.is_file() {
+            candidates.push((name_rank, extension_rank, path));
+        }
+    }
+
+    candidates.into_iter().min().map(|(_, _, path)| path)
+}
+
+/// [find_folder_cover] 的结果转为字符串，用于 [super::tag_reader::AudioFolder::cover]
+pub(crate) fn folder_cover_of(folder: &Path, names: &[String]) -> Option<String> {
+    find_folder_cover(folder, names).map(|item| item.to_string_lossy().to_string())
+}
@@ -0,0 +1,679 @@
+// 可选的 SQLite 索引存储。
+// index_path 下存在 index.db 时，建立/更新索引都会改为读写 index.db，
+// 每个文件夹、每首音乐都可以单独更新和查询，不需要每次都读取整个 index.json。
+// 此时 index.json 不再更新，Flutter 端通过 [get_indexed_folders] 从数据库加载音乐库。
+
+use std::{
+    ops::{Deref, DerefMut},
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard},
+};
+
+use anyhow::Context;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::frb_generated::StreamSink;
+
+use super::{
+    scan_rules::{ScanFilter, ScanRules},
+    tag_reader::{
+        report_index_action_cancelled, Audio, AudioFolder, AudioIndex, IndexAction,
+        IndexActionState, INDEX_VERSION,
+    },
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS meta (
+    key TEXT PRIMARY KEY,
+    value NOT NULL
+);
+CREATE TABLE IF NOT EXISTS folders (
+    path TEXT PRIMARY KEY,
+    modified INTEGER NOT NULL,
+    latest INTEGER NOT NULL,
+    cover TEXT,
+    cover_placeholder TEXT
+);
+CREATE TABLE IF NOT EXISTS audios (
+    path TEXT PRIMARY KEY,
+    folder TEXT NOT NULL REFERENCES folders(path) ON DELETE CASCADE,
+    modified INTEGER NOT NULL,
+    created INTEGER NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS audios_folder ON audios(folder);
+";
+
+/// 打开的 index.db 及其所在的 index_dir，所有 [IndexDatabase] 共用这一个连接。
+/// 只在第一次使用或者切换到另一个 index_dir 时打开数据库
+static CONNECTION: Mutex<Option<(PathBuf, Connection)>> = Mutex::new(None);
+
+/// 持有 [CONNECTION] 期间可以直接当作 [Connection] 使用
+struct ConnectionGuard(MutexGuard<'static, Option<(PathBuf, Connection)>>);
+
+impl Deref for ConnectionGuard {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        &self
+            .0
+            .as_ref()
+            .expect("ConnectionGuard 只在连接打开后创建")
+            .1
+    }
+}
+
+impl DerefMut for ConnectionGuard {
+    fn deref_mut(&mut self) -> &mut Connection {
+        &mut self
+            .0
+            .as_mut()
+            .expect("ConnectionGuard 只在连接打开后创建")
+            .1
+    }
+}
+
+/// index_dir/index.db。每次操作时取得共用的连接，操作之间不会阻塞其他线程读写数据库
+pub(crate) struct IndexDatabase {
+    index_dir: PathBuf,
+}
+
+impl IndexDatabase {
+    /// index_dir/index.db
+    pub(crate) fn path_in(index_dir: &Path) -> PathBuf {
+        index_dir.join("index.db")
+    }
+
+    pub(crate) fn exists_in(index_dir: &Path) -> bool {
+        Self::path_in(index_dir).is_file()
+    }
+
+    /// 打开 index_dir/index.db，不存在时创建
+    pub(crate) fn open_in(index_dir: &Path) -> anyhow::Result<Self> {
+        let db = IndexDatabase {
+            index_dir: index_dir.to_path_buf(),
+        };
+        db.lock()?;
+        Ok(db)
+    }
+
+    /// 取得共用的连接，还没有打开这个数据库时打开它
+    fn lock(&self) -> anyhow::Result<ConnectionGuard> {
+        let mut connection = match CONNECTION.lock() {
+            Ok(val) => val,
+            Err(val) => val.into_inner(),
+        };
+        if !matches!(&*connection, Some((index_dir, _)) if *index_dir == self.index_dir) {
+            // 先关闭原来的连接，打开失败时也不会留下另一个数据库的连接
+            *connection = None;
+            *connection = Some((self.index_dir.clone(), _open(&self.index_dir)?));
+        }
+
+        Ok(ConnectionGuard(connection))
+    }
+
+    /// 和索引保存在一起的扫描规则，以 JSON 的形式保存在 meta 中
+    pub(crate) fn rules(&self) -> anyhow::Result<ScanRules> {
+        self.meta_json("rules")
+    }
+
+    /// 建立索引时是否计算封面占位图，以 JSON 的形式保存在 meta 中
+    pub(crate) fn cover_placeholders(&self) -> anyhow::Result<bool> {
+        self.meta_json("cover_placeholders")
+    }
+
+    /// 按照和索引保存在一起的规则和选项建立 [ScanFilter]
+    pub(crate) fn scan_filter(&self) -> anyhow::Result<ScanFilter> {
+        Ok(ScanFilter::new(self.rules()?)?.with_cover_placeholders(self.cover_placeholders()?))
+    }
+
+    /// 建立索引时传入的音乐库根目录，以 JSON 的形式保存在 meta 中
+    pub(crate) fn roots(&self) -> anyhow::Result<Vec<String>> {
+        self.meta_json("roots")
+    }
+
+    pub(crate) fn set_roots(&self, roots: &[String]) -> anyhow::Result<()> {
+        self.set_meta_json("roots", roots)
+    }
+
+    /// 读取 meta 中以 JSON 保存的值，不存在时返回默认值
+    fn meta_json<T: DeserializeOwned + Default>(&self, key: &str) -> anyhow::Result<T> {
+        _meta_json(&*self.lock()?, key)
+    }
+
+    fn set_meta_json<T: Serialize + ?Sized>(&self, key: &str, value: &T) -> anyhow::Result<()> {
+        _set_meta_json(&*self.lock()?, key, value)
+    }
+
+    /// 所有文件夹的 (path, modified)，按路径排序
+    pub(crate) fn folder_records(&self) -> anyhow::Result<Vec<(String, u64)>> {
+        let conn = self.lock()?;
+        let mut stmt = conn.prepare("SELECT path, modified FROM folders ORDER BY path")?;
+        let records = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(records)
+    }
+
+    pub(crate) fn folder(&self, path: &str) -> anyhow::Result<Option<AudioFolder>> {
+        let conn = self.lock()?;
+        let record: Option<(u64, u64, Option<String>, Option<String>)> = conn
+            .query_row(
+                "SELECT modified, latest, cover, cover_placeholder FROM folders WHERE path = ?1",
+                params![path],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .optional()?;
+        let (modified, latest, cover, cover_placeholder) = match record {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        let mut stmt = conn.prepare("SELECT data FROM audios WHERE folder = ?1 ORDER BY path")?;
+        let mut audios: Vec<Audio> = vec![];
+        for data in stmt.query_map(params![path], |row| row.get::<_, String>(0))? {
+            audios.push(serde_json::from_str(&data?)?);
+        }
+
+        Ok(Some(AudioFolder {
+            path: path.to_string(),
+            modified,
+            latest,
+            audios,
+            cover,
+            cover_placeholder,
+        }))
+    }
+
+    /// 所有文件夹及其音乐，按路径排序。一次查询读取全部记录
+    pub(crate) fn folders(&self) -> anyhow::Result<Vec<AudioFolder>> {
+        let conn = self.lock()?;
+        let mut stmt = conn.prepare(
+            "SELECT folders.path, folders.modified, folders.latest, folders.cover,
+             folders.cover_placeholder, audios.data
+             FROM folders LEFT JOIN audios ON audios.folder = folders.path
+             ORDER BY folders.path, audios.path",
+        )?;
+        let mut rows = stmt.query([])?;
+
+        let mut folders: Vec<AudioFolder> = vec![];
+        while let Some(row) = rows.next()? {
+            let path: String = row.get(0)?;
+            if folders.last().map(|item| &item.path) != Some(&path) {
+                folders.push(AudioFolder {
+                    path,
+                    modified: row.get(1)?,
+                    latest: row.get(2)?,
+                    audios: vec![],
+                    cover: row.get(3)?,
+                    cover_placeholder: row.get(4)?,
+                });
+            }
+
+            // 没有音乐的文件夹只有一行，data 为 NULL
+            if let Some(data) = row.get::<_, Option<String>>(5)? {
+                if let Some(folder) = folders.last_mut() {
+                    folder.audios.push(serde_json::from_str(&data)?);
+                }
+            }
+        }
+
+        Ok(folders)
+    }
+
+    pub(crate) fn audio(&self, path: &str) -> anyhow::Result<Option<Audio>> {
+        let data: Option<String> = self
+            .lock()?
+            .query_row(
+                "SELECT data FROM audios WHERE path = ?1",
+                params![path],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        match data {
+            Some(data) => Ok(Some(serde_json::from_str(&data)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// 把文件夹从 old 更新到 new：只删除消失的音乐、写入新增或者被修改的音乐，
+    /// 再更新文件夹本身的记录
+    pub(crate) fn update_folder(&self, old: &AudioFolder, new: &AudioFolder) -> anyhow::Result<()> {
+        let mut conn = self.lock()?;
+        let tx = conn.transaction()?;
+        for old_audio in &old.audios {
+            if !new.audios.iter().any(|item| item.path == old_audio.path) {
+                tx.execute(
+                    "DELETE FROM audios WHERE path = ?1",
+                    params![old_audio.path],
+                )?;
+            }
+        }
+        for new_audio in &new.audios {
+            if !old.audios.contains(new_audio) {
+                _upsert_audio(&tx, &new.path, new_audio)?;
+            }
+        }
+        tx.execute(
+            "UPDATE folders SET modified = ?2, latest = ?3, cover = ?4, cover_placeholder = ?5
+             WHERE path = ?1",
+            params![
+                new.path,
+                new.modified,
+                new.latest,
+                new.cover,
+                new.cover_placeholder
+            ],
+        )?;
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// 路径为 path 或者位于 path 之下的所有音乐，包括 CUE 分割出的虚拟音轨
+    pub(crate) fn audios_under(&self, path: &str) -> anyhow::Result<Vec<Audio>> {
+        // [prefix, end) 是以 path 加路径分隔符开头的所有路径
+        let prefix = format!("{}{}", path, std::path::MAIN_SEPARATOR);
+        let end = format!("{}{}", path, (std::path::MAIN_SEPARATOR as u8 + 1) as char);
+
+        let conn = self.lock()?;
+        let mut stmt =
+            conn.prepare("SELECT data FROM audios WHERE path = ?1 OR (path >= ?2 AND path < ?3)")?;
+        let mut audios: Vec<Audio> = vec![];
+        for data in stmt.query_map(params![path, prefix, end], |row| row.get::<_, String>(0))? {
+            audios.push(serde_json::from_str(&data?)?);
+        }
+
+        Ok(audios)
+    }
+
+    /// 在一个事务中写入 [super::library_watcher] 监听到的一批变化：
+    /// 删除 removed 中的音乐，再把 upserts 中每个文件夹的音乐添加或替换到这个文件夹下。
+    /// 文件夹不存在时按 upserts 中的记录创建；存在时只更新 latest。
+    /// 最后删除已经没有音乐的文件夹
+    pub(crate) fn apply_audio_changes(
+        &self,
+        upserts: &[AudioFolder],
+        removed: &[String],
+    ) -> anyhow::Result<()> {
+        let mut conn = self.lock()?;
+        let tx = conn.transaction()?;
+        for path in removed {
+            tx.execute("DELETE FROM audios WHERE path = ?1", params![path])?;
+        }
+        for folder in upserts {
+            tx.execute(
+                "INSERT INTO folders (path, modified, latest, cover, cover_placeholder)
+                 VALUES (?1, ?2, ?3, ?4, ?5)
+                 ON CONFLICT(path) DO UPDATE SET latest = MAX(latest, excluded.latest)",
+                params![
+                    folder.path,
+                    folder.modified,
+                    folder.latest,
+                    folder.cover,
+                    folder.cover_placeholder
+                ],
+            )?;
+            for audio in &folder.audios {
+                _upsert_audio(&tx, &folder.path, audio)?;
+            }
+        }
+        tx.execute(
+            "DELETE FROM folders WHERE NOT EXISTS (SELECT 1 FROM audios WHERE audios.folder = folders.path)",
+            [],
+        )?;
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// 删除文件夹及其所有音乐的记录
+    pub(crate) fn remove_folder(&self, path: &str) -> anyhow::Result<()> {
+        self.lock()?
+            .execute("DELETE FROM folders WHERE path = ?1", params![path])?;
+        Ok(())
+    }
+
+    /// 添加一个新的文件夹及其所有音乐
+    pub(crate) fn insert_folder(&self, folder: &AudioFolder) -> anyhow::Result<()> {
+        let mut conn = self.lock()?;
+        let tx = conn.transaction()?;
+        _insert_folder(&tx, folder)?;
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// 用 folders、roots、rules 和 cover_placeholders 替换整个数据库的内容
+    pub(crate) fn replace_all(
+        &self,
+        folders: &[AudioFolder],
+        roots: &[String],
+        rules: &ScanRules,
+        cover_placeholders: bool,
+    ) -> anyhow::Result<()> {
+        let mut conn = self.lock()?;
+        let tx = conn.transaction()?;
+        _set_meta_json(&tx, "roots", roots)?;
+        _set_meta_json(&tx, "rules", rules)?;
+        _set_meta_json(&tx, "cover_placeholders", &cover_placeholders)?;
+        tx.execute("DELETE FROM folders", [])?;
+        for folder in folders {
+            _insert_folder(&tx, folder)?;
+        }
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// 把 index 导入到 index_dir/index.db，index.db 不存在时创建。
+    /// 导入失败时删除新建的 index.db，之后仍然使用 index.json
+    pub(crate) fn import_in(index_dir: &Path, index: &AudioIndex) -> anyhow::Result<()> {
+        let existed = Self::exists_in(index_dir);
+        let result = Self::open_in(index_dir).and_then(|db| {
+            db.replace_all(
+                &index.folders,
+                &index.roots,
+                &index.rules,
+                index.cover_placeholders,
+            )
+        });
+
+        if result.is_err() && !existed {
+            let mut connection = match CONNECTION.lock() {
+                Ok(val) => val,
+                Err(val) => val.into_inner(),
+            };
+            if matches!(&*connection, Some((dir, _)) if dir == index_dir) {
+                *connection = None;
+            }
+
+            let db_path = Self::path_in(index_dir);
+            for path in [
+                db_path.with_extension("db-wal"),
+                db_path.with_extension("db-shm"),
+                db_path,
+            ] {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+
+        result
+    }
+}
+
+/// 打开 index_dir/index.db 并建立表，必要时迁移到 [INDEX_VERSION]
+fn _open(index_dir: &Path) -> anyhow::Result<Connection> {
+    let db_path = IndexDatabase::path_in(index_dir);
+    let mut conn = Connection::open(&db_path).with_context(|| format!("无法打开 {:?}", db_path))?;
+    conn.pragma_update(None, "foreign_keys", "ON")?;
+    conn.pragma_update(None, "journal_mode", "WAL")?;
+    conn.execute_batch(SCHEMA)?;
+    conn.execute(
+        "INSERT OR IGNORE INTO meta (key, value) VALUES ('version', ?1)",
+        params![INDEX_VERSION],
+    )?;
+
+    _migrate(&mut conn)?;
+    Ok(conn)
+}
+
+/// 和 index.json 一样，把数据库中的记录迁移到 [INDEX_VERSION]
+fn _migrate(conn: &mut Connection) -> anyhow::Result<()> {
+    let version: u64 =
+        conn.query_row("SELECT value FROM meta WHERE key = 'version'", [], |row| {
+            row.get(0)
+        })?;
+    if version >= INDEX_VERSION {
+        return Ok(());
+    }
+
+    let filter = ScanFilter::new(_meta_json(conn, "rules")?)?;
+    let tx = conn.transaction()?;
+    if version < 111 {
+        // 111 给每首音乐加上了 size 和 id，给每个文件夹加上了 cover 和 cover_placeholder
+        let mut records: Vec<(String, Audio)> = vec![];
+        {
+            let mut stmt = tx.prepare("SELECT folder, data FROM audios")?;
+            let mut rows = stmt.query([])?;
+            while let Some(row) = rows.next()? {
+                let data: String = row.get(1)?;
+                records.push((row.get(0)?, serde_json::from_str(&data)?));
+            }
+        }
+        for (folder_path, mut audio) in records {
+            audio.fill_identity();
+            _upsert_audio(&tx, &folder_path, &audio)?;
+        }
+
+        tx.execute("ALTER TABLE folders ADD COLUMN cover TEXT", [])?;
+        tx.execute("ALTER TABLE folders ADD COLUMN cover_placeholder TEXT", [])?;
+        let mut folder_paths: Vec<String> = vec![];
+        {
+            let mut stmt = tx.prepare("SELECT path FROM folders")?;
+            for path in stmt.query_map([], |row| row.get::<_, String>(0))? {
+                folder_paths.push(path?);
+            }
+        }
+        for folder_path in folder_paths {
+            tx.execute(
+                "UPDATE folders SET cover = ?2 WHERE path = ?1",
+                params![folder_path, filter.folder_cover_of(Path::new(&folder_path))],
+            )?;
+        }
+    }
+    tx.execute(
+        "UPDATE meta SET value = ?1 WHERE key = 'version'",
+        params![INDEX_VERSION],
+    )?;
+    tx.commit()?;
+
+    Ok(())
+}
+
+fn _meta_json<T: DeserializeOwned + Default>(conn: &Connection, key: &str) -> anyhow::Result<T> {
+    let data: Option<String> = conn
+        .query_row("SELECT value FROM meta WHERE key = ?1", [key], |row| {
+            row.get(0)
+        })
+        .optional()?;
+
+    match data {
+        Some(data) => Ok(serde_json::from_str(&data)?),
+        None => Ok(T::default()),
+    }
+}
+
+fn _set_meta_json<T: Serialize + ?Sized>(
+    conn: &Connection,
+    key: &str,
+    value: &T,
+) -> anyhow::Result<()> {
+    conn.execute(
+        "INSERT INTO meta (key, value) VALUES (?1, ?2)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        params![key, serde_json::to_string(value)?],
+    )?;
+    Ok(())
+}
+
+fn _insert_folder(conn: &Connection, folder: &AudioFolder) -> anyhow::Result<()> {
+    conn.execute(
+        "INSERT INTO folders (path, modified, latest, cover, cover_placeholder)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            folder.path,
+            folder.modified,
+            folder.latest,
+            folder.cover,
+            folder.cover_placeholder
+        ],
+    )?;
+    for audio in &folder.audios {
+        _upsert_audio(conn, &folder.path, audio)?;
+    }
+
+    Ok(())
+}
+
+fn _upsert_audio(conn: &Connection, folder_path: &str, audio: &Audio) -> anyhow::Result<()> {
+    conn.execute(
+        "INSERT INTO audios (path, folder, modified, created, data) VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(path) DO UPDATE SET folder = excluded.folder, modified = excluded.modified,
+         created = excluded.created, data = excluded.data",
+        params![
+            audio.path,
+            folder_path,
+            audio.modified,
+            audio.created,
+            serde_json::to_string(audio)?
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// for Flutter  
+/// 把 index_path/index.json 导入到 index_path/index.db。
+/// 导入后建立/更新索引都会使用 index.db，index.json 保持导入时的内容，不再更新。
+///
+/// 和 [super::tag_reader::update_index] 一样可以通过 [super::tag_reader::cancel_index_action] 取消，
+/// 取消时不会创建 index.db。
+pub fn migrate_index_to_database(
+    index_path: String,
+    sink: StreamSink<IndexActionState>,
+) -> anyhow::Result<()> {
+    let _action = IndexAction::begin();
+    let index_dir = PathBuf::from(index_path);
+    let index = match AudioIndex::read(&index_dir.join("index.json"), &sink)? {
+        Some(value) => value,
+        None => {
+            report_index_action_cancelled(&sink, 0.0);
+            return Ok(());
+        }
+    };
+
+    IndexDatabase::import_in(&index_dir, &index)?;
+
+    let _ = sink.add(IndexActionState {
+        progress: 1.0,
+        message: String::new(),
+    });
+
+    Ok(())
+}
+
+/// for Flutter  
+/// 读取索引中的所有文件夹。存在 index.db 时从数据库读取，否则读取 index.json
+pub fn get_indexed_folders(index_path: String) -> anyhow::Result<Vec<AudioFolder>> {
+    let index_dir = PathBuf::from(index_path);
+    if IndexDatabase::exists_in(&index_dir) {
+        return IndexDatabase::open_in(&index_dir)?.folders();
+    }
+
+    Ok(AudioIndex::read_current(&index_dir.join("index.json"))?.folders)
+}
+
+/// for Flutter  
+/// 读取索引中路径为 folder_path 的文件夹
+pub fn get_indexed_folder(
+    index_path: String,
+    folder_path: String,
+) -> anyhow::Result<Option<AudioFolder>> {
+    let index_dir = PathBuf::from(index_path);
+    if IndexDatabase::exists_in(&index_dir) {
+        return IndexDatabase::open_in(&index_dir)?.folder(&folder_path);
+    }
+
+    Ok(AudioIndex::read_current(&index_dir.join("index.json"))?
+        .folders
+        .into_iter()
+        .find(|item| item.path == folder_path))
+}
+
+/// for Flutter  
+/// 读取索引中路径为 audio_path 的音乐
+pub fn get_indexed_audio(index_path: String, audio_path: String) -> anyhow::Result<Option<Audio>> {
+    let index_dir = PathBuf::from(index_path);
+    if IndexDatabase::exists_in(&index_dir) {
+        return IndexDatabase::open_in(&index_dir)?.audio(&audio_path);
+    }
+
+    Ok(AudioIndex::read_current(&index_dir.join("index.json"))?
+        .folders
+        .into_iter()
+        .flat_map(|item| item.audios)
+        .find(|item| item.path == audio_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _folder(path: &str, audios: &[&str]) -> AudioFolder {
+        AudioFolder {
+            path: path.to_string(),
+            modified: 1,
+            latest: 2,
+            audios: audios
+                .iter()
+                .map(|item| Audio {
+                    path: format!("{}/{}", path, item),
+                    title: item.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            cover: Some(format!("{}/cover.jpg", path)),
+            cover_placeholder: None,
+        }
+    }
+
+    #[test]
+    fn folders_are_read_in_one_query() {
+        let index_dir =
+            std::env::temp_dir().join(format!("coriander_index_db_{}_folders", std::process::id()));
+        let _ = std::fs::remove_dir_all(&index_dir);
+        std::fs::create_dir_all(&index_dir).unwrap();
+
+        let folders = vec![
+            _folder("/music/a", &["1.flac", "2.flac"]),
+            _folder("/music/b", &["3.mp3"]),
+        ];
+        let db = IndexDatabase::open_in(&index_dir).unwrap();
+        db.replace_all(&folders, &[], &ScanRules::default(), false)
+            .unwrap();
+        db.apply_audio_changes(
+            &[_folder("/music/c", &["4.ogg"])],
+            &["/music/b/3.mp3".to_string()],
+        )
+        .unwrap();
+        db.update_folder(&folders[0], &_folder("/music/a", &["2.flac"]))
+            .unwrap();
+
+        let read = db.folders().unwrap();
+        let paths: Vec<_> = read.iter().map(|item| item.path.as_str()).collect();
+        assert_eq!(paths, vec!["/music/a", "/music/c"]);
+        assert_eq!(read[0].audios, _folder("/music/a", &["2.flac"]).audios);
+        assert_eq!(read[0].cover.as_deref(), Some("/music/a/cover.jpg"));
+        assert_eq!(read[1].audios.len(), 1);
+        assert_eq!(
+            db.folder("/music/a").unwrap().unwrap().audios,
+            read[0].audios
+        );
+
+        // 再次打开时沿用同一个连接
+        assert_eq!(
+            IndexDatabase::open_in(&index_dir)
+                .unwrap()
+                .folders()
+                .unwrap()
+                .len(),
+            2
+        );
+
+        std::fs::remove_dir_all(&index_dir).unwrap();
+    }
+}
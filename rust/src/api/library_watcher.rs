@@ -0,0 +1,501 @@
+// 监听已索引的文件夹，文件发生变化时更新索引并通知 Dart。
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard},
+    time::{Duration, UNIX_EPOCH},
+};
+
+use crate::frb_generated::StreamSink;
+use notify_debouncer_mini::{
+    new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode},
+    DebounceEventResult, Debouncer,
+};
+
+use super::{
+    cue_sheet::is_cue_image,
+    index_db::IndexDatabase,
+    logger::log_to_dart,
+    scan_rules::ScanFilter,
+    tag_reader::{lock_index, Audio, AudioFolder, AudioIndex},
+};
+
+/// 同一个文件在这段时间内的多次变化只处理一次
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(800);
+
+static WATCHER: Mutex<Option<LibraryWatcher>> = Mutex::new(None);
+
+struct LibraryWatcher {
+    debouncer: Debouncer<RecommendedWatcher>,
+    index_dir: PathBuf,
+    /// 正在监听的路径，见 [_watch_targets]
+    watched: Vec<PathBuf>,
+}
+
+impl LibraryWatcher {
+    /// 按照现在的索引重新设置监听的路径
+    fn refresh(&mut self) -> anyhow::Result<()> {
+        let targets = _watch_targets(&self.index_dir)?;
+
+        for path in std::mem::take(&mut self.watched) {
+            let _ = self.debouncer.watcher().unwatch(&path);
+        }
+        for (path, mode) in targets {
+            match self.debouncer.watcher().watch(&path, mode) {
+                Ok(_) => self.watched.push(path),
+                Err(err) => log_to_dart(format!("fail to watch {:?}: {}", path, err)),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn _lock_watcher() -> MutexGuard<'static, Option<LibraryWatcher>> {
+    match WATCHER.lock() {
+        Ok(val) => val,
+        Err(val) => val.into_inner(),
+    }
+}
+
+pub enum LibraryEventKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// 发送给 Dart 的文件变化
+pub struct LibraryEvent {
+    pub kind: LibraryEventKind,
+    /// 音乐文件的绝对路径
+    pub path: String,
+    /// Added, Modified: 重新读取到的音乐；Removed: None
+    pub audio: Option<Audio>,
+}
+
+/// for Flutter  
+/// 监听 index_path 下索引的音乐库。文件被添加、删除或修改时更新索引，
+/// 并把变化发送到 sink。
+///
+/// 递归地监听建立索引时传入的根目录，根目录下新建的文件夹中的音乐也会被添加；
+/// 旧版本建立的索引没有保存根目录，只监听索引中的文件夹本身。
+/// 文件夹被删除、移动或重命名时，其中所有的音乐都会被更新。
+/// [super::tag_reader::update_index] 等操作修改了根目录后会自动更新监听的路径。
+///
+/// 变化逐条写入 index.db，每批变化一个事务。index_path 下只有 index.json 时，
+/// 会先把它导入到 index.db（见 [super::index_db::migrate_index_to_database]），之后 index.json 不再更新。
+///
+/// 再次调用会替换之前的监听。
+pub fn watch_library(index_path: String, sink: StreamSink<LibraryEvent>) -> anyhow::Result<()> {
+    let index_dir = PathBuf::from(index_path);
+    if !IndexDatabase::exists_in(&index_dir) {
+        let _guard = lock_index();
+        let index = AudioIndex::read_current(&index_dir.join("index.json"))?;
+        IndexDatabase::import_in(&index_dir, &index)?;
+    }
+
+    let callback_index_dir = index_dir.clone();
+    let debouncer = new_debouncer(DEBOUNCE_TIMEOUT, move |result: DebounceEventResult| {
+        let events = match result {
+            Ok(val) => val,
+            Err(err) => {
+                log_to_dart(format!("fail to watch library: {}", err));
+                return;
+            }
+        };
+
+        let mut paths: Vec<PathBuf> = events.into_iter().map(|item| item.path).collect();
+        paths.sort();
+        paths.dedup();
+
+        match _apply_changes(&callback_index_dir, &paths) {
+            Ok(library_events) => {
+                for item in library_events {
+                    let _ = sink.add(item);
+                }
+            }
+            Err(err) => log_to_dart(format!("fail to update index: {}", err)),
+        }
+    })?;
+
+    let mut library_watcher = LibraryWatcher {
+        debouncer,
+        index_dir,
+        watched: vec![],
+    };
+    library_watcher.refresh()?;
+    *_lock_watcher() = Some(library_watcher);
+
+    Ok(())
+}
+
+/// for Flutter  
+/// 停止 [watch_library] 开始的监听
+pub fn stop_watching_library() {
+    *_lock_watcher() = None;
+}
+
+/// 建立或更新 index_dir 下的索引后调用。正在监听这个索引时，按照新的根目录更新监听的路径
+pub(crate) fn refresh_library_watch(index_dir: &Path) {
+    let mut watcher = _lock_watcher();
+    let library_watcher = match watcher.as_mut() {
+        Some(val) if val.index_dir == index_dir => val,
+        _ => return,
+    };
+
+    if let Err(err) = library_watcher.refresh() {
+        log_to_dart(format!("fail to refresh library watch: {}", err));
+    }
+}
+
+/// 需要监听的路径：递归监听所有根目录；没有保存根目录时，不递归地监听索引中的每个文件夹
+fn _watch_targets(index_dir: &Path) -> anyhow::Result<Vec<(PathBuf, RecursiveMode)>> {
+    let db = IndexDatabase::open_in(index_dir)?;
+    let roots = db.roots()?;
+    let folders = db
+        .folder_records()?
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect::<Vec<_>>();
+
+    if roots.is_empty() {
+        return Ok(folders
+            .into_iter()
+            .map(|item| (PathBuf::from(item), RecursiveMode::NonRecursive))
+            .collect());
+    }
+
+    Ok(roots
+        .into_iter()
+        .map(|item| (PathBuf::from(item), RecursiveMode::Recursive))
+        .collect())
+}
+
+/// path 所在的文件夹，或者它和根目录之间的任意一个文件夹被扫描规则排除。
+/// 根目录本身不受规则限制
+fn _is_in_excluded_folder(path: &Path, roots: &[String], filter: &ScanFilter) -> bool {
+    path.ancestors()
+        .skip(1)
+        .take_while(|item| !roots.iter().any(|root| Path::new(root) == *item))
+        .any(|item| filter.is_folder_excluded(item, false))
+}
+
+/// 比较文件现在的状态和索引中的记录，得到文件的变化。没有变化时返回 None。
+/// 不满足扫描规则的文件视为被删除。
+/// 被 CUE 分割的整轨文件（包括内嵌了 CUE 的）不作为音乐处理，
+/// 虚拟音轨只会在整轨文件不存在或者被排除时删除，其他变化由 [super::tag_reader::update_index] 更新
+fn _diff(
+    path: &Path,
+    indexed: Option<&Audio>,
+    roots: &[String],
+    filter: &ScanFilter,
+) -> Option<LibraryEvent> {
+    let path_str = path.to_string_lossy().to_string();
+    let excluded = _is_in_excluded_folder(path, roots, filter);
+
+    if let Some(indexed) = indexed.filter(|item| item.cue.is_some()) {
+        if excluded || !Path::new(indexed.file_path()).is_file() {
+            return Some(LibraryEvent {
+                kind: LibraryEventKind::Removed,
+                path: path_str,
+                audio: None,
+            });
+        }
+        return None;
+    }
+
+    if path.is_file() && indexed.is_none() && is_cue_image(path) {
+        return None;
+    }
+
+    if path.is_file() && !excluded {
+        let mut audios = filter.read_audios(path);
+        if audios.iter().any(|item| item.cue.is_some()) {
+            return None;
+        }
+        let audio = match audios.pop() {
+            Some(value) => value,
+            None if indexed.is_some() => {
+                return Some(LibraryEvent {
+                    kind: LibraryEventKind::Removed,
+                    path: path_str,
+                    audio: None,
+                })
+            }
+            None => return None,
+        };
+        let kind = match indexed {
+            Some(indexed) if *indexed == audio => return None,
+            Some(_) => LibraryEventKind::Modified,
+            None => LibraryEventKind::Added,
+        };
+        return Some(LibraryEvent {
+            kind,
+            path: path_str,
+            audio: Some(audio),
+        });
+    }
+
+    if indexed.is_some() && (excluded || !path.exists()) {
+        return Some(LibraryEvent {
+            kind: LibraryEventKind::Removed,
+            path: path_str,
+            audio: None,
+        });
+    }
+
+    None
+}
+
+/// 监听到变化的路径对应的需要重新检查的音乐，以及它们在索引中的记录：
+/// - 文件本身；
+/// - 文件夹（新建、移入或者重命名后的）中的所有文件，不进入被扫描规则排除的文件夹和符号链接；
+/// - 索引中路径为它或者位于它之下的音乐（文件夹被删除、移出或者重命名前的路径）。
+fn _affected_audios(
+    db: &IndexDatabase,
+    paths: &[PathBuf],
+    filter: &ScanFilter,
+) -> anyhow::Result<BTreeMap<PathBuf, Option<Audio>>> {
+    let mut affected: BTreeMap<PathBuf, Option<Audio>> = BTreeMap::new();
+    for path in paths {
+        let mut indexed: HashMap<PathBuf, Audio> = db
+            .audios_under(&path.to_string_lossy())?
+            .into_iter()
+            .map(|item| (PathBuf::from(&item.path), item))
+            .collect();
+
+        let mut files: Vec<PathBuf> = vec![];
+        if path.is_dir() {
+            _collect_files(path, filter, &mut files);
+        } else {
+            files.push(path.clone());
+        }
+        for file in files {
+            let audio = indexed.remove(&file);
+            affected.entry(file).or_insert(audio);
+        }
+
+        // 索引中有、但是现在找不到的音乐
+        for (file, audio) in indexed {
+            affected.entry(file).or_insert(Some(audio));
+        }
+    }
+
+    Ok(affected)
+}
+
+/// 递归地收集 folder 下的所有文件
+fn _collect_files(folder: &Path, filter: &ScanFilter, files: &mut Vec<PathBuf>) {
+    let dir = match fs::read_dir(folder) {
+        Ok(val) => val,
+        Err(err) => {
+            log_to_dart(format!("{:?}: {}", folder, err));
+            return;
+        }
+    };
+
+    for entry in dir.flatten() {
+        let file_type = match entry.file_type() {
+            Ok(val) => val,
+            Err(_) => continue,
+        };
+        let path = entry.path();
+        if file_type.is_dir() {
+            if !filter.is_folder_excluded(&path, false) {
+                _collect_files(&path, filter, files);
+            }
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+}
+
+/// 文件夹的修改时间，secs since UNIX_EPOCH。读取不到时为 0，之后的 update_index 会重新扫描它
+fn _folder_modified(folder: &Path) -> u64 {
+    fs::metadata(folder)
+        .and_then(|value| value.modified())
+        .map(|value| {
+            value
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or(Duration::ZERO)
+                .as_secs()
+        })
+        .unwrap_or(0)
+}
+
+/// 重新检查 paths 指向的文件和文件夹，把变化在一个事务中写入 index.db
+fn _apply_changes(index_dir: &Path, paths: &[PathBuf]) -> anyhow::Result<Vec<LibraryEvent>> {
+    let _guard = lock_index();
+
+    let db = IndexDatabase::open_in(index_dir)?;
+    let filter = db.scan_filter()?;
+    let roots = db.roots()?;
+    let mut library_events: Vec<LibraryEvent> = vec![];
+    let mut upserts: BTreeMap<String, AudioFolder> = BTreeMap::new();
+    let mut removed: Vec<String> = vec![];
+
+    for (path, indexed) in _affected_audios(&db, paths, &filter)? {
+        let library_event = match _diff(&path, indexed.as_ref(), &roots, &filter) {
+            Some(value) => value,
+            None => continue,
+        };
+
+        match &library_event.audio {
+            Some(audio) => {
+                let folder_path = match path.parent() {
+                    Some(value) => value,
+                    None => continue,
+                };
+                let folder = upserts
+                    .entry(folder_path.to_string_lossy().to_string())
+                    .or_insert_with_key(|key| {
+                        let mut folder = AudioFolder {
+                            path: key.clone(),
+                            modified: _folder_modified(folder_path),
+                            latest: 0,
+                            audios: vec![],
+                            cover: None,
+                            cover_placeholder: None,
+                        };
+                        filter.fill_folder_cover(&mut folder);
+                        folder
+                    });
+                folder.latest = folder.latest.max(audio.created);
+                folder.audios.push(audio.clone());
+            }
+            None => removed.push(library_event.path.clone()),
+        }
+
+        library_events.push(library_event);
+    }
+
+    if !library_events.is_empty() {
+        let upserts: Vec<AudioFolder> = upserts.into_values().collect();
+        db.apply_audio_changes(&upserts, &removed)?;
+    }
+
+    Ok(library_events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::tag_reader::INDEX_VERSION;
+
+    fn _kinds(events: &[LibraryEvent]) -> Vec<(&str, &str)> {
+        events
+            .iter()
+            .map(|item| {
+                let kind = match item.kind {
+                    LibraryEventKind::Added => "added",
+                    LibraryEventKind::Removed => "removed",
+                    LibraryEventKind::Modified => "modified",
+                };
+                let file_name = Path::new(&item.path).file_name().unwrap();
+                (kind, file_name.to_str().unwrap())
+            })
+            .collect()
+    }
+
+    fn _folder_paths(index_dir: &Path) -> Vec<String> {
+        IndexDatabase::open_in(index_dir)
+            .unwrap()
+            .folders()
+            .unwrap()
+            .into_iter()
+            .map(|item| item.path)
+            .collect()
+    }
+
+    #[test]
+    fn directory_events_update_every_audio_under_them() {
+        let dir = std::env::temp_dir().join(format!(
+            "coriander_library_watcher_{}_directories",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        let index_dir = dir.join("index");
+        let root = dir.join("root");
+        let outside = dir.join("outside");
+        let album = root.join("album");
+        fs::create_dir_all(&index_dir).unwrap();
+        fs::create_dir_all(album.join("disc 2")).unwrap();
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(album.join("a.mp3"), b"a").unwrap();
+        fs::write(album.join("disc 2").join("b.mp3"), b"b").unwrap();
+
+        IndexDatabase::import_in(
+            &index_dir,
+            &AudioIndex {
+                version: INDEX_VERSION,
+                folders: vec![],
+                roots: vec![root.to_string_lossy().to_string()],
+                rules: Default::default(),
+                cover_placeholders: false,
+            },
+        )
+        .unwrap();
+
+        // 新建的文件夹
+        let events = _apply_changes(&index_dir, std::slice::from_ref(&album)).unwrap();
+        assert_eq!(
+            _kinds(&events),
+            vec![("added", "a.mp3"), ("added", "b.mp3")]
+        );
+        let folders = IndexDatabase::open_in(&index_dir)
+            .unwrap()
+            .folders()
+            .unwrap();
+        assert_eq!(folders.len(), 2);
+        assert_eq!(folders[0].modified, _folder_modified(&album));
+        assert_ne!(folders[0].modified, 0);
+
+        // 重命名
+        let renamed = root.join("renamed");
+        fs::rename(&album, &renamed).unwrap();
+        let events = _apply_changes(&index_dir, &[album.clone(), renamed.clone()]).unwrap();
+        assert_eq!(
+            _kinds(&events),
+            vec![
+                ("removed", "a.mp3"),
+                ("removed", "b.mp3"),
+                ("added", "a.mp3"),
+                ("added", "b.mp3"),
+            ]
+        );
+        assert_eq!(
+            _folder_paths(&index_dir),
+            vec![
+                renamed.to_string_lossy().to_string(),
+                renamed.join("disc 2").to_string_lossy().to_string(),
+            ]
+        );
+
+        // 移出根目录
+        let moved = outside.join("renamed");
+        fs::rename(&renamed, &moved).unwrap();
+        let events = _apply_changes(&index_dir, std::slice::from_ref(&renamed)).unwrap();
+        assert_eq!(
+            _kinds(&events),
+            vec![("removed", "a.mp3"), ("removed", "b.mp3")]
+        );
+        assert!(_folder_paths(&index_dir).is_empty());
+
+        // 移入根目录后删除
+        fs::rename(&moved, &album).unwrap();
+        _apply_changes(&index_dir, std::slice::from_ref(&album)).unwrap();
+        assert_eq!(_folder_paths(&index_dir).len(), 2);
+        fs::remove_dir_all(&album).unwrap();
+        let events = _apply_changes(&index_dir, std::slice::from_ref(&album)).unwrap();
+        assert_eq!(
+            _kinds(&events),
+            vec![("removed", "a.mp3"), ("removed", "b.mp3")]
+        );
+        assert!(_folder_paths(&index_dir).is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,608 @@
+// 按照 EBU R128 / ITU-R BS.1770 测量音乐的响度，用于没有 ReplayGain 标签的音乐。
+// 使用 symphonia 解码：MP3、AAC、ALAC、FLAC、Vorbis、WAV、AIFF 等。
+// symphonia 没有解码器的格式见 [UNSUPPORTED_FORMATS]，分析它们会返回说明格式不受支持的 Err。
+// 写入标签时 Opus 使用 R128_*_GAIN（见 [_write_replay_gain]），其他格式使用 REPLAYGAIN_*；
+// 目前 Opus 无法解码，R128 的写入要等 symphonia 支持 Opus 后才会用到。
+// CUE 的虚拟音轨只测量整轨文件中属于它的部分，结果不会写入标签（虚拟音轨共用整轨文件的标签）。
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context};
+use ebur128::{Channel, EbuR128, Mode};
+use flutter_rust_bridge::frb;
+use lofty::{
+    config::WriteOptions,
+    file::FileType,
+    prelude::{AudioFile, ItemKey, TaggedFileExt},
+    tag::{ItemValue, TagItem},
+};
+use symphonia::core::{
+    audio::{Channels, SampleBuffer},
+    codecs::{DecoderOptions, CODEC_TYPE_NULL},
+    errors::Error as SymphoniaError,
+    formats::{FormatOptions, SeekMode, SeekTo},
+    io::MediaSourceStream,
+    meta::MetadataOptions,
+    probe::Hint,
+    units::Time,
+};
+
+use crate::frb_generated::StreamSink;
+
+use super::{
+    cue_sheet::{cue_track_of, source_file_of, CueTrack},
+    logger::log_to_dart,
+    replay_gain::{replay_gain_to_r128, REPLAY_GAIN_REFERENCE_LUFS},
+    tag_reader::IndexActionState,
+    tag_writer::primary_tag_mut,
+};
+
+/// symphonia 没有解码器的格式，(扩展名, 格式名)。分析它们时直接返回 Err
+const UNSUPPORTED_FORMATS: &[(&str, &str)] = &[
+    ("opus", "Opus"),
+    ("ape", "APE"),
+    ("wv", "WavPack"),
+    ("wvc", "WavPack"),
+    ("mpc", "Musepack"),
+    ("wma", "WMA"),
+    ("asf", "WMA"),
+    ("ac3", "AC3"),
+    ("amr", "AMR"),
+    ("3ga", "AMR"),
+    ("mid", "MIDI"),
+    ("dsf", "DSD"),
+    ("dff", "DSD"),
+];
+
+/// 下一个响度分析的编号，见 [new_loudness_analysis_job]
+static NEXT_LOUDNESS_JOB: AtomicU64 = AtomicU64::new(1);
+
+/// 已经取得编号、还没有结束的响度分析，值为它是否被取消
+static LOUDNESS_JOBS: Mutex<BTreeMap<u64, Arc<AtomicBool>>> = Mutex::new(BTreeMap::new());
+
+fn _lock_loudness_jobs() -> MutexGuard<'static, BTreeMap<u64, Arc<AtomicBool>>> {
+    match LOUDNESS_JOBS.lock() {
+        Ok(val) => val,
+        Err(val) => val.into_inner(),
+    }
+}
+
+/// 被取消的分析返回的 Err
+const LOUDNESS_ANALYSIS_CANCELLED_MESSAGE: &str = "已取消";
+
+/// for Flutter  
+/// 取得一个新的响度分析编号，传给 [analyze_track_loudness] 或 [analyze_album_loudness]，
+/// 之后可以通过 [cancel_loudness_analysis] 只取消这一次分析
+#[frb(sync)]
+pub fn new_loudness_analysis_job() -> u64 {
+    let job = NEXT_LOUDNESS_JOB.fetch_add(1, Ordering::Relaxed);
+    _lock_loudness_jobs().insert(job, Arc::new(AtomicBool::new(false)));
+    job
+}
+
+/// for Flutter  
+/// 取消编号为 job 的分析，它会返回 Err("已取消")。其他分析不受影响，已经结束的分析会被忽略
+#[frb(sync)]
+pub fn cancel_loudness_analysis(job: u64) {
+    if let Some(cancelled) = _lock_loudness_jobs().get(&job) {
+        cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+/// 一次正在进行的响度分析，结束（drop）时释放编号
+struct LoudnessJob {
+    job: u64,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl LoudnessJob {
+    fn begin(job: u64) -> Self {
+        let cancelled = _lock_loudness_jobs()
+            .entry(job)
+            .or_insert_with(|| Arc::new(AtomicBool::new(false)))
+            .clone();
+        LoudnessJob { job, cancelled }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for LoudnessJob {
+    fn drop(&mut self) {
+        _lock_loudness_jobs().remove(&self.job);
+    }
+}
+
+pub struct TrackLoudness {
+    pub path: String,
+    /// integrated loudness, in LUFS
+    pub integrated: f64,
+    /// loudness range, in LU
+    pub loudness_range: f64,
+    /// 所有声道中最大的 true peak，线性振幅
+    pub true_peak: f64,
+    /// 相对于 ReplayGain 2.0 参考响度的增益，in dB
+    pub gain: f64,
+}
+
+pub struct AlbumLoudness {
+    pub tracks: Vec<TrackLoudness>,
+    /// 无法解码或者完全静音的音乐，它们不参与专辑响度的计算，也不会被写入标签
+    pub skipped: Vec<String>,
+    /// 整张专辑的 integrated loudness，in LUFS
+    pub integrated: f64,
+    /// 整张专辑的 loudness range，in LU
+    pub loudness_range: f64,
+    /// 所有音乐中最大的 true peak
+    pub true_peak: f64,
+    /// 整张专辑相对于 ReplayGain 2.0 参考响度的增益，in dB
+    pub gain: f64,
+}
+
+/// for Flutter  
+/// 解码 path 并测量它的响度。进度通过 sink 发送。
+/// write_tags 为 true 时把结果写入 ReplayGain 标签（Opus 写入 R128_TRACK_GAIN），虚拟音轨不会写入。
+/// 不支持的格式（见 [UNSUPPORTED_FORMATS]）、无法解码或者完全静音的音乐返回 Err。
+/// job 来自 [new_loudness_analysis_job]
+pub fn analyze_track_loudness(
+    path: String,
+    write_tags: bool,
+    job: u64,
+    sink: StreamSink<IndexActionState>,
+) -> anyhow::Result<TrackLoudness> {
+    let job = LoudnessJob::begin(job);
+
+    let ebur128 = _measure_path(&path, &job, &|progress| _report(&sink, &path, progress))?;
+    let track = _track_loudness(path, &ebur128)?;
+
+    if write_tags {
+        _write_replay_gain(&track, None)?;
+    }
+    _report(&sink, "", 1.0);
+
+    Ok(track)
+}
+
+/// for Flutter  
+/// 测量 paths 中每首音乐的响度以及整张专辑的响度。进度通过 sink 发送。
+/// 无法分析的音乐会被跳过并记录到 [AlbumLoudness::skipped]，所有音乐都无法分析时返回 Err。
+/// write_tags 为 true 时把结果写入 ReplayGain 标签，写入失败的音乐和虚拟音轨会被跳过并记录日志。
+/// job 来自 [new_loudness_analysis_job]
+pub fn analyze_album_loudness(
+    paths: Vec<String>,
+    write_tags: bool,
+    job: u64,
+    sink: StreamSink<IndexActionState>,
+) -> anyhow::Result<AlbumLoudness> {
+    let job = LoudnessJob::begin(job);
+
+    let total = paths.len() as f64;
+    let mut measurements: Vec<EbuR128> = vec![];
+    let mut tracks: Vec<TrackLoudness> = vec![];
+    let mut skipped: Vec<String> = vec![];
+    for (index, path) in paths.into_iter().enumerate() {
+        let measured = _measure_path(&path, &job, &|progress| {
+            _report(&sink, &path, (index as f64 + progress) / total)
+        })
+        .and_then(|ebur128| Ok((_track_loudness(path.clone(), &ebur128)?, ebur128)));
+        match measured {
+            Ok((track, ebur128)) => {
+                tracks.push(track);
+                measurements.push(ebur128);
+            }
+            Err(err) => {
+                if job.is_cancelled() {
+                    return Err(err);
+                }
+                log_to_dart(format!("fail to analyze {}: {}", path, err));
+                skipped.push(path);
+            }
+        }
+    }
+    if tracks.is_empty() {
+        bail!("没有可以分析的音乐");
+    }
+
+    let integrated = EbuR128::loudness_global_multiple(measurements.iter())?;
+    let album = AlbumLoudness {
+        skipped,
+        integrated,
+        loudness_range: EbuR128::loudness_range_multiple(measurements.iter())?,
+        true_peak: tracks.iter().map(|item| item.true_peak).fold(0.0, f64::max),
+        gain: REPLAY_GAIN_REFERENCE_LUFS - integrated,
+        tracks,
+    };
+
+    if write_tags {
+        for track in &album.tracks {
+            if let Err(err) = _write_replay_gain(track, Some(&album)) {
+                log_to_dart(format!(
+                    "fail to write replay gain to {}: {}",
+                    track.path, err
+                ));
+            }
+        }
+    }
+    _report(&sink, "", 1.0);
+
+    Ok(album)
+}
+
+fn _report(sink: &StreamSink<IndexActionState>, path: &str, progress: f64) {
+    let message = if path.is_empty() {
+        String::new()
+    } else {
+        String::from("正在分析 ") + path
+    };
+    let _ = sink.add(IndexActionState { progress, message });
+}
+
+/// 完全静音的音乐没有响度（-inf LUFS），返回 Err
+fn _track_loudness(path: String, ebur128: &EbuR128) -> anyhow::Result<TrackLoudness> {
+    let integrated = ebur128.loudness_global()?;
+    if !integrated.is_finite() {
+        bail!("完全静音，无法测量响度: {}", path);
+    }
+    let mut true_peak: f64 = 0.0;
+    for channel in 0..ebur128.channels() {
+        true_peak = true_peak.max(ebur128.true_peak(channel)?);
+    }
+
+    Ok(TrackLoudness {
+        path,
+        integrated,
+        loudness_range: ebur128.loudness_range()?,
+        true_peak,
+        gain: REPLAY_GAIN_REFERENCE_LUFS - integrated,
+    })
+}
+
+/// 虚拟音轨只测量整轨文件中 [CueTrack::start] 到 [CueTrack::end] 的部分
+fn _measure_path(
+    path: &str,
+    job: &LoudnessJob,
+    on_progress: &dyn Fn(f64),
+) -> anyhow::Result<EbuR128> {
+    let file = source_file_of(path);
+    if file == path {
+        return _measure(Path::new(path), None, job, on_progress);
+    }
+
+    let cue = cue_track_of(path).ok_or(anyhow!("找不到虚拟音轨: {}", path))?;
+    _measure(Path::new(&cue.file), Some(&cue), job, on_progress)
+}
+
+/// 解码整个文件（或者 segment 的部分）并送入 EbuR128。on_progress 的参数为已解码的比例（0.0 ~ 1.0），
+/// 无法得知总帧数时不会调用
+fn _measure(
+    path: &Path,
+    segment: Option<&CueTrack>,
+    job: &LoudnessJob,
+    on_progress: &dyn Fn(f64),
+) -> anyhow::Result<EbuR128> {
+    let extension = path
+        .extension()
+        .map(|item| item.to_string_lossy().to_ascii_lowercase());
+    if let Some((_, name)) = UNSUPPORTED_FORMATS
+        .iter()
+        .find(|(item, _)| extension.as_deref() == Some(*item))
+    {
+        bail!("不支持分析 {} 的响度: {:?}", name, path);
+    }
+
+    let file = File::open(path)?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+    let mut hint = Hint::new();
+    if let Some(extension) = path.extension().and_then(|item| item.to_str()) {
+        hint.with_extension(extension);
+    }
+
+    let mut format = symphonia::default::get_probe()
+        .format(
+            &hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .with_context(|| format!("不支持的格式: {:?}", path))?
+        .format;
+    let track = format
+        .tracks()
+        .iter()
+        .find(|item| item.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or(anyhow!("没有音轨: {:?}", path))?;
+    let track_id = track.id;
+    let time_base = track.codec_params.time_base;
+    let sample_rate = track.codec_params.sample_rate.unwrap_or(0) as u64;
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .with_context(|| format!("不支持的编码: {:?}", path))?;
+
+    if segment.is_some() && sample_rate == 0 {
+        bail!("无法得知采样率: {:?}", path);
+    }
+
+    // 要测量的帧的范围
+    let ms_to_frame = |ms: u64| ms * sample_rate / 1000;
+    let start_frame = segment.map(|item| ms_to_frame(item.start)).unwrap_or(0);
+    let end_frame = segment
+        .and_then(|item| item.end)
+        .map(ms_to_frame)
+        .or(track.codec_params.n_frames);
+    let total_frames = end_frame.map(|item| item.saturating_sub(start_frame));
+
+    if let Some(segment) = segment.filter(|item| item.start > 0) {
+        let seek_to = SeekTo::Time {
+            time: Time::from(Duration::from_millis(segment.start)),
+            track_id: Some(track_id),
+        };
+        // 无法 seek 时从头解码，开始位置之前的帧会被丢弃
+        match format.seek(SeekMode::Accurate, seek_to) {
+            Ok(_) => decoder.reset(),
+            Err(err) => log_to_dart(format!("fail to seek {:?}: {}", path, err)),
+        }
+    }
+    // packet 的时间戳换算为帧
+    let ts_to_frame = |ts: u64| match time_base {
+        Some(time_base) => {
+            let time = time_base.calc_time(ts);
+            time.seconds * sample_rate + (time.frac * sample_rate as f64) as u64
+        }
+        None => ts,
+    };
+
+    let mut ebur128: Option<EbuR128> = None;
+    let mut sample_buffer: Option<SampleBuffer<f32>> = None;
+    let mut decoded_frames: u64 = 0;
+    let mut reported_progress = 0.0;
+
+    loop {
+        if job.is_cancelled() {
+            return Err(anyhow!(LOUDNESS_ANALYSIS_CANCELLED_MESSAGE));
+        }
+
+        let packet = match format.next_packet() {
+            Ok(val) => val,
+            Err(SymphoniaError::IoError(err))
+                if err.kind() == std::io::ErrorKind::UnexpectedEof =>
+            {
+                break
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let packet_frame = ts_to_frame(packet.ts());
+        if end_frame.is_some_and(|end| segment.is_some() && packet_frame >= end) {
+            break;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(val) => val,
+            // 跳过损坏的帧
+            Err(SymphoniaError::DecodeError(err)) => {
+                log_to_dart(format!("{:?}: {}", path, err));
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let spec = *decoded.spec();
+        let ebur128 = match &mut ebur128 {
+            Some(val) => val,
+            None => ebur128.insert(_new_ebur128(spec.channels, spec.rate)?),
+        };
+
+        if sample_buffer
+            .as_ref()
+            .is_none_or(|item| item.capacity() < decoded.capacity())
+        {
+            sample_buffer = Some(SampleBuffer::new(decoded.capacity() as u64, spec));
+        }
+        if let Some(sample_buffer) = &mut sample_buffer {
+            sample_buffer.copy_interleaved_ref(decoded);
+            let channels = spec.channels.count();
+            let frames = (sample_buffer.len() / channels) as u64;
+
+            // 只保留 [start_frame, end_frame) 中的帧
+            let first = start_frame.saturating_sub(packet_frame).min(frames);
+            let last = match end_frame.filter(|_| segment.is_some()) {
+                Some(end) => end.saturating_sub(packet_frame).min(frames),
+                None => frames,
+            };
+            if first < last {
+                let samples =
+                    &sample_buffer.samples()[first as usize * channels..last as usize * channels];
+                ebur128.add_frames_f32(samples)?;
+                decoded_frames += last - first;
+            }
+        }
+
+        if let Some(total_frames) = total_frames {
+            let progress = (decoded_frames as f64 / total_frames as f64).min(1.0);
+            // 每 1% 报告一次
+            if progress - reported_progress >= 0.01 {
+                reported_progress = progress;
+                on_progress(progress);
+            }
+        }
+    }
+
+    ebur128.ok_or(anyhow!("无法解码: {:?}", path))
+}
+
+/// 多声道时按照 symphonia 的声道顺序设置 channel map，LFE 等不参与响度计算的声道设为 Unused
+fn _new_ebur128(channels: Channels, rate: u32) -> anyhow::Result<EbuR128> {
+    let mut ebur128 = EbuR128::new(
+        channels.count() as u32,
+        rate,
+        Mode::I | Mode::LRA | Mode::TRUE_PEAK,
+    )?;
+
+    if channels.count() > 2 {
+        let channel_map: Vec<Channel> = channels
+            .iter()
+            .map(|item| match item {
+                Channels::FRONT_LEFT => Channel::Left,
+                Channels::FRONT_RIGHT => Channel::Right,
+                Channels::FRONT_CENTRE => Channel::Center,
+                Channels::REAR_LEFT | Channels::SIDE_LEFT => Channel::LeftSurround,
+                Channels::REAR_RIGHT | Channels::SIDE_RIGHT => Channel::RightSurround,
+                _ => Channel::Unused,
+            })
+            .collect();
+        ebur128.set_channel_map(&channel_map)?;
+    }
+
+    Ok(ebur128)
+}
+
+/// 把 track（和 album）的增益和峰值写入音乐文件的主标签，没有标签时创建一个。
+/// Opus 写入 R128_TRACK_GAIN 和 R128_ALBUM_GAIN（没有峰值）。
+/// 虚拟音轨共用整轨文件的标签，不会写入
+fn _write_replay_gain(track: &TrackLoudness, album: Option<&AlbumLoudness>) -> anyhow::Result<()> {
+    if source_file_of(&track.path) != track.path {
+        log_to_dart(format!(
+            "skip writing replay gain to cue track: {}",
+            track.path
+        ));
+        return Ok(());
+    }
+
+    let mut tagged_file = lofty::read_from_path(&track.path)?;
+    let file_type = tagged_file.file_type();
+    let tag = primary_tag_mut(&mut tagged_file)?;
+
+    if file_type == FileType::Opus {
+        tag.insert_unchecked(TagItem::new(
+            ItemKey::Unknown("R128_TRACK_GAIN".to_string()),
+            ItemValue::Text(replay_gain_to_r128(track.gain).to_string()),
+        ));
+        if let Some(album) = album {
+            tag.insert_unchecked(TagItem::new(
+                ItemKey::Unknown("R128_ALBUM_GAIN".to_string()),
+                ItemValue::Text(replay_gain_to_r128(album.gain).to_string()),
+            ));
+        }
+    } else {
+        tag.insert_text(
+            ItemKey::ReplayGainTrackGain,
+            format!("{:.2} dB", track.gain),
+        );
+        tag.insert_text(
+            ItemKey::ReplayGainTrackPeak,
+            format!("{:.6}", track.true_peak),
+        );
+        if let Some(album) = album {
+            tag.insert_text(
+                ItemKey::ReplayGainAlbumGain,
+                format!("{:.2} dB", album.gain),
+            );
+            tag.insert_text(
+                ItemKey::ReplayGainAlbumPeak,
+                format!("{:.6}", album.true_peak),
+            );
+        }
+    }
+
+    tagged_file.save_to_path(&track.path, WriteOptions::default())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 16 bit 立体声 WAV，两个声道都是 frequency Hz、峰值为 amplitude 的正弦波
+    fn _sine_wav(path: &Path, frequency: f64, amplitude: f64, seconds: u32) {
+        const RATE: u32 = 48000;
+        let frames = RATE * seconds;
+        let data_len = frames * 4;
+
+        let mut bytes: Vec<u8> = vec![];
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+        bytes.extend_from_slice(b"WAVEfmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&RATE.to_le_bytes());
+        bytes.extend_from_slice(&(RATE * 4).to_le_bytes());
+        bytes.extend_from_slice(&4u16.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&data_len.to_le_bytes());
+        for frame in 0..frames {
+            let t = frame as f64 / RATE as f64;
+            let sample =
+                (amplitude * (2.0 * std::f64::consts::PI * frequency * t).sin() * i16::MAX as f64)
+                    .round() as i16;
+            bytes.extend_from_slice(&sample.to_le_bytes());
+            bytes.extend_from_slice(&sample.to_le_bytes());
+        }
+        std::fs::write(path, bytes).unwrap();
+    }
+
+    #[test]
+    fn sine_wave_of_known_loudness() {
+        // EBU Tech 3341 的第一个测试：-23 dBFS 的 1 kHz 立体声正弦波为 -23 LUFS
+        let path = std::env::temp_dir().join(format!(
+            "coriander_loudness_{}_sine.wav",
+            std::process::id()
+        ));
+        let amplitude = 10f64.powf(-23.0 / 20.0);
+        _sine_wav(&path, 1000.0, amplitude, 5);
+
+        let job = LoudnessJob::begin(new_loudness_analysis_job());
+        let ebur128 = _measure(&path, None, &job, &|_| {}).unwrap();
+        let track = _track_loudness(path.to_string_lossy().to_string(), &ebur128).unwrap();
+        assert!(
+            (track.integrated + 23.0).abs() < 0.1,
+            "{}",
+            track.integrated
+        );
+        assert!((track.gain - 5.0).abs() < 0.1, "{}", track.gain);
+        assert!(
+            (track.true_peak - amplitude).abs() < 0.01,
+            "{}",
+            track.true_peak
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn unsupported_formats_are_reported() {
+        let job = LoudnessJob::begin(new_loudness_analysis_job());
+        for (extension, name) in UNSUPPORTED_FORMATS {
+            let path = format!("/nonexistent/track.{}", extension.to_uppercase());
+            let err = _measure_path(&path, &job, &|_| {}).unwrap_err();
+            assert!(err.to_string().contains(name), "{}", err);
+        }
+    }
+
+    #[test]
+    fn cancelling_one_job_keeps_others_running() {
+        let first = LoudnessJob::begin(new_loudness_analysis_job());
+        cancel_loudness_analysis(first.job);
+        let second = LoudnessJob::begin(new_loudness_analysis_job());
+        assert!(first.is_cancelled());
+        assert!(!second.is_cancelled());
+
+        let first_job = first.job;
+        drop(first);
+        assert!(!_lock_loudness_jobs().contains_key(&first_job));
+    }
+}
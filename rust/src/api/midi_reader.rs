@@ -0,0 +1,445 @@
+// Standard MIDI File 的信息和标签。时长由 tempo map 计算，标题、版权和文本来自 meta 事件。
+
+use std::{fs, path::Path};
+
+use anyhow::{anyhow, bail};
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+use super::tag_reader::Audio;
+
+/// 没有 Set Tempo 事件时的速度：120 BPM，in microseconds per quarter note
+const DEFAULT_TEMPO: u32 = 500_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MidiInfo {
+    /// 0: 单音轨，1: 多音轨同时播放，2: 多个独立的序列
+    pub format: u16,
+    pub tracks: u16,
+    /// pulses per quarter note。使用 SMPTE 时间码时为 None
+    pub ppq: Option<u16>,
+}
+
+/// 时间单位
+#[derive(Clone, Copy)]
+enum Division {
+    /// 每个四分音符的 tick 数，tick 的时长由速度决定
+    Ppq(u16),
+    /// 每秒的 tick 数，与速度无关
+    Smpte(f64),
+}
+
+/// 一个音轨中需要的事件
+#[derive(Default)]
+#[frb(ignore)]
+struct MidiTrack {
+    /// (tick, microseconds per quarter note)
+    tempos: Vec<(u64, u32)>,
+    /// 最后一个事件的 tick
+    end_tick: u64,
+    /// 第一个 Sequence/Track Name
+    name: Option<String>,
+    copyright: Option<String>,
+    texts: Vec<String>,
+}
+
+pub(crate) fn read_midi(path: &Path) -> anyhow::Result<Audio> {
+    let data = fs::read(path)?;
+    if data.get(..4) != Some(b"MThd") {
+        bail!("不是 MIDI 文件: {:?}", path);
+    }
+    let header_len = _u32_be(&data, 4).ok_or(anyhow!("MIDI 文件头不完整: {:?}", path))? as usize;
+    let (format, track_count, division) =
+        match (_u16_be(&data, 8), _u16_be(&data, 10), _u16_be(&data, 12)) {
+            (Some(format), Some(track_count), Some(division)) => (format, track_count, division),
+            _ => bail!("MIDI 文件头不完整: {:?}", path),
+        };
+    let division = if division & 0x8000 == 0 {
+        if division == 0 {
+            bail!("MIDI 的 PPQ 为 0: {:?}", path);
+        }
+        Division::Ppq(division)
+    } else {
+        // 高字节是负的帧率，-29 表示 29.97 (drop frame)，低字节是每帧的 tick 数
+        let fps = match ((division >> 8) as u8 as i8).checked_neg() {
+            Some(29) => 29.97,
+            Some(value) if value > 0 => value as f64,
+            _ => bail!("MIDI 的 SMPTE 帧率不正确: {:?}", path),
+        };
+        if division & 0xFF == 0 {
+            bail!("MIDI 每帧的 tick 数为 0: {:?}", path);
+        }
+        Division::Smpte(fps * (division & 0xFF) as f64)
+    };
+
+    // 只读取 MTrk 块，跳过未知的块
+    let mut tracks: Vec<MidiTrack> = vec![];
+    let mut offset = 8 + header_len;
+    while let (Some(id), Some(len)) = (data.get(offset..offset + 4), _u32_be(&data, offset + 4)) {
+        let start = offset + 8;
+        let end = (start + len as usize).min(data.len());
+        if id == b"MTrk" {
+            tracks.push(_read_track(&data[start..end]));
+        }
+        offset = end;
+    }
+    if tracks.is_empty() {
+        bail!("MIDI 文件没有音轨: {:?}", path);
+    }
+
+    let seconds = if format == 2 {
+        // 每个音轨是独立的序列，依次播放，速度只作用于所在的音轨
+        tracks
+            .iter()
+            .map(|track| _ticks_to_seconds(track.end_tick, &track.tempos, division))
+            .sum()
+    } else {
+        // 所有音轨共用一个 tempo map，通常在第一个音轨中
+        let mut tempos: Vec<(u64, u32)> = tracks
+            .iter()
+            .flat_map(|track| track.tempos.iter().copied())
+            .collect();
+        tempos.sort_by_key(|(tick, _)| *tick);
+        let end_tick = tracks.iter().map(|track| track.end_tick).max().unwrap_or(0);
+        _ticks_to_seconds(end_tick, &tempos, division)
+    };
+
+    let mut audio = Audio::new_with_path(path, Some("MIDI".to_string()))
+        .ok_or(anyhow!("文件名不正确: {:?}", path))?;
+    audio.duration = seconds as u64;
+    if seconds > 0.0 {
+        audio.bitrate = Some((data.len() as f64 * 8.0 / seconds / 1000.0).round() as u32);
+    }
+
+    // 第一个音轨的名字是整首曲子的标题，其他音轨的名字通常是乐器
+    if let Some(name) = tracks[0].name.clone() {
+        audio.title = name;
+    }
+    // 版权不是艺术家，和文本一起放在注释中
+    let texts: Vec<String> = tracks
+        .iter()
+        .find_map(|track| track.copyright.clone())
+        .into_iter()
+        .chain(
+            tracks
+                .iter_mut()
+                .flat_map(|track| std::mem::take(&mut track.texts)),
+        )
+        .collect();
+    if !texts.is_empty() {
+        audio.comment = Some(texts.join("\n"));
+    }
+
+    audio.midi = Some(MidiInfo {
+        format,
+        tracks: track_count,
+        ppq: match division {
+            Division::Ppq(ppq) => Some(ppq),
+            Division::Smpte(_) => None,
+        },
+    });
+    Ok(audio)
+}
+
+/// 按照 tempo map 把 tick 换算为秒。tempos 按 tick 排序
+fn _ticks_to_seconds(end_tick: u64, tempos: &[(u64, u32)], division: Division) -> f64 {
+    let ppq = match division {
+        Division::Ppq(ppq) => ppq as f64,
+        Division::Smpte(ticks_per_second) => return end_tick as f64 / ticks_per_second,
+    };
+
+    let mut seconds = 0.0;
+    let mut last_tick: u64 = 0;
+    let mut tempo = DEFAULT_TEMPO;
+    for (tick, new_tempo) in tempos {
+        let tick = (*tick).min(end_tick);
+        seconds += (tick - last_tick) as f64 * tempo as f64 / ppq / 1_000_000.0;
+        last_tick = tick;
+        tempo = *new_tempo;
+    }
+    seconds + (end_tick - last_tick) as f64 * tempo as f64 / ppq / 1_000_000.0
+}
+
+/// 读取一个 MTrk 块中的所有事件。数据不完整时返回已经读取到的部分
+fn _read_track(data: &[u8]) -> MidiTrack {
+    let mut track = MidiTrack::default();
+    let mut tick: u64 = 0;
+    let mut offset = 0;
+    let mut running_status: Option<u8> = None;
+
+    while offset < data.len() {
+        let delta = match _read_vlq(data, &mut offset) {
+            Some(value) => value,
+            None => break,
+        };
+        tick += delta as u64;
+        track.end_tick = tick;
+
+        let status = match data.get(offset) {
+            Some(value) if value & 0x80 != 0 => {
+                offset += 1;
+                *value
+            }
+            // 省略了状态字节，沿用上一个通道消息的状态
+            Some(_) => match running_status {
+                Some(value) => value,
+                None => break,
+            },
+            None => break,
+        };
+
+        match status {
+            0xFF => {
+                let meta_type = match data.get(offset) {
+                    Some(value) => *value,
+                    None => break,
+                };
+                offset += 1;
+                let len = match _read_vlq(data, &mut offset) {
+                    Some(value) => value as usize,
+                    None => break,
+                };
+                let meta = match data.get(offset..offset + len) {
+                    Some(value) => value,
+                    None => break,
+                };
+                offset += len;
+
+                match meta_type {
+                    0x01 => {
+                        let text = _decode_text(meta);
+                        if !text.is_empty() {
+                            track.texts.push(text);
+                        }
+                    }
+                    0x02 if track.copyright.is_none() => {
+                        track.copyright = Some(_decode_text(meta)).filter(|item| !item.is_empty())
+                    }
+                    0x03 if track.name.is_none() => {
+                        track.name = Some(_decode_text(meta)).filter(|item| !item.is_empty())
+                    }
+                    // Set Tempo
+                    0x51 if len == 3 => {
+                        let tempo = u32::from_be_bytes([0, meta[0], meta[1], meta[2]]);
+                        track.tempos.push((tick, tempo));
+                    }
+                    // End of Track
+                    0x2F => break,
+                    _ => {}
+                }
+                running_status = None;
+            }
+            0xF0 | 0xF7 => {
+                let len = match _read_vlq(data, &mut offset) {
+                    Some(value) => value as usize,
+                    None => break,
+                };
+                offset += len;
+                running_status = None;
+            }
+            0x80..=0xEF => {
+                // Program Change 和 Channel Pressure 只有 1 个数据字节
+                offset += match status & 0xF0 {
+                    0xC0 | 0xD0 => 1,
+                    _ => 2,
+                };
+                running_status = Some(status);
+            }
+            // SMF 中不应该出现的系统消息
+            _ => break,
+        }
+    }
+
+    track
+}
+
+/// 变长整数：每个字节的低 7 位，最高位为 1 表示还有下一个字节，最多 4 个字节
+fn _read_vlq(data: &[u8], offset: &mut usize) -> Option<u32> {
+    let mut value: u32 = 0;
+    for _ in 0..4 {
+        let byte = *data.get(*offset)?;
+        *offset += 1;
+        value = (value << 7) | (byte & 0x7F) as u32;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// SMF 没有规定文本的编码，不是 UTF-8 时按 Latin-1 解码
+fn _decode_text(data: &[u8]) -> String {
+    let text = match std::str::from_utf8(data) {
+        Ok(value) => value.to_string(),
+        Err(_) => data.iter().map(|item| *item as char).collect(),
+    };
+    text.trim_matches(|item: char| item.is_whitespace() || item == '\0')
+        .to_string()
+}
+
+fn _u16_be(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn _u32_be(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    /// 把 data 写入临时文件夹中的 name，返回它的路径
+    fn _fixture(name: &str, data: &[u8]) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("coriander_midi_{}_{}", std::process::id(), name));
+        fs::write(&path, data).unwrap();
+        path
+    }
+
+    fn _midi_fixture(format: u16, division: u16, tracks: &[&[u8]]) -> Vec<u8> {
+        let mut data = b"MThd".to_vec();
+        data.extend_from_slice(&6u32.to_be_bytes());
+        data.extend_from_slice(&format.to_be_bytes());
+        data.extend_from_slice(&(tracks.len() as u16).to_be_bytes());
+        data.extend_from_slice(&division.to_be_bytes());
+        for track in tracks {
+            data.extend_from_slice(b"MTrk");
+            data.extend_from_slice(&(track.len() as u32).to_be_bytes());
+            data.extend_from_slice(track);
+        }
+        data
+    }
+
+    #[test]
+    fn vlq() {
+        let cases: [(&[u8], Option<u32>, usize); 6] = [
+            (&[0x00], Some(0), 1),
+            (&[0x7F], Some(0x7F), 1),
+            (&[0x81, 0x00], Some(0x80), 2),
+            (&[0xFF, 0xFF, 0xFF, 0x7F], Some(0x0FFF_FFFF), 4),
+            // 超过 4 个字节
+            (&[0x80, 0x80, 0x80, 0x80, 0x00], None, 4),
+            // 不完整
+            (&[0x81], None, 1),
+        ];
+        for (data, value, consumed) in cases {
+            let mut offset = 0;
+            assert_eq!(_read_vlq(data, &mut offset), value, "{:02X?}", data);
+            assert_eq!(offset, consumed, "{:02X?}", data);
+        }
+    }
+
+    #[test]
+    fn track_events() {
+        #[rustfmt::skip]
+        let data: &[u8] = &[
+            // Sequence/Track Name、Copyright、Text
+            0x00, 0xFF, 0x03, 0x05, b'S', b'o', b'n', b'g', b' ',
+            0x00, 0xFF, 0x02, 0x03, b'(', b'c', b')',
+            0x00, 0xFF, 0x01, 0x02, 0xE9, b'!',
+            // Set Tempo 1000000 us
+            0x00, 0xFF, 0x51, 0x03, 0x0F, 0x42, 0x40,
+            // Note On，之后两个事件省略了状态字节
+            0x00, 0x90, 0x3C, 0x40,
+            0x81, 0x00, 0x3C, 0x00,
+            0x60, 0x3E, 0x40,
+            // Program Change 只有 1 个数据字节
+            0x00, 0xC0, 0x05,
+            // SysEx
+            0x10, 0xF0, 0x03, 0x7E, 0x7F, 0xF7,
+            // End of Track，之后的数据被忽略
+            0x20, 0xFF, 0x2F, 0x00,
+            0x7F, 0x90, 0x3C, 0x40,
+        ];
+        let track = _read_track(data);
+        assert_eq!(track.name.as_deref(), Some("Song"));
+        assert_eq!(track.copyright.as_deref(), Some("(c)"));
+        // 不是 UTF-8 时按 Latin-1 解码
+        assert_eq!(track.texts, vec!["é!".to_string()]);
+        assert_eq!(track.tempos, vec![(0, 1_000_000)]);
+        assert_eq!(track.end_tick, 0x80 + 0x60 + 0x10 + 0x20);
+
+        // 没有状态可以沿用时停止
+        let track = _read_track(&[0x10, 0x3C, 0x40, 0x10, 0xFF, 0x2F, 0x00]);
+        assert_eq!(track.end_tick, 0x10);
+    }
+
+    #[test]
+    fn ticks_to_seconds() {
+        // 默认 120 BPM，480 PPQ：960 tick 为 1 秒
+        assert_eq!(_ticks_to_seconds(960, &[], Division::Ppq(480)), 1.0);
+        // 前 480 tick 为 120 BPM，之后为 60 BPM
+        let tempos = [(0, 500_000), (480, 1_000_000)];
+        assert_eq!(_ticks_to_seconds(1440, &tempos, Division::Ppq(480)), 2.5);
+        // 结束之后的速度变化不影响时长
+        let tempos = [(0, 500_000), (4800, 1_000_000)];
+        assert_eq!(_ticks_to_seconds(960, &tempos, Division::Ppq(480)), 1.0);
+        // SMPTE 与速度无关
+        assert_eq!(
+            _ticks_to_seconds(2000, &tempos, Division::Smpte(1000.0)),
+            2.0
+        );
+    }
+
+    #[test]
+    fn midi_header_and_tags() {
+        #[rustfmt::skip]
+        let conductor: &[u8] = &[
+            0x00, 0xFF, 0x03, 0x04, b'S', b'o', b'n', b'g',
+            0x00, 0xFF, 0x02, 0x03, b'(', b'c', b')',
+            0x00, 0xFF, 0x51, 0x03, 0x0F, 0x42, 0x40,
+            0x00, 0xFF, 0x2F, 0x00,
+        ];
+        // 1920 tick，60 BPM、480 PPQ 时为 4 秒
+        #[rustfmt::skip]
+        let notes: &[u8] = &[
+            0x00, 0xFF, 0x03, 0x05, b'P', b'i', b'a', b'n', b'o',
+            0x00, 0xFF, 0x01, 0x04, b'N', b'o', b't', b'e',
+            0x00, 0x90, 0x3C, 0x40,
+            0x8F, 0x00, 0x3C, 0x00,
+            0x00, 0xFF, 0x2F, 0x00,
+        ];
+        let path = _fixture("song.mid", &_midi_fixture(1, 480, &[conductor, notes]));
+        let audio = read_midi(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(audio.duration, 4);
+        assert_eq!(audio.title, "Song");
+        assert_eq!(audio.artist, "UNKNOWN");
+        assert_eq!(audio.comment.as_deref(), Some("(c)\nNote"));
+        assert_eq!(
+            audio.midi,
+            Some(MidiInfo {
+                format: 1,
+                tracks: 2,
+                ppq: Some(480)
+            })
+        );
+    }
+
+    #[test]
+    fn midi_smpte_division() {
+        let track: &[u8] = &[0x00, 0x90, 0x3C, 0x40, 0x8F, 0x50, 0xFF, 0x2F, 0x00];
+
+        // 25 fps，每帧 40 tick：1000 tick/秒，2000 tick 为 2 秒
+        let path = _fixture("smpte.mid", &_midi_fixture(0, 0xE728, &[track]));
+        let audio = read_midi(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(audio.midi.unwrap().ppq, None);
+        assert_eq!(audio.duration, 2);
+
+        // 高字节为 0x80 时取反会溢出，低字节为 0 时没有 tick
+        for division in [0x8028, 0xE700] {
+            let path = _fixture("bad_smpte.mid", &_midi_fixture(0, division, &[track]));
+            assert!(read_midi(&path).is_err(), "{:04X}", division);
+            fs::remove_file(&path).unwrap();
+        }
+    }
+}
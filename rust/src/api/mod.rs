@@ -3,6 +3,22 @@
 //
 
 pub mod tag_reader;
+pub mod cover_palette;
+pub mod cover_placeholder;
+pub mod cue_sheet;
+pub mod tag_writer;
+pub mod thumbnail_cache;
+pub mod index_db;
+pub mod dsd_reader;
+pub mod fallback_reader;
+pub mod file_identity;
+pub mod folder_cover;
+pub mod library_watcher;
+pub mod loudness;
+pub mod midi_reader;
+pub mod playlist;
+pub mod replay_gain;
+pub mod scan_rules;
 pub mod smtc_flutter;
 pub mod utils;
 pub mod system_theme;
@@ -0,0 +1,715 @@
+// 播放列表的导入和导出：M3U/M3U8（支持 #EXTINF）、PLS 和 XSPF。
+// 导入时相对路径相对于播放列表所在的文件夹，导出时可以选择写入相对路径或绝对路径。
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    path::{Component, Path, PathBuf, MAIN_SEPARATOR_STR},
+};
+
+use anyhow::anyhow;
+use flutter_rust_bridge::frb;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+
+use super::{
+    cue_sheet::{decode_text, read_folder_cue_sheets, source_file_of},
+    scan_rules::ScanFilter,
+    tag_reader::Audio,
+};
+
+/// Windows 上的播放器通常使用 CRLF
+const NEWLINE: &str = "\r\n";
+
+/// XSPF 的 location 中需要转义的字符。# 会出现在 CUE 分割出的虚拟音轨的路径中
+const LOCATION_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'[')
+    .add(b']')
+    .add(b'^')
+    .add(b'`')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlaylistFormat {
+    /// 扩展 M3U。使用 UTF-8 编码写入，读取时会猜测编码
+    M3u,
+    M3u8,
+    Pls,
+    Xspf,
+}
+
+impl PlaylistFormat {
+    /// 按照扩展名判断格式
+    fn of(path: &Path) -> Option<Self> {
+        match path.extension()?.to_ascii_lowercase().to_str()? {
+            "m3u" => Some(PlaylistFormat::M3u),
+            "m3u8" => Some(PlaylistFormat::M3u8),
+            "pls" => Some(PlaylistFormat::Pls),
+            "xspf" => Some(PlaylistFormat::Xspf),
+            _ => None,
+        }
+    }
+}
+
+/// 播放列表中的一项。标题、艺术家等信息只在文件的标签无法读取时使用
+#[derive(Default)]
+#[frb(ignore)]
+struct PlaylistEntry {
+    path: PathBuf,
+    title: Option<String>,
+    artist: Option<String>,
+    album: Option<String>,
+    /// in secs
+    duration: Option<u64>,
+}
+
+/// for Flutter  
+/// 读取 M3U/M3U8、PLS 或 XSPF 播放列表（按扩展名判断格式）中的音乐，相对路径相对于播放列表所在的文件夹。
+/// 文件能读取时使用文件的标签，否则（例如文件不存在）只有播放列表中记录的标题、艺术家和时长。
+/// 网络流（http:// 等）会被忽略
+pub fn read_playlist(path: String) -> anyhow::Result<Vec<Audio>> {
+    let playlist_path = Path::new(&path);
+    let format =
+        PlaylistFormat::of(playlist_path).ok_or(anyhow!("不支持的播放列表格式: {}", path))?;
+    let base_dir = playlist_path
+        .parent()
+        .ok_or(anyhow!("播放列表的路径不正确: {}", path))?;
+
+    let text = decode_text(&fs::read(playlist_path)?);
+    let entries = match format {
+        PlaylistFormat::M3u | PlaylistFormat::M3u8 => _parse_m3u(&text, base_dir),
+        PlaylistFormat::Pls => _parse_pls(&text, base_dir),
+        PlaylistFormat::Xspf => _parse_xspf(&text, base_dir)?,
+    };
+
+    let paths: Vec<String> = entries
+        .iter()
+        .map(|item| item.path.to_string_lossy().to_string())
+        .collect();
+    let cue_audios = _read_cue_audios(&paths);
+    Ok(entries
+        .par_iter()
+        .zip(paths.par_iter())
+        .map(|(entry, path)| _audio_of_entry(entry, path, &cue_audios))
+        .collect())
+}
+
+/// for Flutter  
+/// 把 paths 按顺序写入路径为 path 的播放列表，覆盖已有的文件。所有格式都使用 UTF-8 编码。
+/// relative 为 true 时写入相对于播放列表所在文件夹的路径，和播放列表不在同一个盘上的文件仍然写入绝对路径。
+/// 能读取到标签时同时写入标题、艺术家和时长
+pub fn write_playlist(
+    path: String,
+    paths: Vec<String>,
+    format: PlaylistFormat,
+    relative: bool,
+) -> anyhow::Result<()> {
+    let playlist_path = Path::new(&path);
+    let base_dir = playlist_path
+        .parent()
+        .ok_or(anyhow!("播放列表的路径不正确: {}", path))?;
+
+    let cue_audios = _read_cue_audios(&paths);
+    let items: Vec<(&String, Option<PathBuf>, Option<Audio>)> = paths
+        .par_iter()
+        .map(|item| {
+            let relative_path = if relative {
+                _relative_path(item, base_dir)
+            } else {
+                None
+            };
+            (item, relative_path, _read_audio(item, &cue_audios))
+        })
+        .collect();
+
+    fs::write(playlist_path, _playlist_bytes(&items, format))?;
+
+    Ok(())
+}
+
+fn _playlist_bytes(
+    items: &[(&String, Option<PathBuf>, Option<Audio>)],
+    format: PlaylistFormat,
+) -> Vec<u8> {
+    let text = match format {
+        PlaylistFormat::M3u | PlaylistFormat::M3u8 => _write_m3u(items),
+        PlaylistFormat::Pls => _write_pls(items),
+        PlaylistFormat::Xspf => _write_xspf(items),
+    };
+    text.into_bytes()
+}
+
+/// paths 中 CUE 分割出的虚拟音轨。每个文件夹的 .cue 文件只读取一次，
+/// 找不到的虚拟音轨再读取整轨文件内嵌的 CUE
+fn _read_cue_audios(paths: &[String]) -> HashMap<String, Audio> {
+    let cue_paths: BTreeSet<&str> = paths
+        .iter()
+        .map(|item| item.as_str())
+        .filter(|item| source_file_of(item) != *item)
+        .collect();
+    if cue_paths.is_empty() {
+        return HashMap::new();
+    }
+
+    let filter = ScanFilter::default();
+    let folders: BTreeSet<&Path> = cue_paths
+        .iter()
+        .filter_map(|item| Path::new(source_file_of(item)).parent())
+        .collect();
+    let mut cue_audios: HashMap<String, Audio> = folders
+        .par_iter()
+        .flat_map_iter(|item| read_folder_cue_sheets(item, &filter).audios)
+        .filter(|item| cue_paths.contains(item.path.as_str()))
+        .map(|item| (item.path.clone(), item))
+        .collect();
+
+    let images: BTreeSet<&str> = cue_paths
+        .iter()
+        .filter(|item| !cue_audios.contains_key(**item))
+        .map(|item| source_file_of(item))
+        .collect();
+    cue_audios.extend(
+        images
+            .par_iter()
+            .flat_map_iter(|item| filter.read_audios(Path::new(item)))
+            .filter(|item| cue_paths.contains(item.path.as_str()))
+            .map(|item| (item.path.clone(), item))
+            .collect::<Vec<_>>(),
+    );
+
+    cue_audios
+}
+
+/// 读取 path 的标签。CUE 分割出的虚拟音轨从 cue_audios（见 [_read_cue_audios]）中查找
+fn _read_audio(path: &str, cue_audios: &HashMap<String, Audio>) -> Option<Audio> {
+    if source_file_of(path) != path {
+        return cue_audios.get(path).cloned();
+    }
+    if Path::new(path).is_file() {
+        Audio::read_from_path(path)
+    } else {
+        None
+    }
+}
+
+fn _audio_of_entry(
+    entry: &PlaylistEntry,
+    path: &str,
+    cue_audios: &HashMap<String, Audio>,
+) -> Audio {
+    if let Some(audio) = _read_audio(path, cue_audios) {
+        return audio;
+    }
+
+    let mut audio = match Audio::new_with_path(&entry.path, None) {
+        Some(value) => value,
+        None => Audio {
+            path: path.to_string(),
+            ..Default::default()
+        },
+    };
+    if let Some(title) = &entry.title {
+        audio.title = title.clone();
+    }
+    if let Some(artist) = &entry.artist {
+        audio.artist = artist.clone();
+    }
+    if let Some(album) = &entry.album {
+        audio.album = album.clone();
+    }
+    audio.duration = entry.duration.unwrap_or(0);
+    audio
+}
+
+/// #EXTINF:时长 属性,艺术家 - 标题，作用于之后的第一个路径。#EXTALB 同理。
+/// 其他以 # 开头的行是注释
+fn _parse_m3u(text: &str, base_dir: &Path) -> Vec<PlaylistEntry> {
+    let mut entries = vec![];
+    let mut pending = PlaylistEntry::default();
+
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(info) = line.strip_prefix("#EXTINF:") {
+            // 属性的值中可能有逗号，显示的名字从引号之外的第一个逗号之后开始
+            let (head, display) = match _find_unquoted_comma(info) {
+                Some(index) => (&info[..index], &info[index + 1..]),
+                None => (info, ""),
+            };
+            pending.duration = head
+                .split_whitespace()
+                .next()
+                .and_then(_parse_duration_secs);
+            (pending.artist, pending.title) = _split_display_name(display);
+        } else if let Some(album) = line.strip_prefix("#EXTALB:") {
+            pending.album = _non_empty(album);
+        } else if !line.starts_with('#') {
+            match _resolve_location(line, base_dir, false) {
+                Some(path) => {
+                    pending.path = path;
+                    entries.push(std::mem::take(&mut pending));
+                }
+                None => pending = PlaylistEntry::default(),
+            }
+        }
+    }
+
+    entries
+}
+
+/// [playlist] 中的 FileN、TitleN、LengthN，按照 N 排序
+fn _parse_pls(text: &str, base_dir: &Path) -> Vec<PlaylistEntry> {
+    let mut entries: BTreeMap<u32, (Option<String>, PlaylistEntry)> = BTreeMap::new();
+
+    for line in text.lines() {
+        let (key, value) = match line.trim().split_once('=') {
+            Some((key, value)) => (key.trim().to_ascii_lowercase(), value.trim()),
+            None => continue,
+        };
+        let (name, index) = match key.find(|item: char| item.is_ascii_digit()) {
+            Some(index) => match key[index..].parse::<u32>() {
+                Ok(value) => (&key[..index], value),
+                Err(_) => continue,
+            },
+            None => continue,
+        };
+
+        let (file, entry) = entries.entry(index).or_default();
+        match name {
+            "file" => *file = Some(value.to_string()),
+            "title" => (entry.artist, entry.title) = _split_display_name(value),
+            "length" => entry.duration = _parse_duration_secs(value),
+            _ => {}
+        }
+    }
+
+    entries
+        .into_values()
+        .filter_map(|(file, mut entry)| {
+            entry.path = _resolve_location(&file?, base_dir, false)?;
+            Some(entry)
+        })
+        .collect()
+}
+
+/// trackList 中的 track：location（URI）、title、creator、album、duration（in ms）
+fn _parse_xspf(text: &str, base_dir: &Path) -> anyhow::Result<Vec<PlaylistEntry>> {
+    let document = roxmltree::Document::parse(text)?;
+    let mut entries = vec![];
+
+    for track in document
+        .descendants()
+        .filter(|item| item.has_tag_name("track"))
+    {
+        let child_text = |name: &str| {
+            track
+                .children()
+                .find(|item| item.has_tag_name(name))
+                .and_then(|item| item.text())
+                .and_then(_non_empty)
+        };
+        // 可以有多个 location，使用第一个能解析的
+        let path = match track
+            .children()
+            .filter(|item| item.has_tag_name("location"))
+            .find_map(|item| _resolve_location(item.text()?, base_dir, true))
+        {
+            Some(value) => value,
+            None => continue,
+        };
+
+        entries.push(PlaylistEntry {
+            path,
+            title: child_text("title"),
+            artist: child_text("creator"),
+            album: child_text("album"),
+            duration: child_text("duration")
+                .and_then(|item| item.parse::<u64>().ok())
+                .map(|item| item / 1000),
+        });
+    }
+
+    Ok(entries)
+}
+
+/// 播放列表中的位置：绝对路径、相对路径或者 file:// URI。
+/// is_uri 为 true 时相对路径也是 URI（XSPF），需要解码。其他协议的 URI（网络流）返回 None
+fn _resolve_location(location: &str, base_dir: &Path, is_uri: bool) -> Option<PathBuf> {
+    let location = location.trim();
+    let path = if location
+        .get(..7)
+        .is_some_and(|item| item.eq_ignore_ascii_case("file://"))
+    {
+        let decoded = percent_decode_str(&location[7..])
+            .decode_utf8_lossy()
+            .to_string();
+        match decoded.strip_prefix('/') {
+            // file:///C:/Music/a.mp3
+            Some(local) if _has_drive_letter(local) => local.to_string(),
+            // file:///home/a.mp3
+            Some(_) => decoded,
+            // file://server/share/a.mp3
+            None => format!("//{}", decoded),
+        }
+    } else if location.contains("://") {
+        return None;
+    } else if is_uri {
+        percent_decode_str(location).decode_utf8_lossy().to_string()
+    } else {
+        location.to_string()
+    };
+    if path.is_empty() {
+        return None;
+    }
+
+    let path = PathBuf::from(path.replace('/', MAIN_SEPARATOR_STR));
+    let path = if path.is_absolute() {
+        path
+    } else {
+        base_dir.join(path)
+    };
+    Some(_normalize(&path))
+}
+
+fn _has_drive_letter(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+/// 去掉路径中的 . 和 ..，不访问文件系统，保证和索引中的路径一致
+fn _normalize(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                result.pop();
+            }
+            other => result.push(other),
+        }
+    }
+    result
+}
+
+/// path 相对于 base_dir 的路径。不在同一个盘上时返回 None
+fn _relative_path(path: &str, base_dir: &Path) -> Option<PathBuf> {
+    let path = Path::new(path);
+    if path.components().next() != base_dir.components().next() {
+        return None;
+    }
+    pathdiff::diff_paths(path, base_dir)
+}
+
+fn _find_unquoted_comma(value: &str) -> Option<usize> {
+    let mut quoted = false;
+    for (index, item) in value.char_indices() {
+        match item {
+            '"' => quoted = !quoted,
+            ',' if !quoted => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// 艺术家 - 标题，没有 " - " 时全部作为标题
+fn _split_display_name(value: &str) -> (Option<String>, Option<String>) {
+    match value.split_once(" - ") {
+        Some((artist, title)) => (_non_empty(artist), _non_empty(title)),
+        None => (None, _non_empty(value)),
+    }
+}
+
+/// 写入播放列表的名字：艺术家 - 标题。没有艺术家时只有标题
+fn _display_name_of(audio: &Audio) -> String {
+    let name = if audio.artist.is_empty() || audio.artist == "UNKNOWN" {
+        audio.title.clone()
+    } else {
+        format!("{} - {}", audio.artist, audio.title)
+    };
+    name.replace(['\r', '\n'], " ")
+}
+
+/// 时长可以是小数，-1 表示未知（网络流）
+fn _parse_duration_secs(value: &str) -> Option<u64> {
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|item| *item >= 0.0)
+        .map(|item| item as u64)
+}
+
+fn _non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+fn _write_m3u(items: &[(&String, Option<PathBuf>, Option<Audio>)]) -> String {
+    let mut text = String::from("#EXTM3U") + NEWLINE;
+    for (path, relative_path, audio) in items {
+        if let Some(audio) = audio {
+            text += &format!(
+                "#EXTINF:{},{}{}",
+                audio.duration,
+                _display_name_of(audio),
+                NEWLINE
+            );
+        }
+        match relative_path {
+            Some(relative_path) => text += &relative_path.to_string_lossy(),
+            None => text += path,
+        }
+        text += NEWLINE;
+    }
+    text
+}
+
+fn _write_pls(items: &[(&String, Option<PathBuf>, Option<Audio>)]) -> String {
+    let mut text = String::from("[playlist]") + NEWLINE;
+    for (index, (path, relative_path, audio)) in items.iter().enumerate() {
+        let number = index + 1;
+        let location = match relative_path {
+            Some(relative_path) => relative_path.to_string_lossy().to_string(),
+            None => path.to_string(),
+        };
+        text += &format!("File{}={}{}", number, location, NEWLINE);
+        if let Some(audio) = audio {
+            text += &format!("Title{}={}{}", number, _display_name_of(audio), NEWLINE);
+            text += &format!("Length{}={}{}", number, audio.duration, NEWLINE);
+        }
+    }
+    text += &format!("NumberOfEntries={}{}", items.len(), NEWLINE);
+    text += &format!("Version=2{}", NEWLINE);
+    text
+}
+
+fn _write_xspf(items: &[(&String, Option<PathBuf>, Option<Audio>)]) -> String {
+    let mut text = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#) + NEWLINE;
+    text += r#"<playlist version="1" xmlns="http://xspf.org/ns/0/">"#;
+    text += NEWLINE;
+    text += "  <trackList>";
+    text += NEWLINE;
+
+    for (path, relative_path, audio) in items {
+        let location = match relative_path {
+            Some(relative_path) => _encode_location(&relative_path.to_string_lossy()),
+            None => _file_uri(path),
+        };
+        text += "    <track>";
+        text += NEWLINE;
+        text += &format!(
+            "      <location>{}</location>{}",
+            _escape_xml(&location),
+            NEWLINE
+        );
+        if let Some(audio) = audio {
+            let mut element = |name: &str, value: &str| {
+                text += &format!(
+                    "      <{}>{}</{}>{}",
+                    name,
+                    _escape_xml(value),
+                    name,
+                    NEWLINE
+                );
+            };
+            element("title", &audio.title);
+            if audio.artist != "UNKNOWN" {
+                element("creator", &audio.artist);
+            }
+            if audio.album != "UNKNOWN" {
+                element("album", &audio.album);
+            }
+            element("duration", &(audio.duration * 1000).to_string());
+        }
+        text += "    </track>";
+        text += NEWLINE;
+    }
+
+    text += "  </trackList>";
+    text += NEWLINE;
+    text += "</playlist>";
+    text += NEWLINE;
+    text
+}
+
+/// 把路径转义为 URI 的路径部分，分隔符为 /
+fn _encode_location(path: &str) -> String {
+    utf8_percent_encode(&path.replace('\\', "/"), LOCATION_ENCODE_SET).to_string()
+}
+
+/// 绝对路径的 file:// URI
+fn _file_uri(path: &str) -> String {
+    let location = _encode_location(path);
+    match location.strip_prefix("//") {
+        // UNC 路径：file://server/share/a.mp3
+        Some(unc) => format!("file://{}", unc),
+        None if location.starts_with('/') => format!("file://{}", location),
+        None => format!("file:///{}", location),
+    }
+}
+
+fn _escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _audio(path: &str, artist: &str, title: &str, duration: u64) -> Audio {
+        Audio {
+            path: path.to_string(),
+            artist: artist.to_string(),
+            title: title.to_string(),
+            album: "UNKNOWN".to_string(),
+            duration,
+            ..Default::default()
+        }
+    }
+
+    fn _items(paths: &[String]) -> Vec<(&String, Option<PathBuf>, Option<Audio>)> {
+        vec![
+            (
+                &paths[0],
+                None,
+                Some(_audio(&paths[0], "Artist", "Title, with comma", 215)),
+            ),
+            (
+                &paths[1],
+                Some(PathBuf::from("sub").join("b #1.flac")),
+                Some(_audio(&paths[1], "UNKNOWN", "Only Title", 60)),
+            ),
+            (&paths[2], None, None),
+        ]
+    }
+
+    fn _paths() -> Vec<String> {
+        vec![
+            "/music/a b.mp3".to_string(),
+            "/music/sub/b #1.flac".to_string(),
+            "/music/CDImage.flac#03".to_string(),
+        ]
+    }
+
+    fn _assert_round_trip(entries: &[PlaylistEntry]) {
+        let paths: Vec<PathBuf> = entries.iter().map(|item| item.path.clone()).collect();
+        assert_eq!(
+            paths,
+            _paths().iter().map(PathBuf::from).collect::<Vec<_>>()
+        );
+        assert_eq!(entries[0].title.as_deref(), Some("Title, with comma"));
+        assert_eq!(entries[0].duration, Some(215));
+        assert_eq!(entries[0].artist.as_deref(), Some("Artist"));
+        assert_eq!(entries[1].title.as_deref(), Some("Only Title"));
+        assert_eq!(entries[1].artist, None);
+        assert_eq!(entries[1].duration, Some(60));
+        assert_eq!(entries[2].title, None);
+        assert_eq!(entries[2].duration, None);
+    }
+
+    #[test]
+    fn m3u_round_trip() {
+        let paths = _paths();
+        let bytes = _playlist_bytes(&_items(&paths), PlaylistFormat::M3u);
+        assert_eq!(
+            bytes,
+            _playlist_bytes(&_items(&paths), PlaylistFormat::M3u8)
+        );
+        assert!(bytes.starts_with(b"#EXTM3U\r\n"));
+        _assert_round_trip(&_parse_m3u(&decode_text(&bytes), Path::new("/music")));
+    }
+
+    #[test]
+    fn pls_round_trip() {
+        let paths = _paths();
+        let text = decode_text(&_playlist_bytes(&_items(&paths), PlaylistFormat::Pls));
+        assert!(text.contains("NumberOfEntries=3\r\n"));
+        _assert_round_trip(&_parse_pls(&text, Path::new("/music")));
+    }
+
+    #[test]
+    fn xspf_round_trip() {
+        let paths = _paths();
+        let text = decode_text(&_playlist_bytes(&_items(&paths), PlaylistFormat::Xspf));
+        assert!(text.contains("<location>file:///music/a%20b.mp3</location>"));
+        assert!(text.contains("<location>sub/b%20%231.flac</location>"));
+        assert!(text.contains("<location>file:///music/CDImage.flac%2303</location>"));
+        // UNKNOWN 的艺术家不会被写入
+        assert_eq!(text.matches("<creator>").count(), 1);
+
+        let entries = _parse_xspf(&text, Path::new("/music")).unwrap();
+        _assert_round_trip(&entries);
+    }
+
+    #[test]
+    fn m3u_is_parsed() {
+        let text = "#EXTM3U\n\
+            #EXTINF:-1 tvg-name=\"a, b\",Artist - Title\n\
+            #EXTALB:Album\n\
+            ../other/./a.mp3\n\
+            #EXTINF:10,Stream\n\
+            http://example.com/stream\n\
+            # comment\n\
+            file:///music/c%20d.flac\n";
+        let entries = _parse_m3u(text, Path::new("/music/list"));
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].path, PathBuf::from("/music/other/a.mp3"));
+        assert_eq!(entries[0].artist.as_deref(), Some("Artist"));
+        assert_eq!(entries[0].title.as_deref(), Some("Title"));
+        assert_eq!(entries[0].album.as_deref(), Some("Album"));
+        assert_eq!(entries[0].duration, None);
+        // 网络流的 #EXTINF 不会作用于下一个路径
+        assert_eq!(entries[1].path, PathBuf::from("/music/c d.flac"));
+        assert_eq!(entries[1].title, None);
+    }
+
+    #[test]
+    fn pls_is_sorted_by_number() {
+        let text = "[playlist]\nFile2=b.mp3\nfile1=a.mp3\nTitle1=A\nLength1=-1\nTitle3=no file\n";
+        let entries = _parse_pls(text, Path::new("/music"));
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].path, PathBuf::from("/music/a.mp3"));
+        assert_eq!(entries[0].title.as_deref(), Some("A"));
+        assert_eq!(entries[0].duration, None);
+        assert_eq!(entries[1].path, PathBuf::from("/music/b.mp3"));
+    }
+
+    #[test]
+    fn location_uris() {
+        assert_eq!(
+            _file_uri("C:\\Music\\a#1.mp3"),
+            "file:///C:/Music/a%231.mp3"
+        );
+        assert_eq!(
+            _file_uri("\\\\server\\share\\a.mp3"),
+            "file://server/share/a.mp3"
+        );
+        assert_eq!(
+            _resolve_location("https://example.com/a.mp3", Path::new("/"), true),
+            None
+        );
+        assert_eq!(_escape_xml("a<b>&\"'"), "a&lt;b&gt;&amp;&quot;&apos;");
+    }
+}
@@ -0,0 +1,234 @@
+// 音量标准化信息。从 ReplayGain、R128 和 iTunNORM 标签中读取，统一为 ReplayGain 2.0 的单位。
+
+use lofty::{
+    prelude::ItemKey,
+    tag::{ItemValue, Tag},
+};
+use serde::{Deserialize, Serialize};
+
+/// ReplayGain 2.0 的参考响度，in LUFS
+pub(crate) const REPLAY_GAIN_REFERENCE_LUFS: f64 = -18.0;
+
+/// R128_*_GAIN 的参考响度，in LUFS
+const R128_REFERENCE_LUFS: f64 = -23.0;
+
+/// gain: 需要调整的音量，in dB，相对于 ReplayGain 2.0 的参考响度（-18 LUFS）
+/// peak: 峰值的线性振幅，1.0 为满刻度
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReplayGain {
+    pub track_gain: Option<f64>,
+    pub track_peak: Option<f64>,
+    pub album_gain: Option<f64>,
+    pub album_peak: Option<f64>,
+}
+
+impl ReplayGain {
+    /// 依次尝试 REPLAYGAIN_*、R128_*_GAIN（Opus）和 iTunNORM。都没有时返回 None
+    pub(crate) fn read_from_tag(tag: &Tag) -> Option<Self> {
+        let mut replay_gain = ReplayGain {
+            track_gain: tag
+                .get_string(&ItemKey::ReplayGainTrackGain)
+                .and_then(_parse_gain),
+            track_peak: tag
+                .get_string(&ItemKey::ReplayGainTrackPeak)
+                .and_then(_parse_peak),
+            album_gain: tag
+                .get_string(&ItemKey::ReplayGainAlbumGain)
+                .and_then(_parse_gain),
+            album_peak: tag
+                .get_string(&ItemKey::ReplayGainAlbumPeak)
+                .and_then(_parse_peak),
+        };
+
+        if replay_gain.track_gain.is_none() {
+            replay_gain.track_gain =
+                _unknown_item(tag, "R128_TRACK_GAIN").and_then(_parse_r128_gain);
+        }
+        if replay_gain.album_gain.is_none() {
+            replay_gain.album_gain =
+                _unknown_item(tag, "R128_ALBUM_GAIN").and_then(_parse_r128_gain);
+        }
+
+        if replay_gain.track_gain.is_none() {
+            if let Some((gain, peak)) = _itun_norm(tag).and_then(_parse_itun_norm) {
+                replay_gain.track_gain = Some(gain);
+                replay_gain.track_peak = replay_gain.track_peak.or(Some(peak));
+            }
+        }
+
+        if replay_gain == ReplayGain::default() {
+            None
+        } else {
+            Some(replay_gain)
+        }
+    }
+}
+
+/// lofty 不认识的键，例如 Vorbis Comments 中的 R128_TRACK_GAIN。不区分大小写
+fn _unknown_item<'a>(tag: &'a Tag, key: &str) -> Option<&'a str> {
+    tag.items()
+        .find_map(|item| match (item.key(), item.value()) {
+            (ItemKey::Unknown(item_key), ItemValue::Text(text))
+                if item_key.eq_ignore_ascii_case(key) =>
+            {
+                Some(text.as_str())
+            }
+            _ => None,
+        })
+}
+
+/// iTunNORM 在 ID3v2 中是描述为 iTunNORM 的 COMM，在 MP4 中是 ----:com.apple.iTunes:iTunNORM
+fn _itun_norm(tag: &Tag) -> Option<&str> {
+    tag.items()
+        .find_map(|item| match (item.key(), item.value()) {
+            (ItemKey::Comment, ItemValue::Text(text)) if item.description() == "iTunNORM" => {
+                Some(text.as_str())
+            }
+            (ItemKey::Unknown(item_key), ItemValue::Text(text))
+                if item_key.to_ascii_lowercase().ends_with("itunnorm") =>
+            {
+                Some(text.as_str())
+            }
+            _ => None,
+        })
+}
+
+/// "-6.54 dB" 或 "-6.54"
+fn _parse_gain(value: &str) -> Option<f64> {
+    let value = value.trim();
+    let value = match value.len().checked_sub(2) {
+        Some(index)
+            if value.is_char_boundary(index) && value[index..].eq_ignore_ascii_case("db") =>
+        {
+            &value[..index]
+        }
+        _ => value,
+    };
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+fn _parse_peak(value: &str) -> Option<f64> {
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite() && *value >= 0.0)
+}
+
+/// R128 的增益是 Q7.8 定点数（dB * 256），参考响度为 -23 LUFS，
+/// 需要再加上 5 dB 才能换算到 ReplayGain 2.0 的参考响度
+fn _parse_r128_gain(value: &str) -> Option<f64> {
+    let q78 = value.trim().parse::<i16>().ok()?;
+    Some(q78 as f64 / 256.0 + (REPLAY_GAIN_REFERENCE_LUFS - R128_REFERENCE_LUFS))
+}
+
+/// [_parse_r128_gain] 的逆运算，超出 Q7.8 范围时取最接近的值
+pub(crate) fn replay_gain_to_r128(gain: f64) -> i16 {
+    let r128 = gain - (REPLAY_GAIN_REFERENCE_LUFS - R128_REFERENCE_LUFS);
+    (r128 * 256.0)
+        .round()
+        .clamp(i16::MIN as f64, i16::MAX as f64) as i16
+}
+
+/// iTunNORM 是 10 个十六进制数：
+/// 0, 1 是左右声道以 1/1000 W 为基准的响度，2, 3 是以 1/2500 W 为基准的响度，
+/// 6, 7 是左右声道的峰值（16 bit 采样）。
+/// 返回 (增益 dB, 峰值)
+fn _parse_itun_norm(value: &str) -> Option<(f64, f64)> {
+    let values: Vec<u32> = value
+        .split_whitespace()
+        .map(|item| u32::from_str_radix(item, 16))
+        .collect::<Result<_, _>>()
+        .ok()?;
+    if values.len() != 10 {
+        return None;
+    }
+
+    let loudness = values[0].max(values[1]);
+    if loudness == 0 {
+        return None;
+    }
+    let gain = -10.0 * (loudness as f64 / 1000.0).log10();
+    let peak = values[6].max(values[7]) as f64 / 32768.0;
+
+    Some((gain, peak))
+}
+
+#[cfg(test)]
+mod tests {
+    use lofty::tag::{TagItem, TagType};
+
+    use super::*;
+
+    fn _tag(items: &[(ItemKey, &str, &str)]) -> Tag {
+        let mut tag = Tag::new(TagType::Id3v2);
+        for (key, description, value) in items {
+            let mut item = TagItem::new(key.clone(), ItemValue::Text(value.to_string()));
+            item.set_description(description.to_string());
+            tag.push_unchecked(item);
+        }
+        tag
+    }
+
+    #[test]
+    fn r128_gain_is_shifted_by_5_db() {
+        assert_eq!(_parse_r128_gain("0"), Some(5.0));
+        assert_eq!(_parse_r128_gain("-256"), Some(4.0));
+        assert_eq!(_parse_r128_gain("512"), Some(7.0));
+        assert_eq!(_parse_r128_gain("40000"), None);
+        assert_eq!(replay_gain_to_r128(5.0), 0);
+        assert_eq!(replay_gain_to_r128(4.0), -256);
+        assert_eq!(replay_gain_to_r128(1000.0), i16::MAX);
+    }
+
+    #[test]
+    fn r128_items_are_read_when_replay_gain_is_missing() {
+        let tag = _tag(&[
+            (ItemKey::Unknown("R128_TRACK_GAIN".to_string()), "", "-1280"),
+            (ItemKey::Unknown("r128_album_gain".to_string()), "", "256"),
+        ]);
+        let replay_gain = ReplayGain::read_from_tag(&tag).unwrap();
+        assert_eq!(replay_gain.track_gain, Some(0.0));
+        assert_eq!(replay_gain.album_gain, Some(6.0));
+        assert_eq!(replay_gain.track_peak, None);
+    }
+
+    #[test]
+    fn itun_norm_gain_and_peak() {
+        // 左右声道响度 1000 / 10000，峰值 0x4000 / 0x8000
+        let value = " 000003E8 00002710 00000000 00000000 00000000 00000000 00004000 00008000 00000000 00000000";
+        let (gain, peak) = _parse_itun_norm(value).unwrap();
+        assert!((gain + 10.0).abs() < 1e-9);
+        assert!((peak - 1.0).abs() < 1e-9);
+
+        assert_eq!(_parse_itun_norm("000003E8 00002710"), None);
+        assert_eq!(_parse_itun_norm(&"00000000 ".repeat(10)), None);
+
+        let tag = _tag(&[(ItemKey::Comment, "iTunNORM", value)]);
+        let replay_gain = ReplayGain::read_from_tag(&tag).unwrap();
+        assert!((replay_gain.track_gain.unwrap() + 10.0).abs() < 1e-9);
+        assert_eq!(replay_gain.track_peak, Some(1.0));
+    }
+
+    #[test]
+    fn replay_gain_items_take_precedence() {
+        let tag = _tag(&[
+            (ItemKey::ReplayGainTrackGain, "", "-6.54 dB"),
+            (ItemKey::ReplayGainTrackPeak, "", "0.988"),
+            (ItemKey::ReplayGainAlbumGain, "", "-7.1"),
+            (ItemKey::ReplayGainAlbumPeak, "", "nan"),
+            (ItemKey::Unknown("R128_TRACK_GAIN".to_string()), "", "0"),
+        ]);
+        let replay_gain = ReplayGain::read_from_tag(&tag).unwrap();
+        assert_eq!(replay_gain.track_gain, Some(-6.54));
+        assert_eq!(replay_gain.track_peak, Some(0.988));
+        assert_eq!(replay_gain.album_gain, Some(-7.1));
+        assert_eq!(replay_gain.album_peak, None);
+
+        assert_eq!(ReplayGain::read_from_tag(&_tag(&[])), None);
+    }
+}
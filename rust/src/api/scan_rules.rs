@@ -0,0 +1,272 @@
+// 扫描时的排除规则。规则和索引保存在一起，更新索引时使用相同的规则。
+
+use std::{fs, path::Path};
+
+use flutter_rust_bridge::frb;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use regex::RegexSet;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    cover_placeholder::{cover_placeholder_of, image_placeholder_of},
+    cue_sheet::split_by_embedded_cue_sheet,
+    folder_cover::folder_cover_of,
+    index_db::IndexDatabase,
+    logger::log_to_dart,
+    tag_reader::{Audio, AudioFolder},
+};
+
+/// 文件夹中存在这些文件时，跳过这个文件夹及其所有子文件夹
+const MARKER_FILES: [&str; 2] = [".nomedia", ".noindex"];
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScanRules {
+    /// 路径匹配其中任意一个 glob 的文件和文件夹不会被索引，例如 `**/Samples/**`
+    pub exclude_globs: Vec<String>,
+    /// 路径匹配其中任意一个正则表达式的文件和文件夹不会被索引
+    pub exclude_regexes: Vec<String>,
+    /// 跳过隐藏的文件夹：名字以 . 开头，或者在 Windows 上带有隐藏属性。
+    /// 建立索引时传入的根目录本身即使是隐藏的也会被扫描
+    pub skip_hidden: bool,
+    /// 跳过包含 .nomedia 或 .noindex 的文件夹及其所有子文件夹
+    pub respect_marker_files: bool,
+    /// 进入符号链接（以及 Windows 上的目录联接）指向的文件夹。
+    /// 为 false 时跳过指向文件夹的符号链接；指向音乐文件的符号链接总是会被读取
+    pub follow_symlinks: bool,
+    /// 时长小于它的音乐不会被索引。in secs
+    pub min_duration: u64,
+    /// 小于它的文件不会被索引。in bytes
+    pub min_file_size: u64,
+    /// 寻找文件夹封面时使用的文件名（不含扩展名，不区分大小写），越靠前越优先。
+    /// 为空时使用默认值：cover, folder, front, albumart。
+    /// 修改后已经建立的索引中的 [super::tag_reader::AudioFolder::cover] 在文件夹下次被更新时才会改变
+    pub folder_cover_names: Vec<String>,
+}
+
+/// 编译好的 [ScanRules]，以及扫描时是否计算封面占位图
+#[frb(ignore)]
+pub(crate) struct ScanFilter {
+    rules: ScanRules,
+    globs: GlobSet,
+    regexes: RegexSet,
+    cover_placeholders: bool,
+}
+
+impl ScanFilter {
+    /// glob 或正则表达式不合法时返回 Err
+    pub(crate) fn new(rules: ScanRules) -> anyhow::Result<Self> {
+        let mut globs = GlobSetBuilder::new();
+        for item in &rules.exclude_globs {
+            globs.add(Glob::new(item)?);
+        }
+
+        Ok(ScanFilter {
+            globs: globs.build()?,
+            regexes: RegexSet::new(&rules.exclude_regexes)?,
+            rules,
+            cover_placeholders: false,
+        })
+    }
+
+    /// 读取标签、寻找文件夹封面时计算封面的 BlurHash，
+    /// 保存在 [Audio::cover_placeholder] 和 [AudioFolder::cover_placeholder]
+    pub(crate) fn with_cover_placeholders(mut self, cover_placeholders: bool) -> Self {
+        self.cover_placeholders = cover_placeholders;
+        self
+    }
+
+    pub(crate) fn rules(&self) -> &ScanRules {
+        &self.rules
+    }
+
+    pub(crate) fn cover_placeholders(&self) -> bool {
+        self.cover_placeholders
+    }
+
+    fn is_path_excluded(&self, path: &Path) -> bool {
+        self.globs.is_match(path) || self.regexes.is_match(&path.to_string_lossy())
+    }
+
+    /// folder 中的封面图片，见 [folder_cover_of]
+    pub(crate) fn folder_cover_of(&self, folder: &Path) -> Option<String> {
+        folder_cover_of(folder, &self.rules.folder_cover_names)
+    }
+
+    /// 需要时计算文件夹封面图片 cover 的封面占位图，见 [ScanFilter::with_cover_placeholders]
+    pub(crate) fn folder_cover_placeholder_of(&self, cover: Option<&str>) -> Option<String> {
+        cover
+            .filter(|_| self.cover_placeholders)
+            .and_then(image_placeholder_of)
+    }
+
+    /// 更新 folder 的封面图片和封面占位图
+    pub(crate) fn fill_folder_cover(&self, folder: &mut AudioFolder) {
+        folder.cover = self.folder_cover_of(Path::new(&folder.path));
+        folder.cover_placeholder = self.folder_cover_placeholder_of(folder.cover.as_deref());
+    }
+
+    /// 是否跳过这个文件夹及其所有子文件夹。is_root 为 true 时不检查 [ScanRules::skip_hidden]
+    pub(crate) fn is_folder_excluded(&self, path: &Path, is_root: bool) -> bool {
+        if self.is_path_excluded(path) {
+            return true;
+        }
+
+        if self.rules.skip_hidden && !is_root && _is_hidden(path) {
+            return true;
+        }
+
+        self.rules.respect_marker_files && MARKER_FILES.iter().any(|item| path.join(item).exists())
+    }
+
+    /// 在读取标签之前检查文件：路径和文件大小
+    pub(crate) fn is_file_excluded(&self, path: &Path) -> bool {
+        if self.is_path_excluded(path) {
+            return true;
+        }
+
+        if self.rules.min_file_size > 0 {
+            return match fs::metadata(path) {
+                Ok(value) => value.len() < self.rules.min_file_size,
+                Err(_) => true,
+            };
+        }
+
+        false
+    }
+
+    /// 在读取标签之后检查音乐：时长
+    pub(crate) fn is_audio_excluded(&self, audio: &Audio) -> bool {
+        audio.duration < self.rules.min_duration
+    }
+
+    /// 检查文件并读取标签。被排除时返回 None
+    pub(crate) fn read_audio(&self, path: &Path) -> Option<Audio> {
+        if self.is_file_excluded(path) {
+            return None;
+        }
+
+        let mut audio = Audio::read_from_path(path).filter(|item| !self.is_audio_excluded(item))?;
+        self.fill_cover_placeholder(&mut audio);
+        Some(audio)
+    }
+
+    /// 和 [ScanFilter::read_audio] 相同，文件内嵌了 CUE 时返回分割出的虚拟音轨。
+    /// 虚拟音轨按照各自的时长检查
+    pub(crate) fn read_audios(&self, path: &Path) -> Vec<Audio> {
+        if self.is_file_excluded(path) {
+            return vec![];
+        }
+
+        let (mut audio, cue_sheet) = match Audio::read_with_embedded_cue_sheet(path) {
+            Some(value) => value,
+            None => return vec![],
+        };
+        self.fill_cover_placeholder(&mut audio);
+        let audios = match cue_sheet {
+            Some(cue_sheet) => split_by_embedded_cue_sheet(&audio, &cue_sheet),
+            None => vec![audio],
+        };
+        audios
+            .into_iter()
+            .filter(|item| !self.is_audio_excluded(item))
+            .collect()
+    }
+
+    /// 需要时计算 audio 的封面占位图，见 [ScanFilter::with_cover_placeholders]
+    pub(crate) fn fill_cover_placeholder(&self, audio: &mut Audio) {
+        if self.cover_placeholders {
+            audio.cover_placeholder = cover_placeholder_of(&audio.path);
+        }
+    }
+}
+
+impl Default for ScanFilter {
+    fn default() -> Self {
+        ScanFilter {
+            rules: ScanRules::default(),
+            globs: GlobSet::empty(),
+            regexes: RegexSet::empty(),
+            cover_placeholders: false,
+        }
+    }
+}
+
+#[cfg(windows)]
+fn _is_hidden(path: &Path) -> bool {
+    use std::os::windows::fs::MetadataExt;
+
+    /// FILE_ATTRIBUTE_HIDDEN
+    const HIDDEN: u32 = 0x2;
+
+    let dot_prefixed = path
+        .file_name()
+        .is_some_and(|item| item.to_string_lossy().starts_with('.'));
+
+    dot_prefixed
+        || fs::metadata(path)
+            .map(|item| item.file_attributes() & HIDDEN != 0)
+            .unwrap_or(false)
+}
+
+#[cfg(not(windows))]
+fn _is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|item| item.to_string_lossy().starts_with('.'))
+}
+
+/// 和 index_dir 下的索引保存在一起的规则和选项。没有索引时为默认值
+#[frb(ignore)]
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct StoredScanOptions {
+    rules: ScanRules,
+    cover_placeholders: bool,
+}
+
+fn _read_stored_scan_options(index_dir: &Path) -> StoredScanOptions {
+    if IndexDatabase::exists_in(index_dir) {
+        let options = IndexDatabase::open_in(index_dir).and_then(|db| {
+            Ok(StoredScanOptions {
+                rules: db.rules()?,
+                cover_placeholders: db.cover_placeholders()?,
+            })
+        });
+        return match options {
+            Ok(val) => val,
+            Err(err) => {
+                log_to_dart(format!("fail to read scan rules: {}", err));
+                StoredScanOptions::default()
+            }
+        };
+    }
+
+    // 只解析 index.json 中的 rules 和 cover_placeholders，跳过其他内容
+    fs::read(index_dir.join("index.json"))
+        .ok()
+        .and_then(|bytes| serde_json::from_slice::<StoredScanOptions>(&bytes).ok())
+        .unwrap_or_default()
+}
+
+/// 读取和 index_dir 下的索引保存在一起的规则。没有索引或者索引中没有规则时返回默认规则
+pub(crate) fn read_stored_scan_rules(index_dir: &Path) -> ScanRules {
+    _read_stored_scan_options(index_dir).rules
+}
+
+/// 按照和 index_dir 下的索引保存在一起的规则和选项建立 [ScanFilter]
+pub(crate) fn read_stored_scan_filter(index_dir: &Path) -> anyhow::Result<ScanFilter> {
+    let options = _read_stored_scan_options(index_dir);
+    Ok(ScanFilter::new(options.rules)?.with_cover_placeholders(options.cover_placeholders))
+}
+
+/// for Flutter  
+/// 读取和 index_path 下的索引保存在一起的扫描规则
+pub fn get_scan_rules(index_path: String) -> ScanRules {
+    read_stored_scan_rules(Path::new(&index_path))
+}
+
+/// for Flutter  
+/// 建立 index_path 下的索引时是否计算了封面占位图，见 [super::tag_reader::build_index_with_rules]
+pub fn get_cover_placeholders_enabled(index_path: String) -> bool {
+    _read_stored_scan_options(Path::new(&index_path)).cover_placeholders
+}
@@ -1,16 +1,31 @@
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     fs::{self},
     io::{self, Cursor, Write},
     path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, MutexGuard,
+    },
     time::{Duration, UNIX_EPOCH},
 };
 
-use image::imageops;
-use lofty::prelude::{Accessor, AudioFile, ItemKey, TaggedFileExt};
+use anyhow::{anyhow, bail, Context};
+use flutter_rust_bridge::frb;
+use image::{imageops, DynamicImage};
+use lofty::{
+    file::{FileType, TaggedFile},
+    picture::{Picture, PictureType},
+    prelude::{Accessor, AudioFile, ItemKey, TaggedFileExt},
+    tag::Tag,
+};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use serde::{Deserialize, Serialize};
+#[cfg(windows)]
 use windows::{
     core::Interface,
     core::HSTRING,
+    Foundation::Collections::{IIterable, IVector},
     Storage::{
         FileProperties::ThumbnailMode,
         StorageFile,
@@ -20,15 +35,29 @@ use windows::{
 
 use crate::frb_generated::StreamSink;
 
-use super::logger::log_to_dart;
+use super::{
+    cue_sheet::{
+        embedded_cue_sheet_of, read_folder_cue_sheets, source_file_of, CueSheet, CueTrack,
+    },
+    fallback_reader::read_by_fallback,
+    file_identity::{canonical_path, FileIdentity},
+    folder_cover::{find_folder_cover, folder_cover_of},
+    index_db::IndexDatabase,
+    library_watcher::refresh_library_watch,
+    logger::log_to_dart,
+    midi_reader::MidiInfo,
+    replay_gain::ReplayGain,
+    scan_rules::{read_stored_scan_filter, ScanFilter, ScanRules},
+    thumbnail_cache::{get_cached_thumbnail, is_thumbnail_cache_enabled},
+};
 
 /// K: extension, V: can read tags by using Lofty
-static SUPPORT_FORMAT: phf::Map<&'static str, bool> = phf::phf_map! {
+pub(crate) static SUPPORT_FORMAT: phf::Map<&'static str, bool> = phf::phf_map! {
     "mp3" => true, "mp2" => false, "mp1" => false,
     "ogg" => true,
     "wav" => true, "wave" => true,
     "aif" => true, "aiff" => true, "aifc" => true,
-    // 通过 Windows 系统支持
+    // 通过后备方式（见 read_by_fallback）或 Windows 系统支持
     "asf" => false, "wma" => false,
     "aac" => true, "adts" => true,
     "m4a" => true,
@@ -36,7 +65,7 @@ static SUPPORT_FORMAT: phf::Map<&'static str, bool> = phf::phf_map! {
     "amr" => false, "3ga" => false,
     "flac" => true,
     "mpc" => true,
-    // 插件支持
+    // 插件支持，或者通过 midi_reader 读取
     "mid" => false,
     "wv" => true, "wvc" => true,
     "opus" => true,
@@ -52,29 +81,231 @@ pub struct IndexActionState {
     pub message: String,
 }
 
-#[derive(Debug)]
-struct Audio {
-    title: String,
-    artist: String,
-    album: String,
-    track: Option<u32>,
+impl IndexActionState {
+    /// completed / total。total 为 0（例如空的音乐库）时为 1.0，避免向 Dart 发送 NaN
+    pub(crate) fn progress_of(completed: usize, total: usize) -> f64 {
+        if total == 0 {
+            1.0
+        } else {
+            completed as f64 / total as f64
+        }
+    }
+}
+
+/// 已经开始（包括正在等待 [lock_index]）的建立/更新索引操作的数量，同时作为操作的编号
+static INDEX_ACTION_STARTED: AtomicU64 = AtomicU64::new(0);
+
+/// 还没有结束的操作的数量
+static INDEX_ACTION_PENDING: AtomicU64 = AtomicU64::new(0);
+
+/// 正在进行或上一次进行的操作的编号
+static INDEX_ACTION_CURRENT: AtomicU64 = AtomicU64::new(0);
+
+/// 编号不大于它的操作都已被取消
+static INDEX_ACTION_CANCELLED_UNTIL: AtomicU64 = AtomicU64::new(0);
+
+/// 被取消的操作最后发送的 [IndexActionState] 的 message
+const INDEX_ACTION_CANCELLED_MESSAGE: &str = "已取消";
+
+/// for Flutter  
+/// 取消正在进行或正在等待开始的 [build_index_from_folders_recursively] 或 [update_index]。
+/// 被取消的操作会发送一个 message 为 "已取消" 的 [IndexActionState] 后正常结束。
+/// 没有操作时什么也不做，不会影响之后开始的操作
+#[frb(sync)]
+pub fn cancel_index_action() {
+    if INDEX_ACTION_PENDING.load(Ordering::SeqCst) > 0 {
+        INDEX_ACTION_CANCELLED_UNTIL.fetch_max(
+            INDEX_ACTION_STARTED.load(Ordering::SeqCst),
+            Ordering::SeqCst,
+        );
+    }
+}
+
+/// for Flutter  
+/// 正在进行或上一次进行的建立/更新索引的操作是否是被取消的
+#[frb(sync)]
+pub fn is_index_action_cancelled() -> bool {
+    let current = INDEX_ACTION_CURRENT.load(Ordering::SeqCst);
+    current != 0 && INDEX_ACTION_CANCELLED_UNTIL.load(Ordering::SeqCst) >= current
+}
+
+/// 建立、更新索引以及 [super::library_watcher] 修改索引时持有，避免同时写入索引
+static INDEX_LOCK: Mutex<()> = Mutex::new(());
+
+pub(crate) fn lock_index() -> MutexGuard<'static, ()> {
+    match INDEX_LOCK.lock() {
+        Ok(val) => val,
+        Err(val) => val.into_inner(),
+    }
+}
+
+/// 一次可以被 [cancel_index_action] 取消的建立/更新索引操作，存在期间持有 [lock_index]。
+/// 编号在等待 [lock_index] 之前取得，所以等待期间的取消也不会丢失
+pub(crate) struct IndexAction {
+    _guard: MutexGuard<'static, ()>,
+}
+
+impl IndexAction {
+    pub(crate) fn begin() -> Self {
+        INDEX_ACTION_PENDING.fetch_add(1, Ordering::SeqCst);
+        let id = INDEX_ACTION_STARTED.fetch_add(1, Ordering::SeqCst) + 1;
+        let guard = lock_index();
+        INDEX_ACTION_CURRENT.store(id, Ordering::SeqCst);
+        Self { _guard: guard }
+    }
+}
+
+impl Drop for IndexAction {
+    fn drop(&mut self) {
+        INDEX_ACTION_PENDING.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+pub(crate) fn report_index_action_cancelled(sink: &StreamSink<IndexActionState>, progress: f64) {
+    let _ = sink.add(IndexActionState {
+        progress,
+        message: INDEX_ACTION_CANCELLED_MESSAGE.to_string(),
+    });
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Audio {
+    pub title: String,
+    pub artist: String,
+    pub album: String,
+    pub track: Option<u32>,
     /// in secs
-    duration: u64,
+    pub duration: u64,
     /// kbps
-    bitrate: Option<u32>,
-    sample_rate: Option<u32>,
+    pub bitrate: Option<u32>,
+    pub sample_rate: Option<u32>,
     /// absolute path
-    path: String,
+    pub path: String,
     /// secs since UNIX_EPOCH
-    modified: u64,
+    pub modified: u64,
     /// secs since UNIX_EPOCH
-    created: u64,
+    pub created: u64,
     /// 标签获取方式
-    by: Option<String>,
+    pub by: Option<String>,
+    /// 文件大小，in bytes
+    #[serde(default)]
+    pub size: u64,
+    /// 由文件大小和标签计算出的标识，文件被移动或重命名后保持不变。见 [Audio::compute_id]
+    #[serde(default)]
+    pub id: String,
+
+    // 以下信息在旧版本的索引中不存在，重新读取标签（文件被修改或重新建立索引）后才会有值
+    #[serde(default)]
+    pub album_artist: Option<String>,
+    #[serde(default)]
+    pub track_total: Option<u32>,
+    #[serde(default)]
+    pub disc: Option<u32>,
+    #[serde(default)]
+    pub disc_total: Option<u32>,
+    #[serde(default)]
+    pub year: Option<u32>,
+    /// 完整的录制日期，例如 "2014-10-27"
+    #[serde(default)]
+    pub date: Option<String>,
+    /// 多个流派以 "/" 分隔
+    #[serde(default)]
+    pub genre: Option<String>,
+    /// 多个作曲家以 "/" 分隔
+    #[serde(default)]
+    pub composer: Option<String>,
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// bits per sample
+    #[serde(default)]
+    pub bit_depth: Option<u8>,
+    #[serde(default)]
+    pub channels: Option<u8>,
+    /// 文件格式，例如 "Flac"、"Mpeg"、"Mp4"。Lofty 不支持的格式为大写的扩展名，例如 "WMA"
+    #[serde(default)]
+    pub file_type: Option<String>,
+    /// 从 ReplayGain、R128 或 iTunNORM 标签中读取的音量标准化信息
+    #[serde(default)]
+    pub replay_gain: Option<ReplayGain>,
+    /// 封面的 BlurHash，可以在封面加载完成前显示。
+    /// 只有建立索引时 cover_placeholders 为 true（见 [build_index_with_rules]）才会计算
+    #[serde(default)]
+    pub cover_placeholder: Option<String>,
+    /// MIDI 文件的格式、音轨数和 PPQ，其他格式为 None
+    #[serde(default)]
+    pub midi: Option<MidiInfo>,
+    /// CUE 分割出的虚拟音轨在整轨文件中的位置，普通的音乐文件为 None。
+    /// 虚拟音轨的 path 为 整轨文件的路径#音轨号，见 [read_folder_cue_sheets]
+    #[serde(default)]
+    pub cue: Option<CueTrack>,
+}
+
+/// 一首音乐从 from 被移动或重命名到了 to
+pub struct AudioRelocation {
+    /// 音乐的 [Audio::id]
+    pub id: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// 上一次 [update_index] 检测到的被移动或重命名的音乐
+static LAST_RELOCATIONS: Mutex<Vec<AudioRelocation>> = Mutex::new(vec![]);
+
+/// for Flutter  
+/// 取出上一次 [update_index] 检测到的被移动或重命名的音乐。
+/// 这些音乐在索引中已经换成了新的路径，以路径为键的数据（歌单、歌词来源等）需要随之更新。
+/// Flutter 端在 [update_index] 结束后调用。
+#[frb(sync)]
+pub fn take_index_relocations() -> Vec<AudioRelocation> {
+    let mut relocations = match LAST_RELOCATIONS.lock() {
+        Ok(val) => val,
+        Err(val) => val.into_inner(),
+    };
+    std::mem::take(&mut *relocations)
+}
+
+/// 一次更新中被删除和新增的音乐，用来找出被移动或重命名的音乐
+#[derive(Default)]
+#[frb(ignore)]
+struct IndexChanges {
+    removed: Vec<Audio>,
+    added: Vec<Audio>,
+}
+
+impl IndexChanges {
+    /// 把 id 相同的被删除的音乐和新增的音乐配对，保存到 [LAST_RELOCATIONS]
+    fn save_relocations(self) {
+        let mut added = self.added;
+        let mut relocations: Vec<AudioRelocation> = vec![];
+        for removed in self.removed {
+            if removed.id.is_empty() {
+                continue;
+            }
+
+            if let Some(index) = added.iter().position(|item| item.id == removed.id) {
+                let to = added.swap_remove(index);
+                relocations.push(AudioRelocation {
+                    id: removed.id,
+                    from: removed.path,
+                    to: to.path,
+                });
+            }
+        }
+
+        for item in &relocations {
+            log_to_dart(format!("relocated: {} -> {}", item.from, item.to));
+        }
+
+        let mut last_relocations = match LAST_RELOCATIONS.lock() {
+            Ok(val) => val,
+            Err(val) => val.into_inner(),
+        };
+        *last_relocations = relocations;
+    }
 }
 
 impl Audio {
-    fn new_with_path(path: impl AsRef<Path>, by: Option<String>) -> Option<Self> {
+    pub(crate) fn new_with_path(path: impl AsRef<Path>, by: Option<String>) -> Option<Self> {
         let path = path.as_ref();
         Some(Audio {
             title: path.file_name()?.to_string_lossy().to_string(),
@@ -88,30 +319,63 @@ impl Audio {
             modified: 0,
             created: 0,
             by,
+            file_type: _file_type_of(path),
+            ..Default::default()
         })
     }
 
-    fn to_json_value(&self) -> serde_json::Value {
-        serde_json::json!({
-            "title": self.title,
-            "artist": self.artist,
-            "album": self.album,
-            "track": self.track,
-            "duration": self.duration,
-            "bitrate": self.bitrate,
-            "sample_rate": self.sample_rate,
-            "path": self.path,
-            "modified": self.modified,
-            "created": self.created,
-            "by": self.by
-        })
+    /// 对文件大小、时长和标签做 FNV-1a 哈希。
+    /// 这些信息不会因为移动或重命名而改变，而且写入 index.json 后要保持稳定，所以不能用 std 的 Hasher。
+    ///
+    /// 没有标题标签时 title 是文件名（见 [Audio::new_with_path]），重命名后会改变，所以不参与计算
+    pub(crate) fn compute_id(&self) -> String {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        let mut write = |bytes: &[u8]| {
+            for byte in bytes.iter().chain(std::iter::once(&0u8)) {
+                hash ^= *byte as u64;
+                hash = hash.wrapping_mul(0x100000001b3);
+            }
+        };
+
+        write(&self.size.to_le_bytes());
+        write(&self.duration.to_le_bytes());
+        write(&self.track.unwrap_or(0).to_le_bytes());
+        let title_from_file_name = Path::new(&self.path)
+            .file_name()
+            .is_some_and(|file_name| file_name.to_string_lossy() == self.title);
+        write(if title_from_file_name {
+            &[]
+        } else {
+            self.title.as_bytes()
+        });
+        write(self.artist.as_bytes());
+        write(self.album.as_bytes());
+
+        format!("{:016x}", hash)
+    }
+
+    /// 音乐所在的文件。CUE 分割出的虚拟音轨为整轨文件
+    pub(crate) fn file_path(&self) -> &str {
+        match &self.cue {
+            Some(cue) => &cue.file,
+            None => &self.path,
+        }
     }
 
     /// 不支持：None  
     /// Lofty 能获取到信息：read_by_lofty  
-    /// 不能的话：read_by_win_music_properties  
+    /// 不能的话：read_by_fallback（symphonia 或者简单的解析器，见 [read_by_fallback]）  
+    /// 再不能的话：read_by_win_music_properties  
     /// 再不能的话：title: filename 代替
-    fn read_from_path(path: impl AsRef<Path>) -> Option<Self> {
+    pub(crate) fn read_from_path(path: impl AsRef<Path>) -> Option<Self> {
+        Self::read_with_embedded_cue_sheet(path).map(|(audio, _)| audio)
+    }
+
+    /// 和 [Audio::read_from_path] 相同，同时返回 Lofty 读取到的内嵌的 CUE，
+    /// 见 [split_by_embedded_cue_sheet]
+    pub(crate) fn read_with_embedded_cue_sheet(
+        path: impl AsRef<Path>,
+    ) -> Option<(Self, Option<CueSheet>)> {
         let path = path.as_ref();
         let lofty_support: bool =
             *SUPPORT_FORMAT.get(&path.extension()?.to_ascii_lowercase().to_string_lossy())?;
@@ -136,31 +400,47 @@ impl Audio {
             .unwrap_or(Duration::ZERO)
             .as_secs();
 
-        if lofty_support {
-            if let Some(value) = Self::read_by_lofty(path, modified, created) {
-                return Some(value);
-            }
-
-            match Self::read_by_win_music_properties(path, modified, created) {
+        let read_by_win_music_properties =
+            || match Self::read_by_win_music_properties(path, modified, created) {
                 Ok(value) => Some(value),
                 Err(err) => {
                     log_to_dart(format!("{:?}: {}", path, err));
-                    return Self::new_with_path(path, None);
+                    Self::new_with_path(path, None)
                 }
+            };
+        let read_without_lofty = || match read_by_fallback(path, modified, created) {
+            Ok(value) => Some((value, None)),
+            Err(err) => {
+                log_to_dart(format!("{:?}: {}", path, err));
+                read_by_win_music_properties().map(|value| (value, None))
             }
+        };
+        let (mut audio, cue_sheet) = if lofty_support {
+            Self::read_by_lofty(path, modified, created).or_else(read_without_lofty)
         } else {
-            match Self::read_by_win_music_properties(path, modified, created) {
-                Ok(value) => Some(value),
-                Err(err) => {
-                    log_to_dart(format!("{:?}: {}", path, err));
-                    return Self::new_with_path(path, None);
-                }
-            }
-        }
+            read_without_lofty()
+        }?;
+
+        audio.size = file_metadata.len();
+        audio.id = audio.compute_id();
+        Some((audio, cue_sheet))
+    }
+
+    /// 给旧的索引记录补上 size 和 id。文件已经不存在时 size 为 0
+    pub(crate) fn fill_identity(&mut self) {
+        self.size = fs::metadata(&self.path)
+            .map(|value| value.len())
+            .unwrap_or(0);
+        self.id = self.compute_id();
     }
 
     /// 使用 lofty 获取音乐标签。只在文件名不正确、没有标签或包含不支持的编码时返回 None
-    fn read_by_lofty(path: impl AsRef<Path>, modified: u64, created: u64) -> Option<Self> {
+    /// 同时读取内嵌的 CUE，见 [embedded_cue_sheet_of]
+    fn read_by_lofty(
+        path: impl AsRef<Path>,
+        modified: u64,
+        created: u64,
+    ) -> Option<(Self, Option<CueSheet>)> {
         let path = path.as_ref();
         let tagged_file = match lofty::read_from_path(path) {
             Ok(val) => val,
@@ -171,40 +451,7 @@ impl Audio {
         };
 
         let properties = tagged_file.properties();
-
-        if let Some(tag) = tagged_file
-            .primary_tag()
-            .or_else(|| tagged_file.first_tag())
-        {
-            let artist_strs: Vec<_> = tag.get_strings(&ItemKey::TrackArtist).collect();
-            let artist = if artist_strs.is_empty() {
-                std::borrow::Cow::Borrowed("UNKNOWN").to_string()
-            } else {
-                artist_strs.join("/")
-            };
-
-            return Some(Audio {
-                title: tag
-                    .title()
-                    .unwrap_or(path.file_name()?.to_string_lossy())
-                    .to_string(),
-                artist,
-                album: tag
-                    .album()
-                    .unwrap_or(std::borrow::Cow::Borrowed("UNKNOWN"))
-                    .to_string(),
-                track: tag.track(),
-                duration: properties.duration().as_secs(),
-                bitrate: properties.audio_bitrate(),
-                sample_rate: properties.sample_rate(),
-                path: path.to_string_lossy().to_string(),
-                modified,
-                created,
-                by: Some("Lofty".to_string()),
-            });
-        }
-
-        return Some(Audio {
+        let mut audio = Audio {
             title: path.file_name()?.to_string_lossy().to_string(),
             artist: std::borrow::Cow::Borrowed("UNKNOWN").to_string(),
             album: std::borrow::Cow::Borrowed("UNKNOWN").to_string(),
@@ -216,17 +463,74 @@ impl Audio {
             modified,
             created,
             by: Some("Lofty".to_string()),
-        });
+            bit_depth: properties.bit_depth(),
+            channels: properties.channels(),
+            file_type: Some(format!("{:?}", tagged_file.file_type())),
+            ..Default::default()
+        };
+
+        if let Some(tag) = tagged_file
+            .primary_tag()
+            .or_else(|| tagged_file.first_tag())
+        {
+            audio.apply_lofty_tag(tag);
+        }
+
+        let cue_sheet = embedded_cue_sheet_of(path, &tagged_file);
+        Some((audio, cue_sheet))
+    }
+
+    /// 用 tag 中的信息覆盖标题、艺术家、专辑等字段，tag 中没有的字段保持不变
+    pub(crate) fn apply_lofty_tag(&mut self, tag: &Tag) {
+        let artist_strs: Vec<_> = tag.get_strings(&ItemKey::TrackArtist).collect();
+        if !artist_strs.is_empty() {
+            self.artist = artist_strs.join("/");
+        }
+        let joined_strings = |key: &ItemKey| {
+            let strs: Vec<_> = tag.get_strings(key).collect();
+            if strs.is_empty() {
+                None
+            } else {
+                Some(strs.join("/"))
+            }
+        };
+
+        if let Some(title) = tag.title() {
+            self.title = title.to_string();
+        }
+        if let Some(album) = tag.album() {
+            self.album = album.to_string();
+        }
+        self.track = tag.track().or(self.track);
+        self.album_artist = tag
+            .get_string(&ItemKey::AlbumArtist)
+            .map(str::to_string)
+            .or(self.album_artist.take());
+        self.track_total = tag.track_total().or(self.track_total);
+        self.disc = tag.disk().or(self.disc);
+        self.disc_total = tag.disk_total().or(self.disc_total);
+        self.year = tag.year().or(self.year);
+        self.date = tag
+            .get_string(&ItemKey::RecordingDate)
+            .map(str::to_string)
+            .or(self.date.take());
+        self.genre = joined_strings(&ItemKey::Genre).or(self.genre.take());
+        self.composer = joined_strings(&ItemKey::Composer).or(self.composer.take());
+        self.comment = _comment_of(tag).or(self.comment.take());
+        self.replay_gain = ReplayGain::read_from_tag(tag).or(self.replay_gain);
     }
 
     /// 使用 Windows Api 获取音乐标签。会因为各种原因返回 Err
+    #[cfg(windows)]
     fn read_by_win_music_properties(
         path: impl AsRef<Path>,
         modified: u64,
         created: u64,
     ) -> Result<Self, windows::core::Error> {
         let path = path.as_ref();
-        let storage_file = StorageFile::GetFileFromPathAsync(&HSTRING::from(path))?.get()?;
+        let storage_file =
+            StorageFile::GetFileFromPathAsync(&HSTRING::from(path.to_string_lossy().as_ref()))?
+                .get()?;
         let music_properties = storage_file
             .Properties()?
             .GetMusicPropertiesAsync()?
@@ -258,6 +562,56 @@ impl Audio {
             album = "UNKNOWN".to_string();
         }
 
+        let non_empty = |value: HSTRING| {
+            if value.is_empty() {
+                None
+            } else {
+                Some(value.to_string())
+            }
+        };
+        let joined = |values: IVector<HSTRING>| {
+            let strs: Vec<String> = values.into_iter().map(|item| item.to_string()).collect();
+            if strs.is_empty() {
+                None
+            } else {
+                Some(strs.join("/"))
+            }
+        };
+
+        // MusicProperties 中没有的信息通过 Windows 属性系统读取
+        let extra_properties = music_properties
+            .RetrievePropertiesAsync(&IIterable::<HSTRING>::try_from(vec![
+                HSTRING::from("System.Audio.SampleRate"),
+                HSTRING::from("System.Audio.SampleSize"),
+                HSTRING::from("System.Audio.ChannelCount"),
+                HSTRING::from("System.Music.PartOfSet"),
+                HSTRING::from("System.Comment"),
+                HSTRING::from("System.Media.MCDI"),
+            ])?)?
+            .get()?;
+        let extra_property = |name: &str| {
+            extra_properties
+                .Lookup(&HSTRING::from(name))
+                .ok()
+                .filter(|value| !value.as_raw().is_null())
+        };
+        let extra_u32 =
+            |name: &str| extra_property(name).and_then(|value| u32::try_from(&value).ok());
+        let extra_string = |name: &str| {
+            extra_property(name)
+                .and_then(|value| HSTRING::try_from(&value).ok())
+                .and_then(non_empty)
+        };
+
+        // "1/2" 或者 "1"
+        let part_of_set = extra_string("System.Music.PartOfSet");
+        let mut part_of_set = part_of_set
+            .iter()
+            .flat_map(|value| value.split('/'))
+            .map(|value| value.trim().parse::<u32>().ok());
+
+        let year = music_properties.Year()?;
+
         Ok(Audio {
             title,
             artist,
@@ -265,38 +619,266 @@ impl Audio {
             track: Some(music_properties.TrackNumber()?),
             duration: duration.as_secs(),
             bitrate: Some(music_properties.Bitrate()? / 1000),
-            sample_rate: None,
+            sample_rate: extra_u32("System.Audio.SampleRate"),
             path: path.to_string_lossy().to_string(),
             modified,
             created,
             by: Some("Windows".to_string()),
+            size: 0,
+            id: String::new(),
+            album_artist: music_properties.AlbumArtist().ok().and_then(non_empty),
+            // Windows 属性系统中没有音轨总数，只能从翻录 CD 时写入的 TOC 中取得
+            track_total: extra_string("System.Media.MCDI")
+                .as_deref()
+                .and_then(_track_total_of_mcdi),
+            disc: part_of_set.next().flatten(),
+            disc_total: part_of_set.next().flatten(),
+            year: if year == 0 { None } else { Some(year) },
+            date: None,
+            genre: music_properties.Genre().ok().and_then(joined),
+            composer: music_properties.Composers().ok().and_then(joined),
+            comment: extra_string("System.Comment"),
+            bit_depth: extra_u32("System.Audio.SampleSize")
+                .and_then(|value| u8::try_from(value).ok()),
+            channels: extra_u32("System.Audio.ChannelCount")
+                .and_then(|value| u8::try_from(value).ok()),
+            file_type: _file_type_of(path),
+            replay_gain: None,
+            cover_placeholder: None,
+            midi: None,
+            cue: None,
         })
     }
+
+    /// 其他平台上没有 Windows Api
+    #[cfg(not(windows))]
+    fn read_by_win_music_properties(
+        _path: impl AsRef<Path>,
+        _modified: u64,
+        _created: u64,
+    ) -> Result<Self, io::Error> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+/// tag 中的注释。iTunes 会把 iTunNORM、iTunSMPB 等数据存为带描述的 COMM，它们不是注释
+fn _comment_of(tag: &Tag) -> Option<String> {
+    tag.items()
+        .filter(|item| *item.key() == ItemKey::Comment && !item.description().starts_with("iTun"))
+        .find_map(|item| item.value().text())
+        .map(str::to_string)
+}
+
+/// MCDI 是 CD 的 TOC："音轨数+第一轨的偏移+...+导出区的偏移"，都是十六进制数
+#[cfg(windows)]
+fn _track_total_of_mcdi(mcdi: &str) -> Option<u32> {
+    let mut values = mcdi.trim().split('+');
+    let track_total = u32::from_str_radix(values.next()?.trim(), 16).ok()?;
+    let offsets = values.count() as u32;
+    if track_total == 0 || offsets != track_total + 1 {
+        return None;
+    }
+
+    Some(track_total)
+}
+
+/// Lofty 支持的格式使用 Lofty 的 FileType，否则使用大写的扩展名
+fn _file_type_of(path: &Path) -> Option<String> {
+    let extension = path.extension()?;
+    Some(match FileType::from_ext(extension) {
+        Some(file_type) => format!("{:?}", file_type),
+        None => extension.to_string_lossy().to_uppercase(),
+    })
 }
 
-#[derive(Debug)]
-struct AudioFolder {
-    path: String,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudioFolder {
+    pub path: String,
     /// secs since UNIX_EPOCH
-    modified: u64,
+    pub modified: u64,
     /// biggest created in audios. secs since UNIX_EPOCH
-    latest: u64,
-    audios: Vec<Audio>,
+    pub latest: u64,
+    pub audios: Vec<Audio>,
+    /// 文件夹中的封面图片（cover.jpg 等）的绝对路径，见 [folder_cover_of]
+    #[serde(default)]
+    pub cover: Option<String>,
+    /// cover 的 BlurHash，和 [Audio::cover_placeholder] 相同，只有需要时才会计算
+    #[serde(default)]
+    pub cover_placeholder: Option<String>,
 }
 
 impl AudioFolder {
-    fn to_json_value(&self) -> serde_json::Value {
-        let mut audios_json: Vec<serde_json::Value> = vec![];
-        for audio in &self.audios {
-            audios_json.push(audio.to_json_value());
+    /// 更新文件夹的索引和封面。new_modified 是文件夹现在的修改时间
+    /// 1. 删除访问不到的文件的记录
+    /// 2. 重新读取被修改（再次读取到的 modified > 记录的 modified）的文件的标签
+    /// 3. 添加新增（路径不在索引中）的音乐文件。被重命名或者移入的文件的 created 不一定会变，
+    ///    所以不能只看 created > 记录的 latest
+    ///
+    /// 4. 重新生成 CUE 分割出的虚拟音轨，被 .cue 文件引用的整轨文件不再作为音乐被索引。
+    ///    内嵌了 CUE 的整轨文件被修改时重新分割
+    ///
+    /// 新增的音乐文件需要满足 filter 的规则。
+    /// 被删除和新增的音乐会记录到 changes 中。
+    /// 被取消时返回 false，此时文件夹可能只更新了一部分，changes 不会被修改
+    fn update(
+        &mut self,
+        new_modified: u64,
+        filter: &ScanFilter,
+        changes: &mut IndexChanges,
+    ) -> bool {
+        self.modified = new_modified;
+        filter.fill_folder_cover(self);
+        let mut folder_changes = IndexChanges::default();
+
+        // .cue 文件分割出的虚拟音轨每次都重新生成
+        let (old_cue_audios, audios): (Vec<Audio>, Vec<Audio>) = std::mem::take(&mut self.audios)
+            .into_iter()
+            .partition(|item| item.cue.as_ref().is_some_and(|cue| !cue.embedded));
+        let cue_sheets = read_folder_cue_sheets(Path::new(&self.path), filter);
+        let mut cue_changes = IndexChanges::default();
+        Self::_diff_by_path(&old_cue_audios, &cue_sheets.audios, &mut cue_changes);
+
+        // 删除访问不到的文件和现在被 .cue 文件分割的整轨文件的记录
+        let (audios, removed): (Vec<Audio>, Vec<Audio>) = audios.into_iter().partition(|item| {
+            let path = Path::new(item.file_path());
+            path.exists() && !cue_sheets.images.contains(path)
+        });
+        self.audios = audios;
+        folder_changes.removed = removed;
+        folder_changes.removed.append(&mut cue_changes.removed);
+
+        // 需要重新分割的内嵌了 CUE 的整轨文件
+        let mut resplit_files: Vec<String> = vec![];
+
+        for audio in &mut self.audios {
+            if is_index_action_cancelled() {
+                return false;
+            }
+
+            let new_audio_modified = match fs::metadata(audio.file_path()) {
+                Ok(value) => match value.modified() {
+                    Ok(value) => value
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or(Duration::ZERO)
+                        .as_secs(),
+                    Err(_) => continue,
+                },
+                Err(_) => continue,
+            };
+            // 跳过没有被修改的文件
+            if new_audio_modified <= audio.modified {
+                continue;
+            }
+
+            // 内嵌 CUE 的虚拟音轨之后和整轨文件的其他音轨一起重新分割
+            if audio.cue.is_some() {
+                let file = audio.file_path().to_string();
+                if !resplit_files.contains(&file) {
+                    resplit_files.push(file);
+                }
+                continue;
+            }
+
+            // 重新读取被修改的音乐文件的标签并更新
+            match Audio::read_with_embedded_cue_sheet(&audio.path) {
+                Some((mut modified_audio, None)) => {
+                    filter.fill_cover_placeholder(&mut modified_audio);
+                    *audio = modified_audio;
+                }
+                // 新增了内嵌的 CUE
+                Some((_, Some(_))) => resplit_files.push(audio.path.clone()),
+                None => {}
+            }
         }
 
-        serde_json::json!({
-            "path": self.path,
-            "modified": self.modified,
-            "latest": self.latest,
-            "audios": audios_json,
-        })
+        for file in resplit_files {
+            if is_index_action_cancelled() {
+                return false;
+            }
+
+            let (old_audios, audios): (Vec<Audio>, Vec<Audio>) = std::mem::take(&mut self.audios)
+                .into_iter()
+                .partition(|item| item.file_path() == file);
+            self.audios = audios;
+            let new_audios = filter.read_audios(Path::new(&file));
+            Self::_diff_by_path(&old_audios, &new_audios, &mut folder_changes);
+            self.audios.extend(new_audios);
+        }
+
+        // 添加新增的音乐文件
+        let indexed: HashSet<String> = self
+            .audios
+            .iter()
+            .map(|item| item.file_path().to_string())
+            .collect();
+        let mut new_latest: u64 = cue_changes
+            .added
+            .iter()
+            .map(|item| item.created)
+            .fold(self.latest, u64::max);
+        self.audios.extend(cue_sheets.audios);
+        folder_changes.added.append(&mut cue_changes.added);
+        let dir = match fs::read_dir(&self.path) {
+            Ok(value) => value,
+            Err(_) => {
+                self.latest = new_latest;
+                changes.removed.append(&mut folder_changes.removed);
+                changes.added.append(&mut folder_changes.added);
+                return true;
+            }
+        };
+        for entry in dir {
+            if is_index_action_cancelled() {
+                return false;
+            }
+
+            let entry = match entry {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+            let file_type = match entry.file_type() {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+            if file_type.is_dir()
+                || indexed.contains(entry.path().to_string_lossy().as_ref())
+                || cue_sheets.images.contains(&entry.path())
+            {
+                continue;
+            }
+
+            for new_audio in filter.read_audios(&entry.path()) {
+                if new_audio.created > new_latest {
+                    new_latest = new_audio.created;
+                }
+
+                folder_changes.added.push(new_audio.clone());
+                self.audios.push(new_audio);
+            }
+        }
+
+        self.latest = new_latest;
+        changes.removed.append(&mut folder_changes.removed);
+        changes.added.append(&mut folder_changes.added);
+        true
+    }
+
+    /// 比较同一个文件（或 CUE）重新读取前后的音乐，路径不再存在的记为删除，新出现的记为新增
+    fn _diff_by_path(old_audios: &[Audio], new_audios: &[Audio], changes: &mut IndexChanges) {
+        let old_paths: HashSet<&String> = old_audios.iter().map(|item| &item.path).collect();
+        let new_paths: HashSet<&String> = new_audios.iter().map(|item| &item.path).collect();
+        changes.removed.extend(
+            old_audios
+                .iter()
+                .filter(|item| !new_paths.contains(&item.path))
+                .cloned(),
+        );
+        changes.added.extend(
+            new_audios
+                .iter()
+                .filter(|item| !old_paths.contains(&item.path))
+                .cloned(),
+        );
     }
 
     /// 扫描路径为 path 的文件夹
@@ -311,6 +893,7 @@ impl AudioFolder {
             }
         };
 
+        let mut cue_sheets = read_folder_cue_sheets(path, &ScanFilter::default());
         let mut audios: Vec<Audio> = vec![];
         let mut latest: u64 = 0;
 
@@ -325,7 +908,7 @@ impl AudioFolder {
                 Err(_) => continue,
             };
 
-            if file_type.is_file() {
+            if file_type.is_file() && !cue_sheets.images.contains(&entry.path()) {
                 if let Some(audio_item) = Audio::read_from_path(entry.path()) {
                     if audio_item.created > latest {
                         latest = audio_item.created;
@@ -335,6 +918,10 @@ impl AudioFolder {
                 }
             }
         }
+        for audio_item in &cue_sheets.audios {
+            latest = latest.max(audio_item.created);
+        }
+        audios.append(&mut cue_sheets.audios);
 
         if !audios.is_empty() {
             return Ok(AudioFolder {
@@ -346,6 +933,8 @@ impl AudioFolder {
                     .as_secs(),
                 latest,
                 audios,
+                cover: folder_cover_of(path, &[]),
+                cover_placeholder: None,
             });
         }
 
@@ -355,37 +944,55 @@ impl AudioFolder {
         ))
     }
 
-    /// 扫描路径为 path 的文件夹及其所有子文件夹。
-    fn read_from_folder_recursively(
-        folder: impl AsRef<Path>,
-        result: &mut Vec<Self>,
-        scaned_count: &mut u64,
-        total_count: &mut u64,
-        scaned_folders: &mut HashSet<String>,
-        sink: &StreamSink<IndexActionState>,
-    ) -> Result<(), io::Error> {
-        let folder = folder.as_ref();
-        if scaned_folders.contains(&folder.to_string_lossy().to_string()) {
-            return Ok(());
+    /// 扫描路径为 folder 的文件夹及其所有子文件夹。
+    /// 子文件夹会被派发到 scope 所在的线程池中并行扫描，文件夹内的音乐文件也会并行读取标签。
+    ///
+    /// 文件夹按照 [FileIdentity] 去重，通过符号链接或挂载点重复访问到的不会被扫描两次，
+    /// 符号链接形成的环也不会导致无限递归。音乐文件的去重见 [ScanContext::into_sorted_result]。
+    /// 只有 [ScanRules::follow_symlinks] 为 true 时才会进入符号链接指向的文件夹，
+    /// 此时文件夹的路径为解析符号链接后的路径；指向根目录之内的符号链接会被跳过，那些文件夹从根目录扫描。
+    /// 指向文件的符号链接总是会被读取。
+    fn read_from_folder_recursively<'s>(
+        folder: PathBuf,
+        scope: &rayon::Scope<'s>,
+        ctx: &'s ScanContext,
+    ) {
+        let folder_str = folder.to_string_lossy().to_string();
+        let identity = match FileIdentity::of(&folder) {
+            Ok(val) => val,
+            Err(err) => {
+                log_to_dart(format!("{:?}: {}", folder, err));
+                ctx.finish_folder();
+                return;
+            }
+        };
+        if !ctx.lock_scaned_folders().insert(identity) {
+            // 重复的文件夹不计入进度
+            ctx.total.fetch_sub(1, Ordering::Relaxed);
+            return;
+        }
+
+        if is_index_action_cancelled() {
+            return;
+        }
+
+        if ctx.filter.is_folder_excluded(&folder, ctx.is_root(&folder)) {
+            ctx.finish_folder();
+            return;
         }
 
-        let dir = match fs::read_dir(folder) {
+        let dir = match fs::read_dir(&folder) {
             Ok(val) => val,
             Err(err) => {
                 log_to_dart(format!("{:?}: {}", folder, err));
-                return Ok(());
+                ctx.finish_folder();
+                return;
             }
         };
 
-        let _ = sink.add(IndexActionState {
-            progress: *scaned_count as f64 / *total_count as f64,
-            message: String::from("正在扫描 ") + &folder.to_string_lossy(),
-        });
-
-        scaned_folders.insert(folder.to_string_lossy().to_string());
-        let mut audios: Vec<Audio> = vec![];
-        let mut latest: u64 = 0;
+        ctx.report(String::from("正在扫描 ") + &folder_str);
 
+        let mut files: Vec<PathBuf> = vec![];
         for item in dir {
             let entry = match item {
                 Ok(value) => value,
@@ -403,115 +1010,396 @@ impl AudioFolder {
                 }
             };
 
-            if file_type.is_dir() {
-                *total_count += 1;
-                let _ = Self::read_from_folder_recursively(
-                    entry.path(),
-                    result,
-                    scaned_count,
-                    total_count,
-                    scaned_folders,
-                    sink,
-                );
-            } else if let Some(metadata) = Audio::read_from_path(entry.path()) {
-                if metadata.created > latest {
-                    latest = metadata.created;
+            let sub_folder = if file_type.is_symlink() {
+                match canonical_path(&entry.path()) {
+                    Ok(value) if value.is_dir() => {
+                        if !ctx.filter.rules().follow_symlinks || ctx.is_in_roots(&value) {
+                            continue;
+                        }
+                        Some(value)
+                    }
+                    Ok(_) => None,
+                    Err(err) => {
+                        // 指向的文件不存在
+                        log_to_dart(format!("{:?}: {}", entry.path(), err));
+                        continue;
+                    }
                 }
+            } else if file_type.is_dir() {
+                Some(entry.path())
+            } else {
+                None
+            };
 
-                audios.push(metadata);
+            match sub_folder {
+                Some(sub_folder) => {
+                    if is_index_action_cancelled() {
+                        continue;
+                    }
+                    ctx.total.fetch_add(1, Ordering::Relaxed);
+                    scope.spawn(move |scope| {
+                        Self::read_from_folder_recursively(sub_folder, scope, ctx)
+                    });
+                }
+                None => files.push(entry.path()),
             }
         }
 
+        if ctx.known_folders.contains(&folder_str) {
+            ctx.finish_folder();
+            return;
+        }
+
+        files.sort();
+        // 被 CUE 分割的整轨文件只索引分割出的虚拟音轨
+        let mut cue_sheets = read_folder_cue_sheets(&folder, ctx.filter);
+        let mut audios: Vec<Audio> = files
+            .par_iter()
+            .filter(|_| !is_index_action_cancelled())
+            .filter(|item| !cue_sheets.images.contains(*item))
+            .flat_map(|item| ctx.filter.read_audios(item))
+            .collect();
+        audios.append(&mut cue_sheets.audios);
+        let latest = audios.iter().map(|item| item.created).max().unwrap_or(0);
+
         if !audios.is_empty() {
-            if let Ok(metadata) = fs::metadata(folder) {
+            if let Ok(metadata) = fs::metadata(&folder) {
                 if let Ok(modified) = metadata.modified() {
-                    result.push(AudioFolder {
-                        path: folder.to_string_lossy().to_string(),
+                    let mut audio_folder = AudioFolder {
+                        path: folder_str,
                         modified: modified
                             .duration_since(UNIX_EPOCH)
                             .unwrap_or(Duration::ZERO)
                             .as_secs(),
                         latest,
                         audios,
-                    });
+                        cover: None,
+                        cover_placeholder: None,
+                    };
+                    ctx.filter.fill_folder_cover(&mut audio_folder);
+                    ctx.lock_result().push(audio_folder);
                 }
             }
         }
 
-        *scaned_count += 1;
-        let _ = sink.add(IndexActionState {
-            progress: *scaned_count as f64 / *total_count as f64,
-            message: String::new(),
-        });
-
-        Ok(())
+        ctx.finish_folder();
     }
 }
 
-fn _get_picture_by_windows(path: &String) -> Result<Vec<u8>, windows::core::Error> {
-    let file = StorageFile::GetFileFromPathAsync(&HSTRING::from(path))?.get()?;
-    let thumbnail = file
-        .GetThumbnailAsyncOverloadDefaultSizeDefaultOptions(ThumbnailMode::MusicView)?
-        .get()?;
-
-    let size = thumbnail.Size()? as u32;
-    let stream: IInputStream = thumbnail.cast()?;
+/// 并行扫描时各个线程共享的状态
+struct ScanContext<'a> {
+    scaned: AtomicU64,
+    total: AtomicU64,
+    scaned_folders: Mutex<HashSet<FileIdentity>>,
+    result: Mutex<Vec<AudioFolder>>,
+    filter: &'a ScanFilter,
+    /// 建立索引时传入的根目录
+    roots: &'a [String],
+    /// 实际扫描的根目录（解析后的路径），见 [effective_roots]
+    canonical_roots: Vec<PathBuf>,
+    /// 已经在索引中的文件夹，只扫描它们的子文件夹
+    known_folders: HashSet<String>,
+    sink: &'a StreamSink<IndexActionState>,
+}
 
-    let mut buffer = vec![0u8; size as usize];
-    let data_reader = DataReader::CreateDataReader(&stream)?;
-    data_reader.LoadAsync(size)?.get()?;
-    data_reader.ReadBytes(&mut buffer)?;
+impl<'a> ScanContext<'a> {
+    fn new(
+        roots: &'a [String],
+        canonical_roots: Vec<PathBuf>,
+        filter: &'a ScanFilter,
+        known_folders: HashSet<String>,
+        sink: &'a StreamSink<IndexActionState>,
+    ) -> Self {
+        ScanContext {
+            scaned: AtomicU64::new(0),
+            total: AtomicU64::new(canonical_roots.len() as u64),
+            scaned_folders: Mutex::new(HashSet::new()),
+            result: Mutex::new(vec![]),
+            filter,
+            roots,
+            canonical_roots,
+            known_folders,
+            sink,
+        }
+    }
 
-    data_reader.Close()?;
-    stream.Close()?;
+    fn is_root(&self, folder: &Path) -> bool {
+        self.roots.iter().any(|item| Path::new(item) == folder)
+    }
+
+    fn lock_scaned_folders(&self) -> MutexGuard<'_, HashSet<FileIdentity>> {
+        match self.scaned_folders.lock() {
+            Ok(val) => val,
+            Err(val) => val.into_inner(),
+        }
+    }
+
+    /// canonical 是否位于某个实际扫描的根目录之内。这样的文件夹会从根目录扫描到，不需要经由符号链接进入
+    fn is_in_roots(&self, canonical: &Path) -> bool {
+        self.canonical_roots
+            .iter()
+            .any(|root| canonical.starts_with(root))
+    }
+
+    fn lock_result(&self) -> MutexGuard<'_, Vec<AudioFolder>> {
+        match self.result.lock() {
+            Ok(val) => val,
+            Err(val) => val.into_inner(),
+        }
+    }
+
+    fn report(&self, message: String) {
+        let _ = self.sink.add(IndexActionState {
+            progress: self.progress(),
+            message,
+        });
+    }
+
+    fn finish_folder(&self) {
+        self.scaned.fetch_add(1, Ordering::Relaxed);
+        self.report(String::new());
+    }
+
+    fn progress(&self) -> f64 {
+        IndexActionState::progress_of(
+            self.scaned.load(Ordering::Relaxed) as usize,
+            self.total.load(Ordering::Relaxed) as usize,
+        )
+    }
+
+    /// 所属根目录的序号。经由符号链接进入的、不在任何根目录之内的文件夹排在最后
+    fn root_index_of(&self, folder: &AudioFolder) -> usize {
+        self.roots
+            .iter()
+            .position(|root| Path::new(&folder.path).starts_with(root))
+            .unwrap_or(usize::MAX)
+    }
+
+    /// 按根目录的传入顺序、再按路径排序，保证 index.json 中文件夹的顺序是确定的。
+    ///
+    /// 同一个音乐文件（硬链接、符号链接）出现在多个文件夹中时，只保留排序后第一个文件夹中的，
+    /// 因此结果与各个线程的扫描先后无关。同一文件夹中引用同一个文件的 CUE 音轨会被保留。
+    /// 去重后没有音乐的文件夹会被丢弃。
+    fn into_sorted_result(self) -> Vec<AudioFolder> {
+        let mut result = match self.result.lock() {
+            Ok(mut val) => std::mem::take(&mut *val),
+            Err(val) => std::mem::take(&mut *val.into_inner()),
+        };
+        result.sort_by_cached_key(|folder| (self.root_index_of(folder), folder.path.clone()));
+
+        let mut owners: HashMap<FileIdentity, String> = HashMap::new();
+        for folder in &mut result {
+            folder.audios.retain(|audio| {
+                let identity = match FileIdentity::of(Path::new(audio.file_path())) {
+                    Ok(val) => val,
+                    Err(_) => return true,
+                };
+                owners
+                    .entry(identity)
+                    .or_insert_with(|| folder.path.clone())
+                    == &folder.path
+            });
+        }
+        result.retain(|folder| !folder.audios.is_empty());
+        result
+    }
+}
+
+/// 扫描线程池的最大线程数。线程池大小取 CPU 核心数和它之间的较小值
+const MAX_SCAN_THREADS: usize = 8;
+
+fn build_scan_thread_pool() -> Result<rayon::ThreadPool, io::Error> {
+    let num_threads = std::thread::available_parallelism()
+        .map(|val| val.get())
+        .unwrap_or(4)
+        .min(MAX_SCAN_THREADS);
+
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .thread_name(|index| format!("coriander-scan-{}", index))
+        .build()
+        .map_err(io::Error::other)
+}
+
+#[cfg(windows)]
+fn _get_picture_by_windows(path: &String) -> Result<Vec<u8>, windows::core::Error> {
+    let file = StorageFile::GetFileFromPathAsync(&HSTRING::from(path))?.get()?;
+    let thumbnail = file
+        .GetThumbnailAsyncOverloadDefaultSizeDefaultOptions(ThumbnailMode::MusicView)?
+        .get()?;
+
+    let size = thumbnail.Size()? as u32;
+    let stream: IInputStream = thumbnail.cast()?;
+
+    let mut buffer = vec![0u8; size as usize];
+    let data_reader = DataReader::CreateDataReader(&stream)?;
+    data_reader.LoadAsync(size)?.get()?;
+    data_reader.ReadBytes(&mut buffer)?;
+
+    data_reader.Close()?;
+    stream.Close()?;
 
     Ok(buffer)
 }
 
-fn _get_picture_by_lofty(path: &String) -> Option<Vec<u8>> {
-    if let Ok(tagged_file) = lofty::read_from_path(&path) {
-        let tag = tagged_file
-            .primary_tag()
-            .or_else(|| tagged_file.first_tag())?;
+/// 其他平台上没有 Windows Api
+#[cfg(not(windows))]
+fn _get_picture_by_windows(_path: &String) -> Result<Vec<u8>, io::Error> {
+    Err(io::ErrorKind::Unsupported.into())
+}
 
-        return Some(tag.pictures().first()?.data().to_vec());
+/// 所有标签中的图片，主标签中的在前
+fn _embedded_pictures(tagged_file: &TaggedFile) -> Vec<&Picture> {
+    let primary_tag_type = tagged_file.primary_tag_type();
+    let mut tags: Vec<&Tag> = tagged_file.tags().iter().collect();
+    tags.sort_by_key(|tag| tag.tag_type() != primary_tag_type);
+
+    tags.into_iter().flat_map(|tag| tag.pictures()).collect()
+}
+
+/// 优先选择封面（CoverFront），没有时选择第一张图片
+fn _get_picture_by_lofty(path: &String) -> Option<Vec<u8>> {
+    if let Ok(tagged_file) = lofty::read_from_path(path) {
+        let pictures = _embedded_pictures(&tagged_file);
+        let picture = pictures
+            .iter()
+            .find(|item| item.pic_type() == PictureType::CoverFront)
+            .or_else(|| pictures.first())?;
+
+        return Some(picture.data().to_vec());
     }
 
     None
 }
 
+pub struct EmbeddedPicture {
+    /// 在 [get_embedded_pictures] 结果中的序号，用于 [get_embedded_picture]
+    pub index: u32,
+    /// 例如 "CoverFront", "CoverBack", "Artist"
+    pub picture_type: String,
+    pub mime_type: Option<String>,
+    pub description: Option<String>,
+    /// 无法解析图片时为 0
+    pub width: u32,
+    pub height: u32,
+    /// in bytes
+    pub size: u64,
+}
+
 /// for Flutter  
-/// 如果无法通过 Lofty 获取则通过 Windows 获取
-pub fn get_picture_from_path(path: String, width: u32, height: u32) -> Option<Vec<u8>> {
-    let pic_option =
-        _get_picture_by_lofty(&path).or_else(|| match _get_picture_by_windows(&path) {
+/// 列出 path 所有标签中的内嵌图片，不包含图片数据。虚拟音轨读取整轨文件
+pub fn get_embedded_pictures(path: String) -> anyhow::Result<Vec<EmbeddedPicture>> {
+    let tagged_file = lofty::read_from_path(source_file_of(&path))?;
+
+    Ok(_embedded_pictures(&tagged_file)
+        .into_iter()
+        .enumerate()
+        .map(|(index, picture)| {
+            let (width, height) = image::ImageReader::new(Cursor::new(picture.data()))
+                .with_guessed_format()
+                .ok()
+                .and_then(|reader| reader.into_dimensions().ok())
+                .unwrap_or((0, 0));
+
+            EmbeddedPicture {
+                index: index as u32,
+                picture_type: format!("{:?}", picture.pic_type()),
+                mime_type: picture.mime_type().map(|item| item.as_str().to_string()),
+                description: picture.description().map(|item| item.to_string()),
+                width,
+                height,
+                size: picture.data().len() as u64,
+            }
+        })
+        .collect())
+}
+
+/// for Flutter  
+/// 读取 [get_embedded_pictures] 中序号为 index 的图片的原始数据
+pub fn get_embedded_picture(path: String, index: u32) -> Option<Vec<u8>> {
+    let tagged_file = match lofty::read_from_path(source_file_of(&path)) {
+        Ok(val) => val,
+        Err(err) => {
+            log_to_dart(format!("fail to get pic: {}", err));
+            return None;
+        }
+    };
+
+    _embedded_pictures(&tagged_file)
+        .get(index as usize)
+        .map(|item| item.data().to_vec())
+}
+
+/// 音乐所在文件夹中的封面图片，见 [find_folder_cover]。
+/// 这里拿不到索引的扫描规则，只使用默认的封面文件名；
+/// 自定义了 [ScanRules::folder_cover_names] 时应该使用 [AudioFolder::cover]
+fn _get_picture_from_folder(path: &String) -> Option<Vec<u8>> {
+    let cover = find_folder_cover(Path::new(path).parent()?, &[])?;
+    match fs::read(&cover) {
+        Ok(val) => Some(val),
+        Err(err) => {
+            log_to_dart(format!("fail to read {:?}: {}", cover, err));
+            None
+        }
+    }
+}
+
+/// 音乐的原始封面。如果无法通过 Lofty 获取则使用文件夹中的封面图片（cover.jpg 等），再没有的话通过 Windows 获取
+pub(crate) fn get_original_picture(path: &str) -> Option<Vec<u8>> {
+    // CUE 分割出的虚拟音轨使用整轨文件的封面
+    let path = &source_file_of(path).to_string();
+    _get_picture_by_lofty(path)
+        .or_else(|| _get_picture_from_folder(path))
+        .or_else(|| match _get_picture_by_windows(path) {
             Ok(val) => Some(val),
             Err(err) => {
                 log_to_dart(format!("fail to get pic: {}", err));
                 None
             }
-        });
+        })
+}
 
-    if let Some(pic) = &pic_option {
-        if let Ok(loaded_pic) = image::load_from_memory(pic) {
-            // 计算新的宽高，保持原比例
-            let pic_ratio = loaded_pic.width() as f32 / loaded_pic.height() as f32;
+/// 保持原比例缩放图片，使其宽不超过 width 或高不超过 height
+pub(crate) fn resize_picture(pic: &DynamicImage, width: u32, height: u32) -> DynamicImage {
+    // 计算新的宽高，保持原比例
+    let pic_ratio = pic.width() as f32 / pic.height() as f32;
+
+    let (result_width, result_height) = if pic_ratio > 1.0 {
+        (width, (width as f32 / pic_ratio).round() as u32)
+    } else {
+        ((height as f32 * pic_ratio).round() as u32, height)
+    };
+
+    DynamicImage::from(imageops::resize(
+        pic,
+        result_width,
+        result_height,
+        imageops::FilterType::Triangle,
+    ))
+}
 
-            let (result_width, result_height) = if pic_ratio > 1.0 {
-                (width, (width as f32 / pic_ratio).round() as u32)
-            } else {
-                ((height as f32 * pic_ratio).round() as u32, height)
-            };
+/// for Flutter  
+/// 如果无法通过 Lofty 获取则使用文件夹中的封面图片（cover.jpg 等），再没有的话通过 Windows 获取。
+/// 启用了缩略图缓存时从缓存中读取，缓存无法使用时直接读取，见 [super::thumbnail_cache::init_thumbnail_cache]
+pub fn get_picture_from_path(path: String, width: u32, height: u32) -> Option<Vec<u8>> {
+    if is_thumbnail_cache_enabled() {
+        match get_cached_thumbnail(&path, width, height) {
+            Ok(val) => return val,
+            Err(err) => log_to_dart(format!("fail to use thumbnail cache: {}", err)),
+        }
+    }
 
-            let resized_img = imageops::resize(
-                &loaded_pic,
-                result_width,
-                result_height,
-                imageops::FilterType::Triangle,
-            );
+    let pic_option = get_original_picture(&path);
+
+    if let Some(pic) = &pic_option {
+        if let Ok(loaded_pic) = image::load_from_memory(pic) {
+            let resized_img = resize_picture(&loaded_pic, width, height);
 
             let mut output = Cursor::new(Vec::new());
-            if let Ok(_) = resized_img.write_to(&mut output, image::ImageFormat::Png) {
+            if resized_img
+                .write_to(&mut output, image::ImageFormat::Png)
+                .is_ok()
+            {
                 return Some(output.into_inner());
             }
         }
@@ -520,8 +1408,8 @@ pub fn get_picture_from_path(path: String, width: u32, height: u32) -> Option<Ve
     pic_option
 }
 
-fn _get_lyric_from_lofty(path: &String) -> Option<String> {
-    if let Ok(tagged_file) = lofty::read_from_path(&path) {
+fn _get_lyric_from_lofty(path: &str) -> Option<String> {
+    if let Ok(tagged_file) = lofty::read_from_path(path) {
         let tag = tagged_file
             .primary_tag()
             .or_else(|| tagged_file.first_tag())?;
@@ -534,7 +1422,7 @@ fn _get_lyric_from_lofty(path: &String) -> Option<String> {
     None
 }
 
-fn _get_lyric_from_lrc_file(path: &String) -> anyhow::Result<String> {
+fn _get_lyric_from_lrc_file(path: &str) -> anyhow::Result<String> {
     let mut lrc_file_path = PathBuf::from(path);
     lrc_file_path.set_extension("lrc");
 
@@ -559,136 +1447,415 @@ fn _get_lyric_from_lrc_file(path: &String) -> anyhow::Result<String> {
         return Ok(String::from_utf16(&u16_bytes)?);
     }
 
-    return Ok(String::from_utf8(lrc_bytes)?);
+    Ok(String::from_utf8(lrc_bytes)?)
 }
 
 /// for Flutter   
 /// 只支持读取 ID3V2, VorbisComment, Mp4Ilst 存储的内嵌歌词
-/// 以及相同目录相同文件名的 .lrc 外挂歌词（utf-8 or utf-16）
+/// 以及相同目录相同文件名的 .lrc 外挂歌词（utf-8 or utf-16）。
+/// 虚拟音轨使用整轨文件的歌词
 pub fn get_lyric_from_path(path: String) -> Option<String> {
-    return _get_lyric_from_lofty(&path).or_else(|| match _get_lyric_from_lrc_file(&path) {
+    let path = source_file_of(&path);
+    _get_lyric_from_lofty(path).or_else(|| match _get_lyric_from_lrc_file(path) {
         Ok(val) => Some(val),
         Err(err) => {
-            log_to_dart(format!("fail to get lrc: {}", err.to_string()));
+            log_to_dart(format!("fail to get lrc: {}", err));
             None
         }
-    });
+    })
 }
 
 /// for Flutter  
 /// 扫描给定路径下所有子文件夹（包括自己）的音乐文件并把索引保存在 index_path/index.json。
+/// 文件夹的遍历和标签的读取在有界线程池中并行进行。
+///
+/// 使用和原来的索引保存在一起的扫描规则和 cover_placeholders，见 [build_index_with_rules]。
+///
+/// 可以通过 [cancel_index_action] 取消，取消后不会写入 index.json，原来的索引保持不变。
+///
+/// index_path 下存在 index.db 时，索引改为写入 index.db，index.json 不会被修改，见 [migrate_index_to_database]。
 pub fn build_index_from_folders_recursively(
     folders: Vec<String>,
     index_path: String,
     sink: StreamSink<IndexActionState>,
 ) -> Result<(), io::Error> {
-    let mut audio_folders: Vec<AudioFolder> = vec![];
-    let mut scaned: u64 = 0;
-    let mut total: u64 = folders.len() as u64;
-    let mut scaned_folders: HashSet<String> = HashSet::new();
-
-    for item in &folders {
-        let _ = AudioFolder::read_from_folder_recursively(
-            Path::new(item),
-            &mut audio_folders,
-            &mut scaned,
-            &mut total,
-            &mut scaned_folders,
-            &sink,
-        );
-    }
+    let _action = IndexAction::begin();
+    let index_dir = PathBuf::from(index_path);
+    let filter = read_stored_scan_filter(&index_dir).map_err(io::Error::other)?;
 
-    let mut audio_folders_json: Vec<serde_json::Value> = vec![];
-    for item in &audio_folders {
-        audio_folders_json.push(item.to_json_value());
+    _build_index(folders, &index_dir, filter, &sink).map_err(io::Error::other)
+}
+
+/// for Flutter  
+/// 和 [build_index_from_folders_recursively] 相同，但是使用给定的扫描规则。
+///
+/// cover_placeholders 为 true 时计算每首音乐和每个文件夹的封面的 BlurHash，
+/// 见 [Audio::cover_placeholder] 和 [AudioFolder::cover_placeholder]。
+/// 需要解码每首音乐的封面，会让建立索引变慢。
+///
+/// 规则和 cover_placeholders 会和索引保存在一起，之后的 [update_index] 也会使用它们。
+///
+/// 规则中的 glob 或正则表达式不合法时返回 Err，原来的索引保持不变。
+pub fn build_index_with_rules(
+    folders: Vec<String>,
+    index_path: String,
+    rules: ScanRules,
+    cover_placeholders: bool,
+    sink: StreamSink<IndexActionState>,
+) -> anyhow::Result<()> {
+    let _action = IndexAction::begin();
+    let filter = ScanFilter::new(rules)?.with_cover_placeholders(cover_placeholders);
+
+    _build_index(folders, Path::new(&index_path), filter, &sink)
+}
+
+/// 调用者需要持有 [IndexAction]
+fn _build_index(
+    folders: Vec<String>,
+    index_dir: &Path,
+    filter: ScanFilter,
+    sink: &StreamSink<IndexActionState>,
+) -> anyhow::Result<()> {
+    let scaned = match _scan_folders(&folders, &filter, HashSet::new(), sink)? {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+
+    if IndexDatabase::exists_in(index_dir) {
+        IndexDatabase::open_in(index_dir)?.replace_all(
+            &scaned,
+            &folders,
+            filter.rules(),
+            filter.cover_placeholders(),
+        )?;
+    } else {
+        AudioIndex {
+            version: INDEX_VERSION,
+            folders: scaned,
+            roots: folders,
+            rules: filter.rules().clone(),
+            cover_placeholders: filter.cover_placeholders(),
+        }
+        .write(&index_dir.join("index.json"))?;
     }
-    let json_value = serde_json::json!({
-        "version": 110,
-        "folders": audio_folders_json,
+
+    refresh_library_watch(index_dir);
+    Ok(())
+}
+
+/// 在扫描开始前确定每个文件夹归哪个根目录扫描：按解析后的路径比较，
+/// 去掉重复的根目录以及位于其他根目录之内的根目录。返回 (根目录, 解析后的路径)，保持传入顺序。
+/// 无法解析的根目录原样保留，扫描时再报告错误
+fn effective_roots(roots: &[String]) -> Vec<(&String, PathBuf)> {
+    let resolved: Vec<_> = roots
+        .iter()
+        .map(|item| {
+            let path = Path::new(item);
+            (
+                item,
+                canonical_path(path).unwrap_or_else(|_| path.to_path_buf()),
+            )
+        })
+        .collect();
+
+    resolved
+        .iter()
+        .enumerate()
+        .filter(|(index, (_, canonical))| {
+            !resolved
+                .iter()
+                .enumerate()
+                .any(|(other_index, (_, other))| {
+                    if other == canonical {
+                        other_index < *index
+                    } else {
+                        canonical.starts_with(other)
+                    }
+                })
+        })
+        .map(|(_, item)| item.clone())
+        .collect()
+}
+
+/// 在有界线程池中并行扫描 roots 及其所有子文件夹，跳过 known_folders 中的文件夹（但会进入它们的子文件夹）。
+/// 被取消时通知 sink 并返回 None
+fn _scan_folders(
+    roots: &[String],
+    filter: &ScanFilter,
+    known_folders: HashSet<String>,
+    sink: &StreamSink<IndexActionState>,
+) -> Result<Option<Vec<AudioFolder>>, io::Error> {
+    let (scan_roots, canonical_roots): (Vec<_>, Vec<_>) =
+        effective_roots(roots).into_iter().unzip();
+    let ctx = ScanContext::new(roots, canonical_roots, filter, known_folders, sink);
+
+    build_scan_thread_pool()?.scope(|scope| {
+        for item in scan_roots {
+            let ctx = &ctx;
+            let folder = PathBuf::from(item);
+            scope.spawn(move |scope| AudioFolder::read_from_folder_recursively(folder, scope, ctx));
+        }
     });
 
-    let mut index_path = PathBuf::from(index_path);
-    index_path.push("index.json");
-    fs::File::create(index_path)?.write_all(json_value.to_string().as_bytes())?;
+    if is_index_action_cancelled() {
+        report_index_action_cancelled(sink, ctx.progress());
+        return Ok(None);
+    }
 
-    Ok(())
+    Ok(Some(ctx.into_sorted_result()))
+}
+
+/// 当前 index.json 的版本
+pub(crate) const INDEX_VERSION: u64 = 111;
+
+/// index.json 的结构：
+/// ```json
+/// {
+///     "version": 111,
+///     "folders": [
+///         {
+///             "path": ..., "modified": ..., "latest": ..., "audios": [{...}, ...],
+///             "cover": ..., "cover_placeholder": ...
+///         },
+///         ...
+///     ],
+///     "roots": [...],
+///     "rules": { "exclude_globs": [...], ... },
+///     "cover_placeholders": false
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct AudioIndex {
+    pub(crate) version: u64,
+    pub(crate) folders: Vec<AudioFolder>,
+    /// 建立索引时传入的音乐库根目录。更新索引时会在其中寻找新的文件夹
+    #[serde(default)]
+    pub(crate) roots: Vec<String>,
+    /// 建立索引时使用的扫描规则，更新索引时沿用
+    #[serde(default)]
+    pub(crate) rules: ScanRules,
+    /// 建立索引时是否计算封面占位图，更新索引时沿用
+    #[serde(default)]
+    pub(crate) cover_placeholders: bool,
 }
 
-fn _update_index_below_1_1_0(
-    index: &serde_json::Value,
-    index_path: &PathBuf,
+/// 把 index 从某个版本迁移到更高的版本。返回的 index 必须带有迁移后的 version
+type IndexMigration =
+    fn(serde_json::Value, &StreamSink<IndexActionState>) -> anyhow::Result<serde_json::Value>;
+
+/// 迁移链，(from_version, migration)。
+/// 读取 index 时会从它的版本开始依次迁移，直到版本等于 [INDEX_VERSION]
+const INDEX_MIGRATIONS: &[(u64, IndexMigration)] =
+    &[(0, _migrate_index_from_0), (110, _migrate_index_from_110)];
+
+impl AudioIndex {
+    /// 读取 index.json，必要时迁移到 [INDEX_VERSION]。
+    /// index.json 不合法时返回说明原因的 Err；迁移被取消时返回 None
+    pub(crate) fn read(
+        index_path: &Path,
+        sink: &StreamSink<IndexActionState>,
+    ) -> anyhow::Result<Option<Self>> {
+        let bytes = fs::read(index_path).with_context(|| format!("无法读取 {:?}", index_path))?;
+        let mut value: serde_json::Value =
+            serde_json::from_slice(&bytes).context("index.json 不是合法的 JSON")?;
+
+        let mut version = Self::version_of(&value)?;
+        while version != INDEX_VERSION {
+            if version > INDEX_VERSION {
+                bail!(
+                    "index.json 的版本 {} 高于支持的版本 {}",
+                    version,
+                    INDEX_VERSION
+                );
+            }
+
+            let migration = match INDEX_MIGRATIONS.iter().find(|(from, _)| *from == version) {
+                Some((_, migration)) => migration,
+                None => bail!("不支持迁移版本为 {} 的 index.json", version),
+            };
+            value = migration(value, sink)?;
+            if is_index_action_cancelled() {
+                return Ok(None);
+            }
+
+            let migrated_version = Self::version_of(&value)?;
+            if migrated_version <= version {
+                bail!("index.json 从版本 {} 迁移失败", version);
+            }
+            version = migrated_version;
+        }
+
+        let index = serde_json::from_value(value).context("index.json 格式错误")?;
+        Ok(Some(index))
+    }
+
+    /// 读取 index.json，不进行迁移。版本不是 [INDEX_VERSION] 时返回 Err
+    pub(crate) fn read_current(index_path: &Path) -> anyhow::Result<Self> {
+        let bytes = fs::read(index_path).with_context(|| format!("无法读取 {:?}", index_path))?;
+        let value: serde_json::Value =
+            serde_json::from_slice(&bytes).context("index.json 不是合法的 JSON")?;
+
+        let version = Self::version_of(&value)?;
+        if version != INDEX_VERSION {
+            bail!("index.json 的版本 {} 需要先通过 update_index 迁移", version);
+        }
+
+        serde_json::from_value(value).context("index.json 格式错误")
+    }
+
+    /// 1.1.0 之前的 index 是没有 version 的文件夹数组，视为版本 0
+    fn version_of(value: &serde_json::Value) -> anyhow::Result<u64> {
+        if value.is_array() {
+            return Ok(0);
+        }
+
+        match value.get("version") {
+            Some(version) => version
+                .as_u64()
+                .ok_or_else(|| anyhow!("index.json 的 version 不是非负整数：{}", version)),
+            None => bail!("index.json 缺少 version"),
+        }
+    }
+
+    /// 先写入临时文件再替换 index.json，避免中途失败时破坏原来的索引
+    pub(crate) fn write(&self, index_path: &Path) -> Result<(), io::Error> {
+        let tmp_path = index_path.with_extension("json.tmp");
+        fs::File::create(&tmp_path)?.write_all(&serde_json::to_vec(self)?)?;
+        fs::rename(tmp_path, index_path)
+    }
+}
+
+/// 1.1.0 之前的 index 缺少现在需要的字段，只能根据其中记录的路径重新扫描这些文件夹。
+/// 重新扫描得到的记录已经带有 111 新增的字段，因此直接迁移到 111，不再经过 [_migrate_index_from_110]
+fn _migrate_index_from_0(
+    value: serde_json::Value,
     sink: &StreamSink<IndexActionState>,
-) -> Result<(), io::Error> {
-    let mut audio_folders_json: Vec<serde_json::Value> = vec![];
-    let folders = index.as_array().unwrap();
-    for item in folders {
-        let path = item["path"].as_str().unwrap();
+) -> anyhow::Result<serde_json::Value> {
+    #[derive(Deserialize)]
+    struct LegacyAudioFolder {
+        path: String,
+    }
+
+    let legacy_folders: Vec<LegacyAudioFolder> =
+        serde_json::from_value(value).context("index.json 格式错误")?;
+    let total = legacy_folders.len();
+
+    let mut folders: Vec<AudioFolder> = vec![];
+    for (scaned, item) in legacy_folders.iter().enumerate() {
+        if is_index_action_cancelled() {
+            break;
+        }
+
         let _ = sink.add(IndexActionState {
-            progress: audio_folders_json.len() as f64 / folders.len() as f64,
-            message: String::from("正在扫描 ") + path,
+            progress: IndexActionState::progress_of(scaned, total),
+            message: String::from("正在扫描 ") + &item.path,
         });
-        let folder_path = Path::new(path);
-        if let Ok(audio_folder) = AudioFolder::read_from_folder(folder_path) {
-            audio_folders_json.push(audio_folder.to_json_value());
-            let _ = sink.add(IndexActionState {
-                progress: audio_folders_json.len() as f64 / folders.len() as f64,
-                message: String::new(),
-            });
+        if let Ok(audio_folder) = AudioFolder::read_from_folder(&item.path) {
+            folders.push(audio_folder);
         }
+        let _ = sink.add(IndexActionState {
+            progress: IndexActionState::progress_of(scaned + 1, total),
+            message: String::new(),
+        });
     }
-    fs::File::create(index_path)?.write_all(
-        serde_json::json!({
-            "version": 110,
-            "folders": audio_folders_json,
-        })
-        .to_string()
-        .as_bytes(),
-    )?;
 
-    Ok(())
+    Ok(serde_json::to_value(AudioIndex {
+        version: 111,
+        folders,
+        roots: vec![],
+        rules: ScanRules::default(),
+        cover_placeholders: false,
+    })?)
+}
+
+/// 111 给每首音乐加上了 size 和 id，用来识别被移动或重命名的音乐；给每个文件夹加上了 cover
+fn _migrate_index_from_110(
+    value: serde_json::Value,
+    _sink: &StreamSink<IndexActionState>,
+) -> anyhow::Result<serde_json::Value> {
+    let mut index: AudioIndex = serde_json::from_value(value).context("index.json 格式错误")?;
+    let filter = ScanFilter::new(index.rules.clone())?;
+    for folder in &mut index.folders {
+        for audio in &mut folder.audios {
+            audio.fill_identity();
+        }
+        folder.cover = filter.folder_cover_of(Path::new(&folder.path));
+    }
+    index.version = 111;
+
+    Ok(serde_json::to_value(index)?)
 }
 
 /// for Flutter   
 /// 读取 index_path/index.json，检查更新。不可能重新读取被修改的文件夹下所有的音乐标签，这样太耗时。  
 ///
-/// [LOWEST_VERSION] 指定可以继承的 index 的最低版本。
-/// 如果 index version < [LOWEST_VERSION] 或者是 index 根本没有 version 再或者格式不符合要求，就转到
-/// [_update_index_below_1_1_0] 更新 index；
-/// 如果 index version >= [LOWEST_VERSION] 则进行更新。
+/// index.json 会先按照 [INDEX_MIGRATIONS] 迁移到 [INDEX_VERSION]。
+/// index.json 不合法（无法解析、缺少字段或者版本不受支持）时返回 Err，不会修改原来的索引。
 ///
 /// 如果文件夹不存在，删除记录。  
 /// 如果文件夹被修改（再次读取到的 modified > 记录的 modified），就更新它。没有则跳过它
 /// 1. 遍历该文件夹索引，判断文件是否存在，不存在则删除记录
 /// 2. 遍历该文件夹索引，如果文件被修改（再次读取到的 modified > 记录的 modified），重新读取标签；没有则跳过它
-/// 3. 遍历该文件夹，添加新增（读取到的 created > 记录的 latest）的音乐文件
+/// 3. 遍历该文件夹，添加新增（路径不在索引中）并且满足扫描规则的音乐文件
+///
+/// 之后扫描建立索引时传入的根目录，添加其中新增的文件夹。已经不存在的根目录及其下所有文件夹的记录会被删除。
+/// 旧版本建立的索引没有保存根目录，不会寻找新增的文件夹。
+///
+/// 被删除的音乐和新增的音乐 [Audio::id] 相同时，视为被移动或重命名，可以通过 [take_index_relocations] 取得。
+///
+/// 可以通过 [cancel_index_action] 取消。取消时正在更新的文件夹保持原样，
+/// 已经更新完的文件夹会被写入 index.json。
+///
+/// index_path 下存在 index.db 时改为更新 index.db，每个文件夹更新完后立即写入，index.json 不会被修改。
 pub fn update_index(index_path: String, sink: StreamSink<IndexActionState>) -> anyhow::Result<()> {
-    let mut index_path = PathBuf::from(index_path);
-    index_path.push("index.json");
-    let index = fs::read(&index_path)?;
-    let mut index: serde_json::Value = serde_json::from_slice(&index)?;
-
-    let version = index["version"].as_u64();
-    if version.is_none() {
-        return Ok(_update_index_below_1_1_0(&index, &index_path, &sink)?);
+    let _action = IndexAction::begin();
+    let index_dir = PathBuf::from(index_path);
+    if IndexDatabase::exists_in(&index_dir) {
+        _update_index_database(&index_dir, &sink)?;
+        refresh_library_watch(&index_dir);
+        return Ok(());
     }
 
-    let folders = index["folders"].as_array_mut().unwrap();
-    // 删除访问不到的文件夹的记录
-    folders.retain(|item| {
-        let path = item["path"].as_str().unwrap();
+    let index_path = index_dir.join("index.json");
 
-        Path::new(path).exists()
-    });
+    let mut index = match AudioIndex::read(&index_path, &sink)? {
+        Some(value) => value,
+        None => {
+            // 迁移被取消，保留原来的索引
+            report_index_action_cancelled(&sink, 0.0);
+            return Ok(());
+        }
+    };
+
+    let filter =
+        ScanFilter::new(index.rules.clone())?.with_cover_placeholders(index.cover_placeholders);
+    let mut changes = IndexChanges::default();
+
+    // 删除已经不存在的根目录
+    let (roots, removed_roots): (Vec<String>, Vec<String>) = std::mem::take(&mut index.roots)
+        .into_iter()
+        .partition(|item| Path::new(item).exists());
+    index.roots = roots;
+
+    // 删除访问不到的文件夹和不存在的根目录下的文件夹的记录
+    let (folders, removed_folders): (Vec<AudioFolder>, Vec<AudioFolder>) =
+        std::mem::take(&mut index.folders)
+            .into_iter()
+            .partition(|item| !_is_folder_removed(&item.path, &removed_roots));
+    index.folders = folders;
+    for folder in removed_folders {
+        changes.removed.extend(folder.audios);
+    }
 
     let mut updated = 0;
-    let total = folders.len();
+    let total = index.folders.len();
 
-    for folder_item in folders {
-        let folder_path = folder_item["path"].as_str().unwrap().to_string();
-        let latest = folder_item["latest"].as_u64().unwrap();
-        let old_folder_modified = folder_item["modified"].as_u64().unwrap();
+    for folder in &mut index.folders {
+        if is_index_action_cancelled() {
+            break;
+        }
 
-        let new_folder_modified = match fs::metadata(&folder_path) {
+        let new_folder_modified = match fs::metadata(&folder.path) {
             Ok(value) => match value.modified() {
                 Ok(value) => value
                     .duration_since(UNIX_EPOCH)
@@ -700,100 +1867,401 @@ pub fn update_index(index_path: String, sink: StreamSink<IndexActionState>) -> a
         };
 
         // 跳过没有被修改的文件夹
-        if new_folder_modified <= old_folder_modified {
+        if new_folder_modified <= folder.modified {
             updated += 1;
             continue;
         }
 
         let _ = sink.add(IndexActionState {
-            progress: updated as f64 / total as f64,
-            message: String::from("正在更新 ") + &folder_path,
+            progress: IndexActionState::progress_of(updated, total),
+            message: String::from("正在更新 ") + &folder.path,
         });
 
-        folder_item["modified"] = serde_json::json!(new_folder_modified);
-
-        // 删除访问不到的文件的记录
-        let audios = folder_item["audios"].as_array_mut().unwrap();
-        audios.retain(|item| {
-            let path = item["path"].as_str().unwrap();
+        // 被取消时恢复没有更新完的文件夹
+        let folder_backup = folder.clone();
+        if !folder.update(new_folder_modified, &filter, &mut changes) {
+            *folder = folder_backup;
+            break;
+        }
 
-            Path::new(path).exists()
+        updated += 1;
+        let _ = sink.add(IndexActionState {
+            progress: IndexActionState::progress_of(updated, total),
+            message: String::new(),
         });
+    }
 
-        for audio_item in &mut *audios {
-            let old_audio_modified = audio_item["modified"].as_u64().unwrap();
-            let audio_path = audio_item["path"].as_str().unwrap();
-            let new_audio_modified = match fs::metadata(&audio_path) {
-                Ok(value) => match value.modified() {
-                    Ok(value) => value
-                        .duration_since(UNIX_EPOCH)
-                        .unwrap_or(Duration::ZERO)
-                        .as_secs(),
-                    Err(_) => continue,
-                },
-                Err(_) => continue,
-            };
-            // 跳过没有被修改的文件
-            if new_audio_modified <= old_audio_modified {
-                continue;
+    if is_index_action_cancelled() {
+        report_index_action_cancelled(&sink, IndexActionState::progress_of(updated, total));
+    } else {
+        // 在根目录下寻找新的文件夹
+        let known_folders = index.folders.iter().map(|item| item.path.clone()).collect();
+        if let Some(new_folders) = _scan_folders(&index.roots, &filter, known_folders, &sink)? {
+            for folder in &new_folders {
+                changes.added.extend(folder.audios.iter().cloned());
             }
+            index.folders.extend(new_folders);
+        }
+    }
 
-            // 重新读取被修改的音乐文件的标签并更新
-            if let Some(modified_audio) = Audio::read_from_path(Path::new(audio_path)) {
-                *audio_item = modified_audio.to_json_value();
+    changes.save_relocations();
+    index.write(&index_path)?;
+    refresh_library_watch(&index_dir);
+    Ok(())
+}
+
+/// 文件夹不存在，或者位于已经不存在的根目录下
+fn _is_folder_removed(folder_path: &str, removed_roots: &[String]) -> bool {
+    let path = Path::new(folder_path);
+    !path.exists() || removed_roots.iter().any(|root| path.starts_with(root))
+}
+
+/// 和 [update_index] 相同，但是读写 index.db。只会读取需要检查的文件夹，
+/// 更新时也只写入发生变化的音乐
+fn _update_index_database(
+    index_dir: &Path,
+    sink: &StreamSink<IndexActionState>,
+) -> anyhow::Result<()> {
+    let db = IndexDatabase::open_in(index_dir)?;
+
+    let filter = db.scan_filter()?;
+    let mut changes = IndexChanges::default();
+
+    // 删除已经不存在的根目录
+    let (roots, removed_roots): (Vec<String>, Vec<String>) = db
+        .roots()?
+        .into_iter()
+        .partition(|item| Path::new(item).exists());
+    if !removed_roots.is_empty() {
+        db.set_roots(&roots)?;
+    }
+
+    // 删除访问不到的文件夹和不存在的根目录下的文件夹的记录
+    let mut folder_records = db.folder_records()?;
+    for (path, _) in &folder_records {
+        if _is_folder_removed(path, &removed_roots) {
+            if let Some(folder) = db.folder(path)? {
+                changes.removed.extend(folder.audios);
             }
+            db.remove_folder(path)?;
         }
+    }
+    folder_records.retain(|(path, _)| !_is_folder_removed(path, &removed_roots));
+    let known_folders: HashSet<String> = folder_records
+        .iter()
+        .map(|(path, _)| path.clone())
+        .collect();
 
-        // 添加新增的音乐文件
-        let mut new_latest: u64 = latest;
-        let dir = match fs::read_dir(folder_path) {
-            Ok(value) => value,
-            Err(_) => continue,
-        };
-        for entry in dir {
-            let entry = match entry {
-                Ok(value) => value,
-                Err(_) => continue,
-            };
-            let file_type = match entry.file_type() {
-                Ok(value) => value,
-                Err(_) => continue,
-            };
-            if file_type.is_dir() {
-                continue;
-            }
+    let mut updated = 0;
+    let total = folder_records.len();
 
-            let entry_created = match entry.metadata() {
-                Ok(value) => match value.created() {
-                    Ok(value) => value
-                        .duration_since(UNIX_EPOCH)
-                        .unwrap_or(Duration::ZERO)
-                        .as_secs(),
-                    Err(_) => continue,
-                },
+    for (folder_path, old_folder_modified) in folder_records {
+        if is_index_action_cancelled() {
+            break;
+        }
+
+        let new_folder_modified = match fs::metadata(&folder_path) {
+            Ok(value) => match value.modified() {
+                Ok(value) => value
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or(Duration::ZERO)
+                    .as_secs(),
                 Err(_) => continue,
-            };
-            if entry_created > latest {
-                if let Some(new_audio) = Audio::read_from_path(entry.path()) {
-                    if entry_created > new_latest {
-                        new_latest = entry_created;
-                    }
+            },
+            Err(_) => continue,
+        };
 
-                    audios.push(new_audio.to_json_value());
-                }
-            }
+        // 跳过没有被修改的文件夹
+        if new_folder_modified <= old_folder_modified {
+            updated += 1;
+            continue;
         }
 
-        folder_item["latest"] = serde_json::json!(new_latest);
+        let _ = sink.add(IndexActionState {
+            progress: IndexActionState::progress_of(updated, total),
+            message: String::from("正在更新 ") + &folder_path,
+        });
+
+        let old_folder = match db.folder(&folder_path)? {
+            Some(value) => value,
+            None => continue,
+        };
+        let mut folder = old_folder.clone();
+        // 被取消时丢弃没有更新完的文件夹
+        if !folder.update(new_folder_modified, &filter, &mut changes) {
+            break;
+        }
+        db.update_folder(&old_folder, &folder)?;
 
         updated += 1;
         let _ = sink.add(IndexActionState {
-            progress: updated as f64 / total as f64,
+            progress: IndexActionState::progress_of(updated, total),
             message: String::new(),
         });
     }
 
-    fs::File::create(index_path)?.write_all(index.to_string().as_bytes())?;
+    if is_index_action_cancelled() {
+        report_index_action_cancelled(sink, IndexActionState::progress_of(updated, total));
+    } else if let Some(new_folders) = _scan_folders(&roots, &filter, known_folders, sink)? {
+        // 在根目录下寻找新的文件夹
+        for folder in &new_folders {
+            db.insert_folder(folder)?;
+            changes.added.extend(folder.audios.iter().cloned());
+        }
+    }
 
+    changes.save_relocations();
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "coriander_tag_reader_{}_{}",
+            std::process::id(),
+            name
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn _folder(path: &Path, files: &[&Path]) -> AudioFolder {
+        AudioFolder {
+            path: path.to_string_lossy().to_string(),
+            modified: 0,
+            latest: 0,
+            audios: files
+                .iter()
+                .map(|file| Audio {
+                    path: file.to_string_lossy().to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            cover: None,
+            cover_placeholder: None,
+        }
+    }
+
+    #[test]
+    fn progress_of_empty_total_is_complete() {
+        assert_eq!(IndexActionState::progress_of(0, 0), 1.0);
+        assert_eq!(IndexActionState::progress_of(1, 4), 0.25);
+    }
+
+    #[test]
+    fn effective_roots_drop_nested_and_duplicate_roots() {
+        let dir = _temp_dir("roots");
+        let outer = dir.join("outer");
+        let inner = outer.join("inner");
+        let other = dir.join("other");
+        fs::create_dir_all(&inner).unwrap();
+        fs::create_dir_all(&other).unwrap();
+
+        let roots: Vec<String> = [&inner, &outer, &other, &outer]
+            .iter()
+            .map(|item| item.to_string_lossy().to_string())
+            .collect();
+        let effective: Vec<_> = effective_roots(&roots)
+            .into_iter()
+            .map(|(root, _)| root.clone())
+            .collect();
+        assert_eq!(effective, vec![roots[1].clone(), roots[2].clone()]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn hard_linked_file_belongs_to_first_root() {
+        let dir = _temp_dir("hard_link");
+        let first = dir.join("first");
+        let second = dir.join("second");
+        fs::create_dir_all(&first).unwrap();
+        fs::create_dir_all(&second).unwrap();
+        let first_file = first.join("a.flac");
+        let second_file = second.join("a.flac");
+        let other_file = second.join("b.flac");
+        fs::write(&first_file, b"audio").unwrap();
+        fs::hard_link(&first_file, &second_file).unwrap();
+        fs::write(&other_file, b"other").unwrap();
+
+        let roots = vec![
+            first.to_string_lossy().to_string(),
+            second.to_string_lossy().to_string(),
+        ];
+        let canonical_roots = effective_roots(&roots)
+            .into_iter()
+            .map(|(_, canonical)| canonical)
+            .collect();
+        let filter = ScanFilter::new(ScanRules::default()).unwrap();
+        let sink = StreamSink::<IndexActionState>::deserialize("0".to_string());
+        let ctx = ScanContext::new(&roots, canonical_roots, &filter, HashSet::new(), &sink);
+        // 模拟 second 先被扫描完
+        ctx.lock_result()
+            .push(_folder(&second, &[&second_file, &other_file]));
+        ctx.lock_result().push(_folder(&first, &[&first_file]));
+
+        let result = ctx.into_sorted_result();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].path, roots[0]);
+        assert_eq!(result[0].audios.len(), 1);
+        assert_eq!(result[1].path, roots[1]);
+        let second_paths: Vec<_> = result[1].audios.iter().map(|item| &item.path).collect();
+        assert_eq!(
+            second_paths,
+            vec![&other_file.to_string_lossy().to_string()]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn _sink() -> StreamSink<IndexActionState> {
+        StreamSink::<IndexActionState>::deserialize("0".to_string())
+    }
+
+    #[test]
+    fn read_index_rejects_truncated_file() {
+        let dir = _temp_dir("truncated");
+        let index_path = dir.join("index.json");
+        fs::write(&index_path, br#"{"version": 111, "folders": [{"path": "#).unwrap();
+
+        assert!(AudioIndex::read(&index_path, &_sink()).is_err());
+        // 不合法的 index.json 不会被改写
+        assert_eq!(
+            fs::read(&index_path).unwrap(),
+            br#"{"version": 111, "folders": [{"path": "#
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn read_index_rejects_unknown_versions() {
+        let dir = _temp_dir("version");
+        let index_path = dir.join("index.json");
+
+        for content in [
+            r#"{"version": 112, "folders": []}"#,
+            r#"{"version": 50, "folders": []}"#,
+            r#"{"version": "111", "folders": []}"#,
+            r#"{"folders": []}"#,
+        ] {
+            fs::write(&index_path, content).unwrap();
+            assert!(
+                AudioIndex::read(&index_path, &_sink()).is_err(),
+                "{}",
+                content
+            );
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn read_index_migrates_from_0() {
+        let dir = _temp_dir("from_0");
+        let music = dir.join("music");
+        fs::create_dir_all(&music).unwrap();
+        fs::write(music.join("a.mp3"), b"not really mp3").unwrap();
+        fs::write(music.join("cover.jpg"), b"").unwrap();
+        let index_path = dir.join("index.json");
+        fs::write(
+            &index_path,
+            serde_json::to_vec(&serde_json::json!([
+                { "path": music.to_string_lossy(), "modified": 0, "latest": 0, "audios": [] },
+                { "path": dir.join("missing").to_string_lossy(), "modified": 0, "latest": 0, "audios": [] },
+            ]))
+            .unwrap(),
+        )
+        .unwrap();
+
+        let index = AudioIndex::read(&index_path, &_sink()).unwrap().unwrap();
+        assert_eq!(index.version, INDEX_VERSION);
+        assert_eq!(index.folders.len(), 1);
+        let folder = &index.folders[0];
+        assert_eq!(folder.path, music.to_string_lossy());
+        assert_eq!(
+            folder.cover.as_deref(),
+            Some(&*music.join("cover.jpg").to_string_lossy())
+        );
+        assert_eq!(folder.audios.len(), 1);
+        assert_eq!(folder.audios[0].size, 14);
+        assert_eq!(folder.audios[0].id, folder.audios[0].compute_id());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn read_index_migrates_from_110() {
+        let dir = _temp_dir("from_110");
+        let music = dir.join("music");
+        fs::create_dir_all(&music).unwrap();
+        let file = music.join("a.flac");
+        fs::write(&file, b"flac").unwrap();
+        fs::write(music.join("folder.png"), b"").unwrap();
+        let index_path = dir.join("index.json");
+        fs::write(
+            &index_path,
+            serde_json::to_vec(&serde_json::json!({
+                "version": 110,
+                "folders": [{
+                    "path": music.to_string_lossy(),
+                    "modified": 1,
+                    "latest": 2,
+                    "audios": [{
+                        "title": "Title", "artist": "Artist", "album": "Album", "track": 1,
+                        "duration": 60, "bitrate": null, "sample_rate": null,
+                        "path": file.to_string_lossy(), "modified": 1, "created": 2, "by": null
+                    }]
+                }],
+                "roots": [dir.to_string_lossy()]
+            }))
+            .unwrap(),
+        )
+        .unwrap();
+
+        let index = AudioIndex::read(&index_path, &_sink()).unwrap().unwrap();
+        assert_eq!(index.version, INDEX_VERSION);
+        assert_eq!(index.roots, vec![dir.to_string_lossy().to_string()]);
+        let folder = &index.folders[0];
+        assert_eq!(folder.modified, 1);
+        assert_eq!(
+            folder.cover.as_deref(),
+            Some(&*music.join("folder.png").to_string_lossy())
+        );
+        let audio = &folder.audios[0];
+        assert_eq!(audio.title, "Title");
+        assert_eq!(audio.size, 4);
+        assert!(!audio.id.is_empty());
+        assert_eq!(audio.id, audio.compute_id());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn renaming_untagged_file_keeps_id() {
+        let dir = _temp_dir("rename");
+        let from = dir.join("untagged.mp3");
+        let to = dir.join("renamed.mp3");
+        fs::write(&from, b"not really mp3").unwrap();
+
+        let before = Audio::read_from_path(&from).unwrap();
+        assert_eq!(before.title, "untagged.mp3");
+        fs::rename(&from, &to).unwrap();
+        let after = Audio::read_from_path(&to).unwrap();
+        assert_eq!(after.title, "renamed.mp3");
+        assert_eq!(before.id, after.id);
+
+        // 标题来自标签时仍然参与计算
+        let tagged = Audio {
+            title: "Title".to_string(),
+            ..after.clone()
+        };
+        assert_ne!(tagged.compute_id(), after.id);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,433 @@
+// 通过 lofty 修改音乐标签和内嵌封面。
+
+use std::{
+    fs,
+    io::Cursor,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail};
+use image::{codecs::jpeg::JpegEncoder, imageops, ImageFormat};
+use lofty::{
+    config::WriteOptions,
+    file::TaggedFile,
+    picture::{MimeType, Picture, PictureType},
+    prelude::{Accessor, AudioFile, ItemKey, TaggedFileExt},
+    tag::Tag,
+};
+
+use super::{
+    cover_palette::invalidate_cover_palettes,
+    cue_sheet::source_file_of,
+    logger::log_to_dart,
+    tag_reader::{Audio, SUPPORT_FORMAT},
+};
+
+/// 要修改的字段。None 表示不修改；空字符串或 0 表示删除这个字段
+#[derive(Debug, Clone, Default)]
+pub struct AudioTagChanges {
+    pub title: Option<String>,
+    /// 多个艺术家以 "/" 分隔，和读取时相同
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub album_artist: Option<String>,
+    pub track: Option<u32>,
+    pub disc: Option<u32>,
+    pub year: Option<u32>,
+    pub genre: Option<String>,
+}
+
+/// for Flutter  
+/// 把 changes 写入 path 的主标签（格式由文件类型决定，例如 MP3 为 ID3v2，FLAC 为 Vorbis Comments），
+/// 没有主标签时创建一个。没有修改的字段和其他标签保持不变。
+///
+/// 返回重新读取的音乐，可以直接替换索引中的记录。
+///
+/// CUE 分割出的虚拟音轨（path#NN）的标签来自 CUE，不能通过这里修改，返回 Err
+pub fn write_audio_tags(path: String, changes: AudioTagChanges) -> anyhow::Result<Audio> {
+    if source_file_of(&path) != path {
+        bail!("不能修改 CUE 虚拟音轨的标签: {}", path);
+    }
+
+    let mut tagged_file = lofty::read_from_path(&path)?;
+    let tag = primary_tag_mut(&mut tagged_file)?;
+
+    if let Some(title) = changes.title {
+        _set_or_remove(tag, title, Tag::set_title, Tag::remove_title);
+    }
+    if let Some(artist) = changes.artist {
+        _set_or_remove(tag, artist, Tag::set_artist, Tag::remove_artist);
+    }
+    if let Some(album) = changes.album {
+        _set_or_remove(tag, album, Tag::set_album, Tag::remove_album);
+    }
+    if let Some(album_artist) = changes.album_artist {
+        if album_artist.is_empty() {
+            tag.remove_key(&ItemKey::AlbumArtist);
+        } else {
+            tag.insert_text(ItemKey::AlbumArtist, album_artist);
+        }
+    }
+    if let Some(genre) = changes.genre {
+        _set_or_remove(tag, genre, Tag::set_genre, Tag::remove_genre);
+    }
+    if let Some(track) = changes.track {
+        _set_or_remove_number(tag, track, Tag::set_track, Tag::remove_track);
+    }
+    if let Some(disc) = changes.disc {
+        _set_or_remove_number(tag, disc, Tag::set_disk, Tag::remove_disk);
+    }
+    if let Some(year) = changes.year {
+        _set_or_remove_number(tag, year, Tag::set_year, Tag::remove_year);
+    }
+
+    tagged_file.save_to_path(&path, WriteOptions::default())?;
+
+    Audio::read_from_path(&path).ok_or(anyhow!("无法重新读取 {}", path))
+}
+
+/// 文件的主标签，没有时插入一个新的主标签。
+/// 文件已经有其他标签（例如只有 ID3v1 的 MP3）时，新的主标签复制它的内容，
+/// 否则保存后只剩下写入的字段
+pub(crate) fn primary_tag_mut(tagged_file: &mut TaggedFile) -> anyhow::Result<&mut Tag> {
+    let file_type = tagged_file.file_type();
+    if tagged_file.primary_tag().is_none() {
+        let primary_tag_type = tagged_file.primary_tag_type();
+        let tag = match tagged_file.first_tag() {
+            Some(tag) => {
+                let mut tag = tag.clone();
+                tag.re_map(primary_tag_type);
+                tag
+            }
+            None => Tag::new(primary_tag_type),
+        };
+        tagged_file.insert_tag(tag);
+    }
+    tagged_file
+        .primary_tag_mut()
+        .ok_or(anyhow!("{:?} 不支持写入标签", file_type))
+}
+
+fn _set_or_remove(tag: &mut Tag, value: String, set: fn(&mut Tag, String), remove: fn(&mut Tag)) {
+    if value.is_empty() {
+        remove(tag);
+    } else {
+        set(tag, value);
+    }
+}
+
+fn _set_or_remove_number(tag: &mut Tag, value: u32, set: fn(&mut Tag, u32), remove: fn(&mut Tag)) {
+    if value == 0 {
+        remove(tag);
+    } else {
+        set(tag, value);
+    }
+}
+
+/// 写入封面前对图片的处理
+#[derive(Debug, Clone)]
+pub struct CoverOptions {
+    /// 宽或高超过它时按比例缩小。0 表示不缩小
+    pub max_dimension: u32,
+    /// 缩小后或者 recompress 为 true 时重新编码为 JPEG 使用的质量，1 ~ 100
+    pub jpeg_quality: u8,
+    /// 即使不需要缩小也重新编码为 JPEG
+    pub recompress: bool,
+}
+
+impl Default for CoverOptions {
+    fn default() -> Self {
+        CoverOptions {
+            max_dimension: 1200,
+            jpeg_quality: 90,
+            recompress: false,
+        }
+    }
+}
+
+/// for Flutter  
+/// 把 image_path 的图片作为封面（CoverFront）写入 path，替换原来的封面，其他图片保持不变。
+/// path 为 CUE 虚拟音轨时写入它所在的整轨文件
+pub fn set_cover_from_file(
+    path: String,
+    image_path: String,
+    options: CoverOptions,
+) -> anyhow::Result<()> {
+    let cover = _prepare_cover(fs::read(image_path)?, &options)?;
+    _set_cover(Path::new(source_file_of(&path)), cover)?;
+    invalidate_cover_palettes(&[&path]);
+    Ok(())
+}
+
+/// for Flutter  
+/// 和 [set_cover_from_file] 相同，图片来自内存中的数据
+pub fn set_cover_from_bytes(
+    path: String,
+    image: Vec<u8>,
+    options: CoverOptions,
+) -> anyhow::Result<()> {
+    let cover = _prepare_cover(image, &options)?;
+    _set_cover(Path::new(source_file_of(&path)), cover)?;
+    invalidate_cover_palettes(&[&path]);
+    Ok(())
+}
+
+/// for Flutter  
+/// 把图片作为封面写入 folder_path 下（不包括子文件夹）所有 Lofty 支持的音乐文件。
+/// 图片只处理一次。写入失败的文件会被跳过并记录日志，返回写入成功的文件
+pub fn set_folder_cover(
+    folder_path: String,
+    image: Vec<u8>,
+    options: CoverOptions,
+) -> anyhow::Result<Vec<String>> {
+    let cover = _prepare_cover(image, &options)?;
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(&folder_path)?
+        .filter_map(|item| item.ok())
+        .map(|item| item.path())
+        .filter(|item| {
+            item.is_file()
+                && item
+                    .extension()
+                    .and_then(|extension| {
+                        SUPPORT_FORMAT
+                            .get(&extension.to_ascii_lowercase().to_string_lossy())
+                            .copied()
+                    })
+                    .unwrap_or(false)
+        })
+        .collect();
+    paths.sort();
+
+    let mut updated: Vec<String> = vec![];
+    for path in paths {
+        match _set_cover(&path, cover.clone()) {
+            Ok(_) => updated.push(path.to_string_lossy().to_string()),
+            Err(err) => log_to_dart(format!("fail to set cover of {:?}: {}", path, err)),
+        }
+    }
+
+    let updated_paths: Vec<&str> = updated.iter().map(String::as_str).collect();
+    invalidate_cover_palettes(&updated_paths);
+    Ok(updated)
+}
+
+/// for Flutter  
+/// 删除 path 所有标签中的所有内嵌图片。only_front_cover 为 true 时只删除封面（CoverFront）。
+/// path 为 CUE 虚拟音轨时删除它所在的整轨文件中的图片
+pub fn remove_pictures(path: String, only_front_cover: bool) -> anyhow::Result<()> {
+    let source = source_file_of(&path);
+    let mut tagged_file = lofty::read_from_path(source)?;
+
+    let tag_types: Vec<_> = tagged_file
+        .tags()
+        .iter()
+        .map(|tag| tag.tag_type())
+        .collect();
+    for tag_type in tag_types {
+        if let Some(tag) = tagged_file.tag_mut(tag_type) {
+            if only_front_cover {
+                tag.remove_picture_type(PictureType::CoverFront);
+            } else {
+                while !tag.pictures().is_empty() {
+                    tag.remove_picture(0);
+                }
+            }
+        }
+    }
+
+    tagged_file.save_to_path(source, WriteOptions::default())?;
+    invalidate_cover_palettes(&[&path]);
+    Ok(())
+}
+
+/// 按照 options 缩小、重新编码图片，返回 (图片数据, MIME)。
+/// 不需要处理时直接使用原来的数据
+fn _prepare_cover(image: Vec<u8>, options: &CoverOptions) -> anyhow::Result<(Vec<u8>, MimeType)> {
+    let format = image::guess_format(&image)?;
+    let loaded = image::load_from_memory_with_format(&image, format)?;
+
+    let need_resize = options.max_dimension > 0
+        && (loaded.width() > options.max_dimension || loaded.height() > options.max_dimension);
+    if !need_resize && !options.recompress {
+        let mime_type = match format {
+            ImageFormat::Jpeg => MimeType::Jpeg,
+            ImageFormat::Png => MimeType::Png,
+            ImageFormat::Gif => MimeType::Gif,
+            ImageFormat::Bmp => MimeType::Bmp,
+            ImageFormat::Tiff => MimeType::Tiff,
+            // 其他格式不一定能被播放器识别，转为 JPEG
+            _ => return _encode_jpeg(&loaded, options.jpeg_quality),
+        };
+        return Ok((image, mime_type));
+    }
+
+    if need_resize {
+        let resized = loaded.resize(
+            options.max_dimension,
+            options.max_dimension,
+            imageops::FilterType::Triangle,
+        );
+        return _encode_jpeg(&resized, options.jpeg_quality);
+    }
+
+    _encode_jpeg(&loaded, options.jpeg_quality)
+}
+
+fn _encode_jpeg(image: &image::DynamicImage, quality: u8) -> anyhow::Result<(Vec<u8>, MimeType)> {
+    let mut output = Cursor::new(Vec::new());
+    // JPEG 不支持透明度
+    JpegEncoder::new_with_quality(&mut output, quality.clamp(1, 100))
+        .encode_image(&image.to_rgb8())?;
+
+    Ok((output.into_inner(), MimeType::Jpeg))
+}
+
+/// 和 [remove_pictures] 一样处理所有标签：删除每个标签中原来的封面，
+/// 再把新的封面写入主标签和原来有封面的标签，避免优先读取其他标签的播放器仍然显示旧的封面
+fn _set_cover(path: &Path, (data, mime_type): (Vec<u8>, MimeType)) -> anyhow::Result<()> {
+    let mut tagged_file = lofty::read_from_path(path)?;
+    let primary_tag_type = primary_tag_mut(&mut tagged_file)?.tag_type();
+
+    let tag_types: Vec<_> = tagged_file
+        .tags()
+        .iter()
+        .map(|tag| tag.tag_type())
+        .collect();
+    for tag_type in tag_types {
+        if let Some(tag) = tagged_file.tag_mut(tag_type) {
+            let had_cover = tag
+                .pictures()
+                .iter()
+                .any(|item| item.pic_type() == PictureType::CoverFront);
+            tag.remove_picture_type(PictureType::CoverFront);
+            if had_cover || tag_type == primary_tag_type {
+                tag.push_picture(Picture::new_unchecked(
+                    PictureType::CoverFront,
+                    Some(mime_type.clone()),
+                    None,
+                    data.clone(),
+                ));
+            }
+        }
+    }
+
+    tagged_file.save_to_path(path, WriteOptions::default())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 只有 data 块（0.1 秒静音）的 16 bit 单声道 WAV
+    fn _silent_wav(path: &Path) {
+        let data_len: u32 = 8820;
+        let mut bytes: Vec<u8> = vec![];
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+        bytes.extend_from_slice(b"WAVEfmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&44100u32.to_le_bytes());
+        bytes.extend_from_slice(&88200u32.to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&data_len.to_le_bytes());
+        bytes.resize(bytes.len() + data_len as usize, 0);
+        fs::write(path, bytes).unwrap();
+    }
+
+    fn _png(color: [u8; 3]) -> Vec<u8> {
+        let image = image::RgbImage::from_pixel(2, 2, image::Rgb(color));
+        let mut output = Cursor::new(Vec::new());
+        image.write_to(&mut output, ImageFormat::Png).unwrap();
+        output.into_inner()
+    }
+
+    /// 主标签中 (图片类型, 数据)
+    fn _pictures(path: &Path) -> Vec<(PictureType, Vec<u8>)> {
+        let tagged_file = lofty::read_from_path(path).unwrap();
+        tagged_file
+            .primary_tag()
+            .unwrap()
+            .pictures()
+            .iter()
+            .map(|item| (item.pic_type(), item.data().to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn writing_one_field_keeps_others_and_pictures() {
+        let path = std::env::temp_dir().join(format!(
+            "coriander_tag_writer_{}_fields.wav",
+            std::process::id()
+        ));
+        _silent_wav(&path);
+        let path_str = path.to_string_lossy().to_string();
+
+        write_audio_tags(
+            path_str.clone(),
+            AudioTagChanges {
+                title: Some("Title".to_string()),
+                artist: Some("Artist".to_string()),
+                album: Some("Album".to_string()),
+                track: Some(3),
+                year: Some(2014),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let back = _png([0, 0, 255]);
+        let mut tagged_file = lofty::read_from_path(&path).unwrap();
+        tagged_file
+            .primary_tag_mut()
+            .unwrap()
+            .push_picture(Picture::new_unchecked(
+                PictureType::CoverBack,
+                Some(MimeType::Png),
+                None,
+                back.clone(),
+            ));
+        tagged_file
+            .save_to_path(&path, WriteOptions::default())
+            .unwrap();
+
+        let options = CoverOptions::default();
+        let old_front = _png([255, 0, 0]);
+        set_cover_from_bytes(path_str.clone(), old_front, options.clone()).unwrap();
+        let front = _png([0, 255, 0]);
+        set_cover_from_bytes(path_str.clone(), front.clone(), options).unwrap();
+        let mut pictures = _pictures(&path);
+        pictures.sort_by_key(|(pic_type, _)| pic_type.as_u8());
+        assert_eq!(
+            pictures,
+            vec![
+                (PictureType::CoverFront, front),
+                (PictureType::CoverBack, back)
+            ]
+        );
+
+        let audio = write_audio_tags(
+            path_str,
+            AudioTagChanges {
+                title: Some("New Title".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(audio.title, "New Title");
+        assert_eq!(audio.artist, "Artist");
+        assert_eq!(audio.album, "Album");
+        assert_eq!(audio.track, Some(3));
+        assert_eq!(audio.year, Some(2014));
+        let mut after = _pictures(&path);
+        after.sort_by_key(|(pic_type, _)| pic_type.as_u8());
+        assert_eq!(after, pictures);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
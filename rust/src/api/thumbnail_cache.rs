@@ -0,0 +1,501 @@
+// 封面缩略图的磁盘缓存。
+// 缓存以 音乐文件的身份 + 修改时间 + 请求的尺寸 + 输出格式 为键，超过容量时删除最久没有使用的缩略图。
+// 没有封面的音乐也会记录一个空文件，避免每次都重新读取标签。
+// 每个缓存文件都额外计入固定的大小，空文件也会占用容量，不会无限增加。
+
+use std::{
+    fs::{self, File},
+    io::Cursor,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, MutexGuard,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::bail;
+use image::{codecs::jpeg::JpegEncoder, DynamicImage, ImageFormat};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::frb_generated::StreamSink;
+
+use super::{
+    cue_sheet::source_file_of,
+    file_identity::FileIdentity,
+    logger::log_to_dart,
+    tag_reader::{get_original_picture, resize_picture, IndexActionState},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThumbnailFormat {
+    Jpeg,
+    /// 无损 WebP，不使用 quality
+    WebP,
+    /// 不使用 quality
+    Png,
+}
+
+impl ThumbnailFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            ThumbnailFormat::Jpeg => "jpg",
+            ThumbnailFormat::WebP => "webp",
+            ThumbnailFormat::Png => "png",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ThumbnailCacheOptions {
+    /// 缓存文件夹，不存在时创建
+    pub cache_dir: String,
+    /// 缓存的最大容量，in bytes。超过时删除最久没有使用的缩略图
+    pub max_bytes: u64,
+    pub format: ThumbnailFormat,
+    /// JPEG 的质量，1 ~ 100。
+    /// 只用于 JPEG：image 的 WebP 编码器只支持无损编码，PNG 也是无损的，两者都忽略它
+    pub quality: u8,
+}
+
+struct ThumbnailCache {
+    options: ThumbnailCacheOptions,
+    /// 缓存文件夹中所有缩略图的大小之和，每个文件额外计入 [ENTRY_OVERHEAD_BYTES]
+    total_bytes: u64,
+}
+
+/// None 表示没有启用缓存
+static THUMBNAIL_CACHE: Mutex<Option<ThumbnailCache>> = Mutex::new(None);
+
+fn _lock_thumbnail_cache() -> MutexGuard<'static, Option<ThumbnailCache>> {
+    match THUMBNAIL_CACHE.lock() {
+        Ok(val) => val,
+        Err(val) => val.into_inner(),
+    }
+}
+
+/// 超过容量时删除缩略图，直到总大小不超过容量的这个比例，避免每次写入都要清理
+const EVICTION_TARGET_RATIO: f64 = 0.9;
+
+/// 没有封面的音乐在缓存中对应的空文件的扩展名
+const NO_PICTURE_EXTENSION: &str = "none";
+
+/// 每个缓存文件额外计入的大小，相当于文件系统中一个文件至少占用的空间
+const ENTRY_OVERHEAD_BYTES: u64 = 4096;
+
+/// 缩略图和空文件的扩展名，其他文件（包括正在写入的临时文件）不属于缓存
+const CACHE_FILE_EXTENSIONS: [&str; 4] = ["jpg", "webp", "png", NO_PICTURE_EXTENSION];
+
+/// 写入缓存时临时文件的序号，避免并行写入同一个缩略图时互相覆盖
+static TMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// for Flutter  
+/// 启用缩略图缓存。之后 [super::tag_reader::get_picture_from_path] 会从缓存中读取，
+/// 返回的图片格式为 options.format。再次调用会替换之前的设置
+pub fn init_thumbnail_cache(options: ThumbnailCacheOptions) -> anyhow::Result<()> {
+    fs::create_dir_all(&options.cache_dir)?;
+    let total_bytes = _cached_files(Path::new(&options.cache_dir))?
+        .iter()
+        .map(|(_, _, len)| len)
+        .sum();
+
+    *_lock_thumbnail_cache() = Some(ThumbnailCache {
+        options,
+        total_bytes,
+    });
+    Ok(())
+}
+
+/// for Flutter  
+/// 删除所有缓存的缩略图，缓存保持启用
+pub fn clear_thumbnail_cache() -> anyhow::Result<()> {
+    let mut cache = _lock_thumbnail_cache();
+    if let Some(cache) = cache.as_mut() {
+        for (path, _, len) in _cached_files(Path::new(&cache.options.cache_dir))? {
+            fs::remove_file(path)?;
+            cache.total_bytes = cache.total_bytes.saturating_sub(len);
+        }
+    }
+    Ok(())
+}
+
+/// for Flutter  
+/// 为 paths 中的音乐生成 width x height 的缩略图并写入缓存，已经缓存的会被跳过。
+/// 在线程池中并行生成，进度通过 sink 发送。没有启用缓存时返回 Err
+pub fn warm_thumbnail_cache(
+    paths: Vec<String>,
+    width: u32,
+    height: u32,
+    sink: StreamSink<IndexActionState>,
+) -> anyhow::Result<()> {
+    if !is_thumbnail_cache_enabled() {
+        bail!("缩略图缓存没有启用");
+    }
+
+    let total = paths.len();
+    let finished = AtomicU64::new(0);
+    paths.par_iter().for_each(|path| {
+        if let Err(err) = get_cached_thumbnail(path, width, height) {
+            log_to_dart(format!("{:?}: {}", path, err));
+        }
+
+        let finished = finished.fetch_add(1, Ordering::Relaxed) + 1;
+        let _ = sink.add(IndexActionState {
+            progress: IndexActionState::progress_of(finished as usize, total),
+            message: String::new(),
+        });
+    });
+
+    Ok(())
+}
+
+pub(crate) fn is_thumbnail_cache_enabled() -> bool {
+    _lock_thumbnail_cache().is_some()
+}
+
+/// 从缓存中读取缩略图，没有时生成并写入缓存。
+/// 返回 Ok(None) 表示音乐没有封面；封面无法解码时返回原始的封面，不写入缓存。
+/// 缓存没有启用或者无法使用时返回 Err，调用者应该改为不使用缓存读取
+pub(crate) fn get_cached_thumbnail(
+    path: &str,
+    width: u32,
+    height: u32,
+) -> anyhow::Result<Option<Vec<u8>>> {
+    let options = match _lock_thumbnail_cache().as_ref() {
+        Some(cache) => cache.options.clone(),
+        None => bail!("缩略图缓存没有启用"),
+    };
+
+    // 虚拟音轨和整轨文件共用缩略图
+    let key = _cache_key(Path::new(source_file_of(path)), width, height, &options)?;
+    let cache_dir = Path::new(&options.cache_dir);
+    let cache_path = cache_dir.join(format!("{:016x}.{}", key, options.format.extension()));
+    let no_picture_path = cache_dir.join(format!("{:016x}.{}", key, NO_PICTURE_EXTENSION));
+
+    if no_picture_path.is_file() {
+        _touch(&no_picture_path);
+        return Ok(None);
+    }
+    if let Ok(thumbnail) = fs::read(&cache_path) {
+        _touch(&cache_path);
+        return Ok(Some(thumbnail));
+    }
+
+    let pic = match get_original_picture(path) {
+        Some(val) => val,
+        None => {
+            _write_to_cache(&no_picture_path, &[])?;
+            return Ok(None);
+        }
+    };
+    let loaded_pic = match image::load_from_memory(&pic) {
+        Ok(val) => val,
+        Err(_) => return Ok(Some(pic)),
+    };
+    let thumbnail = match _encode(&resize_picture(&loaded_pic, width, height), &options) {
+        Ok(val) => val,
+        Err(err) => {
+            log_to_dart(format!("fail to encode thumbnail: {}", err));
+            return Ok(Some(pic));
+        }
+    };
+
+    if let Err(err) = _write_to_cache(&cache_path, &thumbnail) {
+        log_to_dart(format!("fail to write thumbnail cache: {}", err));
+    }
+
+    Ok(Some(thumbnail))
+}
+
+/// 更新修改时间，用来判断最近是否使用过
+fn _touch(path: &Path) {
+    if let Ok(file) = File::options().write(true).open(path) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+}
+
+/// 先写入临时文件再重命名，其他线程或者进程不会读到写了一半的缩略图
+fn _write_to_cache(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let tmp_path = path.with_extension(format!(
+        "{}.tmp",
+        TMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    if let Err(err) = fs::write(&tmp_path, data).and_then(|_| fs::rename(&tmp_path, path)) {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(err);
+    }
+
+    _add_to_cache(data.len() as u64);
+    Ok(())
+}
+
+fn _encode(image: &DynamicImage, options: &ThumbnailCacheOptions) -> anyhow::Result<Vec<u8>> {
+    let mut output = Cursor::new(Vec::new());
+    match options.format {
+        ThumbnailFormat::Jpeg => {
+            // JPEG 不支持透明度
+            JpegEncoder::new_with_quality(&mut output, options.quality.clamp(1, 100))
+                .encode_image(&image.to_rgb8())?
+        }
+        ThumbnailFormat::WebP => image.to_rgba8().write_to(&mut output, ImageFormat::WebP)?,
+        ThumbnailFormat::Png => image.write_to(&mut output, ImageFormat::Png)?,
+    }
+    Ok(output.into_inner())
+}
+
+/// 对音乐文件的身份、音乐文件和所在文件夹的修改时间（文件夹中的封面图片可能被替换）、
+/// 请求的尺寸和输出格式做 FNV-1a 哈希
+fn _cache_key(
+    path: &Path,
+    width: u32,
+    height: u32,
+    options: &ThumbnailCacheOptions,
+) -> std::io::Result<u64> {
+    let modified_nanos = |path: &Path| -> std::io::Result<u128> {
+        Ok(fs::metadata(path)?
+            .modified()?
+            .duration_since(UNIX_EPOCH)
+            .map(|value| value.as_nanos())
+            .unwrap_or(0))
+    };
+
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut write = |bytes: &[u8]| {
+        for byte in bytes.iter().chain(std::iter::once(&0u8)) {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    };
+
+    write(&FileIdentity::of(path)?.to_le_bytes());
+    write(&modified_nanos(path)?.to_le_bytes());
+    if let Some(folder) = path.parent() {
+        write(&modified_nanos(folder).unwrap_or(0).to_le_bytes());
+    }
+    write(&width.to_le_bytes());
+    write(&height.to_le_bytes());
+    write(options.format.extension().as_bytes());
+    if options.format == ThumbnailFormat::Jpeg {
+        write(&[options.quality]);
+    }
+
+    Ok(hash)
+}
+
+/// 记录新写入的缩略图，超过容量时删除最久没有使用的缩略图
+fn _add_to_cache(len: u64) {
+    if let Some(cache) = _lock_thumbnail_cache().as_mut() {
+        cache.add(len);
+    }
+}
+
+impl ThumbnailCache {
+    fn add(&mut self, len: u64) {
+        self.total_bytes += len + ENTRY_OVERHEAD_BYTES;
+        if self.total_bytes <= self.options.max_bytes {
+            return;
+        }
+
+        let mut files = match _cached_files(Path::new(&self.options.cache_dir)) {
+            Ok(val) => val,
+            Err(err) => {
+                log_to_dart(format!("fail to evict thumbnail cache: {}", err));
+                return;
+            }
+        };
+        files.sort_by_key(|(_, modified, _)| *modified);
+
+        let target = (self.options.max_bytes as f64 * EVICTION_TARGET_RATIO) as u64;
+        self.total_bytes = files.iter().map(|(_, _, len)| len).sum();
+        for (path, _, len) in files {
+            if self.total_bytes <= target {
+                break;
+            }
+            if fs::remove_file(&path).is_ok() {
+                self.total_bytes = self.total_bytes.saturating_sub(len);
+            }
+        }
+    }
+}
+
+/// 是否是缓存文件的名称：16 位十六进制的键 + 缩略图或空文件的扩展名
+fn _is_cache_file_name(name: &str) -> bool {
+    match name.split_once('.') {
+        Some((key, extension)) => {
+            key.len() == 16
+                && key.bytes().all(|byte| byte.is_ascii_hexdigit())
+                && CACHE_FILE_EXTENSIONS.contains(&extension)
+        }
+        None => false,
+    }
+}
+
+/// 缓存文件夹中的所有缩略图：(路径, 修改时间, 计入的大小)。
+/// 计入的大小是文件大小加上 [ENTRY_OVERHEAD_BYTES]
+fn _cached_files(cache_dir: &Path) -> std::io::Result<Vec<(PathBuf, SystemTime, u64)>> {
+    let mut files = vec![];
+    for entry in fs::read_dir(cache_dir)? {
+        let entry = entry?;
+        if !entry.file_name().to_str().is_some_and(_is_cache_file_name) {
+            continue;
+        }
+        let metadata = entry.metadata()?;
+        if metadata.is_file() {
+            files.push((
+                entry.path(),
+                metadata.modified().unwrap_or(UNIX_EPOCH),
+                metadata.len() + ENTRY_OVERHEAD_BYTES,
+            ));
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "coriander_thumbnail_cache_{}_{}",
+            std::process::id(),
+            name
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn _options(cache_dir: &Path, max_bytes: u64) -> ThumbnailCacheOptions {
+        ThumbnailCacheOptions {
+            cache_dir: cache_dir.to_string_lossy().to_string(),
+            max_bytes,
+            format: ThumbnailFormat::Jpeg,
+            quality: 80,
+        }
+    }
+
+    #[test]
+    fn cache_key_changes_with_modified_time() {
+        let dir = _temp_dir("key");
+        let audio = dir.join("audio.mp3");
+        fs::write(&audio, b"not really audio").unwrap();
+        let options = _options(&dir, 1 << 20);
+
+        let key = _cache_key(&audio, 128, 128, &options).unwrap();
+        assert_eq!(_cache_key(&audio, 128, 128, &options).unwrap(), key);
+        assert_ne!(_cache_key(&audio, 256, 256, &options).unwrap(), key);
+
+        File::options()
+            .write(true)
+            .open(&audio)
+            .unwrap()
+            .set_modified(UNIX_EPOCH + std::time::Duration::from_secs(1_000_000))
+            .unwrap();
+        assert_ne!(_cache_key(&audio, 128, 128, &options).unwrap(), key);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn only_cache_file_names_are_listed() {
+        assert!(_is_cache_file_name("0123456789abcdef.jpg"));
+        assert!(_is_cache_file_name("0123456789ABCDEF.none"));
+        assert!(!_is_cache_file_name("0123456789abcdef.3.tmp"));
+        assert!(!_is_cache_file_name("0123456789abcdef.txt"));
+        assert!(!_is_cache_file_name("0123456789abcde.png"));
+        assert!(!_is_cache_file_name("desktop.ini"));
+    }
+
+    #[test]
+    fn eviction_removes_least_recently_used_to_target() {
+        let dir = _temp_dir("evict");
+        // 10 个 1000 字节的缩略图和 10 个空文件，修改时间依次增加
+        let mut names = vec![];
+        for i in 0..20u64 {
+            let (extension, len) = if i % 2 == 0 {
+                ("jpg", 1000)
+            } else {
+                (NO_PICTURE_EXTENSION, 0)
+            };
+            let path = dir.join(format!("{:016x}.{}", i, extension));
+            fs::write(&path, vec![0u8; len]).unwrap();
+            File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(UNIX_EPOCH + std::time::Duration::from_secs(1_000 + i))
+                .unwrap();
+            names.push(path);
+        }
+        fs::write(dir.join("0000000000000099.7.tmp"), vec![0u8; 1000]).unwrap();
+        fs::write(dir.join("unrelated.txt"), vec![0u8; 1000]).unwrap();
+
+        let entry_bytes = |len: u64| len + ENTRY_OVERHEAD_BYTES;
+        let listed: u64 = _cached_files(&dir)
+            .unwrap()
+            .iter()
+            .map(|(_, _, len)| len)
+            .sum();
+        assert_eq!(listed, 10 * entry_bytes(1000) + 10 * entry_bytes(0));
+
+        // 空文件也计入容量：容量只够 10 个文件，加上新的缩略图后超出
+        let max_bytes = 10 * entry_bytes(1000);
+        let mut cache = ThumbnailCache {
+            options: _options(&dir, max_bytes),
+            total_bytes: listed,
+        };
+        let newest = dir.join(format!("{:016x}.jpg", 100));
+        fs::write(&newest, vec![0u8; 1000]).unwrap();
+        cache.add(1000);
+
+        let target = (max_bytes as f64 * EVICTION_TARGET_RATIO) as u64;
+        let remaining: u64 = _cached_files(&dir)
+            .unwrap()
+            .iter()
+            .map(|(_, _, len)| len)
+            .sum();
+        assert_eq!(cache.total_bytes, remaining);
+        assert!(remaining <= target);
+        // 删除的是最久没有使用的文件，最近使用的文件都保留
+        let first_kept = names.iter().position(|path| path.exists()).unwrap();
+        assert!(first_kept > 0);
+        assert!(names[first_kept..].iter().all(|path| path.exists()));
+        assert!(newest.exists());
+        assert!(dir.join("0000000000000099.7.tmp").exists());
+        assert!(dir.join("unrelated.txt").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn audio_without_picture_writes_marker() {
+        let dir = _temp_dir("marker");
+        let cache_dir = dir.join("cache");
+        let audio = dir.join("untagged.mp3");
+        fs::write(&audio, b"not really audio").unwrap();
+
+        init_thumbnail_cache(_options(&cache_dir, 1 << 20)).unwrap();
+        let audio_path = audio.to_string_lossy().to_string();
+        assert_eq!(get_cached_thumbnail(&audio_path, 64, 64).unwrap(), None);
+
+        let files = _cached_files(&cache_dir).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(
+            files[0].0.extension().unwrap().to_str(),
+            Some(NO_PICTURE_EXTENSION)
+        );
+        assert_eq!(files[0].2, ENTRY_OVERHEAD_BYTES);
+        // 第二次从空文件得知没有封面
+        assert_eq!(get_cached_thumbnail(&audio_path, 64, 64).unwrap(), None);
+        assert_eq!(
+            _lock_thumbnail_cache().as_ref().unwrap().total_bytes,
+            ENTRY_OVERHEAD_BYTES
+        );
+
+        clear_thumbnail_cache().unwrap();
+        assert!(_cached_files(&cache_dir).unwrap().is_empty());
+        *_lock_thumbnail_cache() = None;
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}